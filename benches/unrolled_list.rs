@@ -0,0 +1,78 @@
+//! Iteration speed of `UnrolledList` against `LinkedList`.
+//!
+//! Run with `cargo bench --bench unrolled_list`. Uses no benchmark framework: every case is
+//! timed over a few rounds with `std::time::Instant` and the best round is reported.
+
+use rust_algo::collections::{LinkedList, UnrolledList};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 100_000;
+const ROUNDS: usize = 5;
+
+fn best_of<F: FnMut() -> u64>(mut run: F) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, duration: Duration) {
+    println!(
+        "{:<46} {:>10.3} ms {:>8.2} ns/element",
+        name,
+        duration.as_secs_f64() * 1e3,
+        duration.as_nanos() as f64 / LEN as f64
+    );
+}
+
+fn linked_list() -> LinkedList<u64> {
+    let mut list = LinkedList::new();
+    for i in 0..LEN as u64 {
+        list.push_back(i);
+    }
+    list
+}
+
+fn unrolled_list<const N: usize>() -> UnrolledList<u64, N> {
+    (0..LEN as u64).collect()
+}
+
+fn main() {
+    println!("{} elements, best of {} rounds", LEN, ROUNDS);
+
+    // a search for a missing value walks the whole list without modifying it
+    let list = linked_list();
+    report(
+        "LinkedList::index_of (full scan)",
+        best_of(|| list.index_of(u64::MAX) as u64),
+    );
+    for_capacity::<8>();
+    for_capacity::<16>();
+    for_capacity::<64>();
+
+    report(
+        "LinkedList build + into_iter().sum()",
+        best_of(|| linked_list().into_iter().sum()),
+    );
+    report(
+        "UnrolledList<_, 16> build + into_iter().sum()",
+        best_of(|| unrolled_list::<16>().into_iter().sum()),
+    );
+}
+
+fn for_capacity<const N: usize>() {
+    let list = unrolled_list::<N>();
+    report(
+        &format!("UnrolledList<_, {}>::index_of (full scan)", N),
+        best_of(|| list.index_of(u64::MAX) as u64),
+    );
+    report(
+        &format!("UnrolledList<_, {}>::iter().sum()", N),
+        best_of(|| list.iter().sum()),
+    );
+}
@@ -0,0 +1,408 @@
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+const WORD_BITS: usize = 64;
+
+/// BitSet structure, a fixed number of bits packed in 64-bit words.
+///
+/// Can be seen either as a vector of bits or as a set of small integers:
+/// `set`, `test` and the set operations work on whole words at a time.
+///
+/// # Attributes
+///
+/// * `words` - The bits, bit `i` is bit `i % 64` of word `i / 64`
+/// * `length` - Number of bits
+///
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    words: Vec<u64>,
+    length: usize,
+}
+
+impl BitSet {
+    /// Create a BitSet of `len` bits, all cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of bits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BitSet;
+    /// fn main() {
+    ///     let mut bits = BitSet::new(100);
+    ///     bits.set(42);
+    ///     assert!(bits.test(42));
+    ///     assert_eq!(bits.count_ones(), 1);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `BitSet` - The BitSet that was created
+    ///
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            length: len,
+        }
+    }
+
+    /// Create a BitSet of `len` bits, all set.
+    pub fn new_filled(len: usize) -> Self {
+        let mut bits = Self {
+            words: vec![u64::MAX; len.div_ceil(WORD_BITS)],
+            length: len,
+        };
+        bits.trim();
+        bits
+    }
+
+    /// Create a BitSet from a slice of booleans.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BitSet;
+    /// fn main() {
+    ///     let bits = BitSet::from_bools(&[true, false, true]);
+    ///     assert_eq!(bits.iter().collect::<Vec<_>>(), vec![0, 2]);
+    /// }
+    /// ```
+    ///
+    pub fn from_bools(values: &[bool]) -> Self {
+        let mut bits = Self::new(values.len());
+        for (index, _) in values.iter().enumerate().filter(|(_, &value)| value) {
+            bits.set(index);
+        }
+        bits
+    }
+
+    // Clear the unused bits of the last word so counts and comparisons stay exact
+    fn trim(&mut self) {
+        let used = self.length % WORD_BITS;
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << used) - 1;
+            }
+        }
+    }
+
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.length,
+            "bit {} out of bounds for length {}",
+            index,
+            self.length
+        );
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the BitSet has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the words holding the bits, the unused high bits of the last word are cleared.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the value of the bit at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the bit, must be lower than `len()`
+    ///
+    pub fn test(&self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Set the bit at `index` to 1.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The previous value of the bit
+    ///
+    pub fn set(&mut self, index: usize) -> bool {
+        let previous = self.test(index);
+        self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+        previous
+    }
+
+    /// Set the bit at `index` to 0.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The previous value of the bit
+    ///
+    pub fn clear(&mut self, index: usize) -> bool {
+        let previous = self.test(index);
+        self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+        previous
+    }
+
+    /// Flip the bit at `index`.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - The new value of the bit
+    ///
+    pub fn toggle(&mut self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+        self.test(index)
+    }
+
+    /// Set the bit at `index` to `value`.
+    pub fn assign(&mut self, index: usize, value: bool) {
+        if value {
+            self.set(index);
+        } else {
+            self.clear(index);
+        }
+    }
+
+    /// Set every bit to 0.
+    pub fn clear_all(&mut self) {
+        for word in self.words.iter_mut() {
+            *word = 0;
+        }
+    }
+
+    /// Set every bit to 1.
+    pub fn set_all(&mut self) {
+        for word in self.words.iter_mut() {
+            *word = u64::MAX;
+        }
+        self.trim();
+    }
+
+    /// Returns the number of bits set to 1.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the number of bits set to 0.
+    pub fn count_zeros(&self) -> usize {
+        self.length - self.count_ones()
+    }
+
+    /// Returns true if no bit is set.
+    pub fn none(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns true if every bit is set.
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.length
+    }
+
+    /// Change the number of bits, new bits are cleared.
+    pub fn resize(&mut self, len: usize) {
+        self.words.resize(len.div_ceil(WORD_BITS), 0);
+        self.length = len;
+        self.trim();
+    }
+
+    fn combine_with<F: Fn(u64, u64) -> u64>(&mut self, other: &BitSet, operation: F) {
+        if other.length > self.length {
+            self.resize(other.length);
+        }
+        for (index, word) in self.words.iter_mut().enumerate() {
+            *word = operation(*word, other.words.get(index).copied().unwrap_or(0));
+        }
+    }
+
+    /// Set every bit that is set in `other`, growing `self` if `other` is longer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BitSet;
+    /// fn main() {
+    ///     let mut a = BitSet::from_bools(&[true, false, false]);
+    ///     let b = BitSet::from_bools(&[false, false, true, true]);
+    ///     a.union_with(&b);
+    ///     assert_eq!(a.iter().collect::<Vec<_>>(), vec![0, 2, 3]);
+    /// }
+    /// ```
+    ///
+    pub fn union_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a | b)
+    }
+
+    /// Keep only the bits that are also set in `other`.
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a & b)
+    }
+
+    /// Clear every bit that is set in `other`.
+    pub fn difference_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a & !b)
+    }
+
+    /// Keep the bits set in exactly one of `self` and `other`.
+    pub fn symmetric_difference_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns true if every bit set in `self` is set in `other`.
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(index, word)| word & !other.words.get(index).copied().unwrap_or(0) == 0)
+    }
+
+    /// Returns true if `self` and `other` have no set bit in common.
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// An iterator over the positions of the set bits, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BitSet;
+    /// fn main() {
+    ///     let mut bits = BitSet::new(200);
+    ///     for i in [3, 64, 199] {
+    ///         bits.set(i);
+    ///     }
+    ///     assert_eq!(bits.iter().collect::<Vec<_>>(), vec![3, 64, 199]);
+    /// }
+    /// ```
+    ///
+    pub fn iter(&self) -> Ones<'_> {
+        Ones {
+            words: &self.words,
+            index: 0,
+            current: self.words.first().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl Debug for BitSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let values: Vec<bool> = iter.into_iter().collect();
+        Self::from_bools(&values)
+    }
+}
+
+macro_rules! impl_set_operator {
+    ($trait:ident, $method:ident, $with:ident) => {
+        impl $trait<&BitSet> for &BitSet {
+            type Output = BitSet;
+            fn $method(self, other: &BitSet) -> BitSet {
+                let mut result = self.clone();
+                result.$with(other);
+                result
+            }
+        }
+    };
+}
+
+impl_set_operator!(BitOr, bitor, union_with);
+impl_set_operator!(BitAnd, bitand, intersect_with);
+impl_set_operator!(BitXor, bitxor, symmetric_difference_with);
+impl_set_operator!(Sub, sub, difference_with);
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Ones<'a>;
+    fn into_iter(self) -> Ones<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the positions of the set bits of a `BitSet`, see [`BitSet::iter`].
+pub struct Ones<'a> {
+    words: &'a [u64],
+    index: usize,
+    current: u64,
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.index * WORD_BITS + bit)
+    }
+}
+
+#[cfg(test)]
+mod bit_set_tests {
+    use super::*;
+
+    #[test]
+    fn set_clear_toggle() {
+        let mut bits = BitSet::new(130);
+        assert!(bits.none());
+        assert!(!bits.set(129));
+        assert!(bits.set(129));
+        assert!(bits.toggle(0));
+        assert!(!bits.toggle(0));
+        bits.set(64);
+        assert!(bits.clear(64));
+        assert!(!bits.test(64));
+        assert_eq!(bits.count_ones(), 1);
+        bits.set_all();
+        assert!(bits.all());
+        assert_eq!(bits.count_ones(), 130);
+        assert_eq!(bits, BitSet::new_filled(130));
+        bits.clear_all();
+        assert_eq!(bits.count_zeros(), 130);
+    }
+
+    #[test]
+    fn set_operations() {
+        let evens: BitSet = (0..100).map(|i| i % 2 == 0).collect();
+        let threes: BitSet = (0..150).map(|i| i % 3 == 0).collect();
+        let union = &evens | &threes;
+        assert_eq!(union.len(), 150);
+        assert_eq!(
+            union.iter().count(),
+            (0..150)
+                .filter(|i| i < &100 && i % 2 == 0 || i % 3 == 0)
+                .count()
+        );
+        let both = &evens & &threes;
+        assert!(both.iter().all(|i| i % 6 == 0));
+        assert_eq!(both.count_ones(), 17);
+        let difference = &evens - &threes;
+        assert!(difference.iter().all(|i| i % 2 == 0 && i % 3 != 0));
+        assert!(both.is_subset(&evens));
+        assert!(difference.is_disjoint(&threes));
+        let xor = &evens ^ &threes;
+        assert_eq!(xor.count_ones(), union.count_ones() - both.count_ones());
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        BitSet::new(10).test(10);
+    }
+}
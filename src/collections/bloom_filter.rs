@@ -0,0 +1,399 @@
+use std::f64::consts::LN_2;
+use std::hash::{Hash, Hasher};
+
+// Deterministic 64-bit hasher: unlike `RandomState` it gives the same hashes in every run,
+// which serialized filters rely on.
+struct SeededHasher {
+    state: u64,
+}
+
+impl Hasher for SeededHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.state = (self.state ^ u64::from_le_bytes(word))
+                .rotate_left(29)
+                .wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        // SplitMix64 finalizer, spreads every input bit over the whole output
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Two independent hashes of `item`, from which any number of hashes are derived by double hashing.
+pub(crate) fn hash_pair<T: Hash + ?Sized>(item: &T) -> (u64, u64) {
+    let hash = |seed: u64| {
+        let mut hasher = SeededHasher { state: seed };
+        item.hash(&mut hasher);
+        hasher.finish()
+    };
+    // an odd step cannot share the factor 2 with the table size, which would shorten its cycle
+    (hash(0x243F_6A88_85A3_08D3), hash(0x1319_8A2E_0370_7344) | 1)
+}
+
+/// The `index`-th hash derived from `hashes`, reduced to `0..modulo`.
+pub(crate) fn nth_hash(hashes: (u64, u64), index: u64, modulo: usize) -> usize {
+    (hashes.0.wrapping_add(index.wrapping_mul(hashes.1)) % modulo as u64) as usize
+}
+
+/// BloomFilter structure, a probabilistic set.
+///
+/// `contains` never answers false for an inserted item, but may answer true for an item
+/// that was never inserted. Each item sets `num_hashes` bits chosen by double hashing.
+///
+/// Items are hashed with their `Hash` implementation and a fixed hasher, so a filter
+/// serialized with `to_bytes` can be read back by another run on a machine with the
+/// same endianness.
+///
+/// # Attributes
+///
+/// * `bits` - The bit array, stored in 64-bit words
+/// * `num_bits` - Number of bits of the filter
+/// * `num_hashes` - Number of bits set per item
+/// * `inserted` - Number of insertions
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: u32,
+    inserted: u64,
+}
+
+impl BloomFilter {
+    /// Make a BloomFilter sized for `expected_items` items with the given false positive rate.
+    ///
+    /// Uses the optimal `m = -n ln(p) / ln(2)²` bits and `k = m / n ln(2)` hashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_items` - Number of items that will be inserted
+    /// * `false_positive_rate` - Wanted probability of a false positive, in `(0, 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BloomFilter;
+    /// fn main() {
+    ///     let mut filter = BloomFilter::new(1000, 0.01);
+    ///     filter.insert("apple");
+    ///     assert!(filter.contains("apple"));
+    ///     assert_eq!(filter.num_hashes(), 7);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `BloomFilter` - The BloomFilter that was created
+    ///
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be in (0, 1)"
+        );
+        let items = expected_items.max(1) as f64;
+        let num_bits = (-items * false_positive_rate.ln() / (LN_2 * LN_2)).ceil() as usize;
+        let num_hashes = (num_bits as f64 / items * LN_2).round().max(1.0) as u32;
+        Self::with_size(num_bits, num_hashes)
+    }
+
+    /// Make a BloomFilter with exactly `num_bits` bits and `num_hashes` hashes per item.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_bits` - Number of bits, at least 1
+    /// * `num_hashes` - Number of bits set per item, at least 1
+    ///
+    pub fn with_size(num_bits: usize, num_hashes: u32) -> Self {
+        assert!(
+            num_bits > 0 && num_hashes > 0,
+            "a BloomFilter needs bits and hashes"
+        );
+        Self {
+            bits: vec![0; num_bits.div_ceil(64)],
+            num_bits,
+            num_hashes,
+            inserted: 0,
+        }
+    }
+
+    /// Returns the number of bits of the filter.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Returns the number of bits set per item.
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    /// Returns the number of insertions since the filter was created or cleared.
+    pub fn inserted(&self) -> u64 {
+        self.inserted
+    }
+
+    /// Returns the number of bits set to 1.
+    pub fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn indexes<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let hashes = hash_pair(item);
+        let num_bits = self.num_bits;
+        (0..self.num_hashes as u64).map(move |index| nth_hash(hashes, index, num_bits))
+    }
+
+    /// Add `item` to the set.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Any hashable value
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - true if `item` was definitely not in the set before
+    ///
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) -> bool {
+        let mut new = false;
+        let indexes: Vec<usize> = self.indexes(item).collect();
+        for index in indexes {
+            let mask = 1u64 << (index % 64);
+            new |= self.bits[index / 64] & mask == 0;
+            self.bits[index / 64] |= mask;
+        }
+        self.inserted += 1;
+        new
+    }
+
+    /// Returns false if `item` was definitely never inserted, true if it probably was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BloomFilter;
+    /// fn main() {
+    ///     let mut filter = BloomFilter::new(100, 0.001);
+    ///     for i in 0..100 {
+    ///         filter.insert(&i);
+    ///     }
+    ///     assert!((0..100).all(|i| filter.contains(&i)));
+    /// }
+    /// ```
+    ///
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        self.indexes(item)
+            .all(|index| self.bits[index / 64] & (1u64 << (index % 64)) != 0)
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        for word in self.bits.iter_mut() {
+            *word = 0;
+        }
+        self.inserted = 0;
+    }
+
+    /// Returns the probability of a false positive given the bits currently set, `(ones / m)^k`.
+    pub fn false_positive_rate(&self) -> f64 {
+        (self.count_ones() as f64 / self.num_bits as f64).powi(self.num_hashes as i32)
+    }
+
+    /// Estimate the number of distinct items inserted from the number of bits set.
+    pub fn estimated_items(&self) -> f64 {
+        let m = self.num_bits as f64;
+        let ones = self.count_ones() as f64;
+        if ones >= m {
+            return f64::INFINITY;
+        }
+        -m / self.num_hashes as f64 * (1.0 - ones / m).ln()
+    }
+
+    fn assert_compatible(&self, other: &BloomFilter) {
+        assert!(
+            self.num_bits == other.num_bits && self.num_hashes == other.num_hashes,
+            "BloomFilters must have the same number of bits and hashes"
+        );
+    }
+
+    /// Add all the items of `other` to `self`, the result is exactly the filter of the union.
+    ///
+    /// # Panics
+    ///
+    /// If the filters do not have the same number of bits and hashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BloomFilter;
+    /// fn main() {
+    ///     let mut a = BloomFilter::new(100, 0.01);
+    ///     let mut b = BloomFilter::new(100, 0.01);
+    ///     a.insert("a");
+    ///     b.insert("b");
+    ///     a.union(&b);
+    ///     assert!(a.contains("a") && a.contains("b"));
+    /// }
+    /// ```
+    ///
+    pub fn union(&mut self, other: &BloomFilter) {
+        self.assert_compatible(other);
+        for (word, other) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other;
+        }
+        self.inserted += other.inserted;
+    }
+
+    /// Keep only the bits set in both filters. Every item of both sets is still reported,
+    /// with a false positive rate at most the one of either filter.
+    ///
+    /// # Panics
+    ///
+    /// If the filters do not have the same number of bits and hashes.
+    ///
+    pub fn intersection(&mut self, other: &BloomFilter) {
+        self.assert_compatible(other);
+        for (word, other) in self.bits.iter_mut().zip(&other.bits) {
+            *word &= other;
+        }
+        self.inserted = self.inserted.min(other.inserted);
+    }
+
+    /// Serialize the filter: `num_bits` (u64), `num_hashes` (u32), `inserted` (u64)
+    /// then the bit words (u64), all little-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::BloomFilter;
+    /// fn main() {
+    ///     let mut filter = BloomFilter::new(10, 0.1);
+    ///     filter.insert("x");
+    ///     let copy = BloomFilter::from_bytes(&filter.to_bytes()).unwrap();
+    ///     assert_eq!(copy, filter);
+    /// }
+    /// ```
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(20 + 8 * self.bits.len());
+        bytes.extend_from_slice(&(self.num_bits as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.num_hashes.to_le_bytes());
+        bytes.extend_from_slice(&self.inserted.to_le_bytes());
+        for word in &self.bits {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Read back a filter written by `to_bytes`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<BloomFilter>` - The filter, or None if `bytes` is not a valid serialized filter
+    ///
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let num_bits = u64::from_le_bytes(bytes.get(0..8)?.try_into().ok()?) as usize;
+        let num_hashes = u32::from_le_bytes(bytes.get(8..12)?.try_into().ok()?);
+        let inserted = u64::from_le_bytes(bytes.get(12..20)?.try_into().ok()?);
+        let words = &bytes[20..];
+        if num_bits == 0 || num_hashes == 0 || words.len() != 8 * num_bits.div_ceil(64) {
+            return None;
+        }
+        let bits: Vec<u64> = words
+            .chunks_exact(8)
+            .map(|word| u64::from_le_bytes(word.try_into().expect("chunks of 8 bytes")))
+            .collect();
+        // the bits of the last word past num_bits are never set
+        let used = num_bits % 64;
+        if used != 0 && bits[bits.len() - 1] >> used != 0 {
+            return None;
+        }
+        Some(Self {
+            bits,
+            num_bits,
+            num_hashes,
+            inserted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod bloom_filter_tests {
+    use super::*;
+
+    #[test]
+    fn no_false_negative() {
+        let mut filter = BloomFilter::new(10_000, 0.01);
+        for i in 0..10_000u32 {
+            filter.insert(&i);
+        }
+        assert!((0..10_000u32).all(|i| filter.contains(&i)));
+        assert_eq!(filter.inserted(), 10_000);
+        let estimated = filter.estimated_items();
+        assert!((9_500.0..10_500.0).contains(&estimated));
+    }
+
+    #[test]
+    fn false_positive_rate() {
+        let mut filter = BloomFilter::new(5_000, 0.02);
+        for i in 0..5_000u64 {
+            filter.insert(&i);
+        }
+        let false_positives = (5_000..105_000u64).filter(|i| filter.contains(i)).count();
+        let rate = false_positives as f64 / 100_000.0;
+        assert!(rate < 0.03, "false positive rate too high: {}", rate);
+        assert!((filter.false_positive_rate() - 0.02).abs() < 0.01);
+    }
+
+    #[test]
+    fn union_intersection_and_bytes() {
+        let mut a = BloomFilter::new(100, 0.01);
+        let mut b = BloomFilter::new(100, 0.01);
+        assert!(a.insert("shared"));
+        assert!(!a.insert("shared"));
+        a.insert("only a");
+        b.insert("shared");
+        b.insert("only b");
+        let mut union = a.clone();
+        union.union(&b);
+        assert!(["shared", "only a", "only b"]
+            .iter()
+            .all(|item| union.contains(*item)));
+        a.intersection(&b);
+        assert!(a.contains("shared"));
+
+        let bytes = union.to_bytes();
+        assert_eq!(BloomFilter::from_bytes(&bytes), Some(union));
+        assert_eq!(BloomFilter::from_bytes(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(BloomFilter::from_bytes(&[]), None);
+    }
+
+    #[test]
+    fn bytes_with_stray_bits() {
+        let mut filter = BloomFilter::with_size(100, 3);
+        filter.insert("x");
+        let mut bytes = filter.to_bytes();
+        assert_eq!(BloomFilter::from_bytes(&bytes), Some(filter));
+        // bit 100 of the filter, the first past the end
+        bytes[20 + 8 + 4] |= 0x10;
+        assert_eq!(BloomFilter::from_bytes(&bytes), None);
+        let full = BloomFilter::with_size(128, 3).to_bytes();
+        assert!(BloomFilter::from_bytes(&full).is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn union_of_different_sizes() {
+        let mut a = BloomFilter::with_size(64, 3);
+        a.union(&BloomFilter::with_size(128, 3));
+    }
+}
@@ -0,0 +1,183 @@
+use super::bloom_filter::{hash_pair, nth_hash};
+use std::f64::consts::E;
+use std::hash::Hash;
+
+/// CountMinSketch structure, approximate frequency counts in constant memory.
+///
+/// Each row of counters is indexed by a different hash of the item; an item's estimate
+/// is the minimum of its counters. Estimates never undercount, and overcount by at most
+/// `epsilon * total` with probability `1 - delta`.
+///
+/// # Attributes
+///
+/// * `counters` - `depth` rows of `width` counters
+/// * `width` - Number of counters per row
+/// * `depth` - Number of rows
+/// * `total` - Sum of all the counts added
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountMinSketch {
+    counters: Vec<u64>,
+    width: usize,
+    depth: usize,
+    total: u64,
+}
+
+impl CountMinSketch {
+    /// Make a CountMinSketch with error at most `epsilon * total` with probability `1 - delta`.
+    ///
+    /// Uses `width = ⌈e / epsilon⌉` and `depth = ⌈ln(1 / delta)⌉`.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon` - Relative error bound, in `(0, 1)`
+    /// * `delta` - Probability of exceeding the bound, in `(0, 1)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::CountMinSketch;
+    /// fn main() {
+    ///     let mut sketch = CountMinSketch::new(0.001, 0.01);
+    ///     for word in "to be or not to be".split(' ') {
+    ///         sketch.increment(word);
+    ///     }
+    ///     assert_eq!(sketch.estimate("be"), 2);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `CountMinSketch` - The CountMinSketch that was created
+    ///
+    pub fn new(epsilon: f64, delta: f64) -> Self {
+        assert!(epsilon > 0.0 && epsilon < 1.0, "epsilon must be in (0, 1)");
+        assert!(delta > 0.0 && delta < 1.0, "delta must be in (0, 1)");
+        let width = (E / epsilon).ceil() as usize;
+        let depth = (1.0 / delta).ln().ceil().max(1.0) as usize;
+        Self::with_size(width, depth)
+    }
+
+    /// Make a CountMinSketch with `depth` rows of `width` counters.
+    pub fn with_size(width: usize, depth: usize) -> Self {
+        assert!(width > 0 && depth > 0, "a CountMinSketch needs counters");
+        Self {
+            counters: vec![0; width * depth],
+            width,
+            depth,
+            total: 0,
+        }
+    }
+
+    /// Returns the number of counters per row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the sum of all the counts added.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    fn cells<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let hashes = hash_pair(item);
+        let width = self.width;
+        (0..self.depth).map(move |row| row * width + nth_hash(hashes, row as u64, width))
+    }
+
+    /// Add `count` occurrences of `item`.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Any hashable value
+    /// * `count` - Number of occurrences
+    ///
+    pub fn add<T: Hash + ?Sized>(&mut self, item: &T, count: u64) {
+        let cells: Vec<usize> = self.cells(item).collect();
+        for cell in cells {
+            self.counters[cell] = self.counters[cell].saturating_add(count);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+
+    /// Add one occurrence of `item`.
+    pub fn increment<T: Hash + ?Sized>(&mut self, item: &T) {
+        self.add(item, 1)
+    }
+
+    /// Returns an upper bound of the number of occurrences of `item`.
+    pub fn estimate<T: Hash + ?Sized>(&self, item: &T) -> u64 {
+        self.cells(item)
+            .map(|cell| self.counters[cell])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Add all the counts of `other`, as if its items had been added to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If the sketches do not have the same width and depth.
+    ///
+    pub fn merge(&mut self, other: &CountMinSketch) {
+        assert!(
+            self.width == other.width && self.depth == other.depth,
+            "CountMinSketches must have the same width and depth"
+        );
+        for (counter, other) in self.counters.iter_mut().zip(&other.counters) {
+            *counter = counter.saturating_add(*other);
+        }
+        self.total = self.total.saturating_add(other.total);
+    }
+
+    /// Reset every counter to 0.
+    pub fn clear(&mut self) {
+        for counter in self.counters.iter_mut() {
+            *counter = 0;
+        }
+        self.total = 0;
+    }
+}
+
+#[cfg(test)]
+mod count_min_sketch_tests {
+    use super::*;
+
+    #[test]
+    fn error_bound() {
+        let mut sketch = CountMinSketch::new(0.01, 0.01);
+        assert_eq!(sketch.width(), 272);
+        assert_eq!(sketch.depth(), 5);
+        // item i appears i % 50 + 1 times
+        for i in 0..2000u32 {
+            sketch.add(&i, (i % 50 + 1) as u64);
+        }
+        let bound = (0.01 * sketch.total() as f64) as u64;
+        for i in 0..2000u32 {
+            let exact = (i % 50 + 1) as u64;
+            let estimate = sketch.estimate(&i);
+            assert!(estimate >= exact);
+            assert!(estimate <= exact + bound);
+        }
+    }
+
+    #[test]
+    fn merge_and_clear() {
+        let mut a = CountMinSketch::with_size(64, 4);
+        let mut b = CountMinSketch::with_size(64, 4);
+        a.add("x", 3);
+        b.add("x", 4);
+        b.increment("y");
+        a.merge(&b);
+        assert!(a.estimate("x") >= 7);
+        assert!(a.estimate("y") >= 1);
+        assert_eq!(a.total(), 8);
+        a.clear();
+        assert_eq!(a.estimate("x"), 0);
+    }
+}
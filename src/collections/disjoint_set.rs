@@ -0,0 +1,408 @@
+/// DisjointSet structure (union-find) over the elements `0..len`.
+///
+/// `find` compresses paths and `union` attaches the smaller set under the larger one,
+/// so every operation runs in amortized inverse-Ackermann time.
+///
+/// # Attributes
+///
+/// * `parent` - Parent of each element, a root is its own parent
+/// * `size` - Size of the set of each root
+/// * `sets` - Number of disjoint sets
+///
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// Create a DisjointSet of `len` singleton sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let sets = DisjointSet::new(5);
+    ///     assert_eq!(sets.num_sets(), 5);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `DisjointSet` - The DisjointSet that was created
+    ///
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Add a new singleton set and returns its element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let mut sets = DisjointSet::new(2);
+    ///     assert_eq!(sets.make_set(), 2);
+    ///     assert_eq!(sets.len(), 3);
+    /// }
+    /// ```
+    ///
+    pub fn make_set(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.sets += 1;
+        element
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the representative of the set containing `element`.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - An element lower than `len()`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let mut sets = DisjointSet::new(3);
+    ///     sets.union(0, 2);
+    ///     assert_eq!(sets.find(0), sets.find(2));
+    ///     assert_ne!(sets.find(0), sets.find(1));
+    /// }
+    /// ```
+    ///
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // path compression: every visited element now points to the root
+        let mut current = element;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - An element of the first set
+    /// * `b` - An element of the second set
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let mut sets = DisjointSet::new(3);
+    ///     assert!(sets.union(0, 1));
+    ///     assert!(!sets.union(1, 0));
+    ///     assert_eq!(sets.num_sets(), 2);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - true if two different sets were merged, false if `a` and `b` were already together
+    ///
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns true if `a` and `b` are in the same set.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing `element`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let mut sets = DisjointSet::new(4);
+    ///     sets.union(0, 1);
+    ///     sets.union(1, 2);
+    ///     assert_eq!(sets.set_size(2), 3);
+    ///     assert_eq!(sets.set_size(3), 1);
+    /// }
+    /// ```
+    ///
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn num_sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Enumerate all the sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::DisjointSet;
+    /// fn main() {
+    ///     let mut sets = DisjointSet::new(4);
+    ///     sets.union(3, 1);
+    ///     assert_eq!(sets.groups(), vec![vec![0], vec![1, 3], vec![2]]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<Vec<usize>>` - Each set with its elements in increasing order, sets ordered by their smallest element
+    ///
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of_root = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.sets);
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if group_of_root[root] == usize::MAX {
+                group_of_root[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[group_of_root[root]].push(element);
+        }
+        groups
+    }
+}
+
+/// DisjointSet with undo support, for offline algorithms (e.g. dynamic connectivity
+/// over a segment tree of time) that need to go back to an earlier state.
+///
+/// Path compression would make undoing unions expensive, so only union by size is used
+/// and `find` runs in O(log n).
+///
+/// # Attributes
+///
+/// * `parent` - Parent of each element, a root is its own parent
+/// * `size` - Size of the set of each root
+/// * `sets` - Number of disjoint sets
+/// * `history` - Roots attached by each successful union, most recent last
+///
+#[derive(Clone, Debug)]
+pub struct RollbackDisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+    history: Vec<usize>,
+}
+
+impl RollbackDisjointSet {
+    /// Create a RollbackDisjointSet of `len` singleton sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements
+    ///
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            sets: len,
+            history: Vec::new(),
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns true if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the representative of the set containing `element`.
+    pub fn find(&self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`, see [`DisjointSet::union`].
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.sets -= 1;
+        self.history.push(b);
+        true
+    }
+
+    /// Returns true if `a` and `b` are in the same set.
+    pub fn same_set(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing `element`.
+    pub fn set_size(&self, element: usize) -> usize {
+        self.size[self.find(element)]
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn num_sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns a marker of the current state, to give to `rollback`.
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undo every union made since `snapshot` was taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - A value returned by `snapshot`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::RollbackDisjointSet;
+    /// fn main() {
+    ///     let mut sets = RollbackDisjointSet::new(4);
+    ///     sets.union(0, 1);
+    ///     let snapshot = sets.snapshot();
+    ///     sets.union(1, 2);
+    ///     sets.union(2, 3);
+    ///     assert_eq!(sets.num_sets(), 1);
+    ///     sets.rollback(snapshot);
+    ///     assert_eq!(sets.num_sets(), 3);
+    ///     assert!(sets.same_set(0, 1));
+    ///     assert!(!sets.same_set(1, 2));
+    /// }
+    /// ```
+    ///
+    pub fn rollback(&mut self, snapshot: usize) {
+        while self.history.len() > snapshot {
+            if let Some(child) = self.history.pop() {
+                let root = self.parent[child];
+                self.size[root] -= self.size[child];
+                self.parent[child] = child;
+                self.sets += 1;
+            }
+        }
+    }
+
+    /// Undo the most recent successful union, returns false if there was none.
+    pub fn undo(&mut self) -> bool {
+        match self.history.len() {
+            0 => false,
+            len => {
+                self.rollback(len - 1);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod disjoint_set_tests {
+    use super::*;
+
+    #[test]
+    fn union_find() {
+        let mut sets = DisjointSet::new(10);
+        assert_eq!(sets.num_sets(), 10);
+        for i in (0..10).step_by(2) {
+            sets.union(0, i);
+        }
+        for i in (1..10).step_by(2) {
+            sets.union(9, i);
+        }
+        assert_eq!(sets.num_sets(), 2);
+        assert!(sets.same_set(2, 8));
+        assert!(sets.same_set(1, 7));
+        assert!(!sets.same_set(1, 8));
+        assert_eq!(sets.set_size(4), 5);
+        assert!(!sets.union(2, 4));
+        assert!(sets.union(3, 4));
+        assert_eq!(sets.set_size(0), 10);
+        assert_eq!(sets.groups(), vec![(0..10).collect::<Vec<_>>()]);
+    }
+
+    #[test]
+    fn make_set_and_groups() {
+        let mut sets = DisjointSet::new(0);
+        assert!(sets.is_empty());
+        let a = sets.make_set();
+        let b = sets.make_set();
+        let c = sets.make_set();
+        sets.union(c, a);
+        assert_eq!(sets.groups(), vec![vec![a, c], vec![b]]);
+    }
+
+    #[test]
+    fn rollback() {
+        let mut sets = RollbackDisjointSet::new(6);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        let snapshot = sets.snapshot();
+        sets.union(1, 3);
+        sets.union(4, 5);
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.set_size(0), 4);
+        assert!(sets.undo());
+        assert!(!sets.same_set(4, 5));
+        sets.rollback(snapshot);
+        assert_eq!(sets.num_sets(), 4);
+        assert_eq!(sets.set_size(3), 2);
+        assert!(!sets.same_set(0, 3));
+        sets.rollback(0);
+        assert_eq!(sets.num_sets(), 6);
+        assert!(!sets.undo());
+    }
+}
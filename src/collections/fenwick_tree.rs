@@ -0,0 +1,349 @@
+use super::util::range_to_bounds;
+use std::ops::{Add, Neg, RangeBounds, Sub};
+
+/// FenwickTree structure (binary indexed tree) for prefix sums.
+///
+/// Adding to an element and summing any range both take O(log n).
+///
+/// # Attributes
+///
+/// * `tree` - Node `i` (1-based) holds the sum of the `i & -i` elements ending at `i`
+///
+#[derive(Clone, Debug)]
+pub struct FenwickTree<T> {
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Create a FenwickTree of `len` elements equal to `T::default()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements
+    ///
+    pub fn new(len: usize) -> Self {
+        Self {
+            tree: vec![T::default(); len + 1],
+        }
+    }
+
+    /// Build a FenwickTree from `values` in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The initial elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::FenwickTree;
+    /// fn main() {
+    ///     let tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+    ///     assert_eq!(tree.prefix_sum(3), 6);
+    ///     assert_eq!(tree.range_sum(1..4), 9);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `FenwickTree<T>` - The FenwickTree that was created
+    ///
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut tree = Vec::with_capacity(values.len() + 1);
+        tree.push(T::default());
+        tree.extend_from_slice(values);
+        for node in 1..tree.len() {
+            let parent = node + lowest_bit(node);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[node];
+            }
+        }
+        Self { tree }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Returns true if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.tree.len() == 1
+    }
+
+    /// Add `delta` to the element at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the element, must be lower than `len()`
+    /// * `delta` - The value to add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::FenwickTree;
+    /// fn main() {
+    ///     let mut tree = FenwickTree::new(4);
+    ///     tree.add(2, 7);
+    ///     tree.add(0, 1);
+    ///     assert_eq!(tree.prefix_sum(2), 1);
+    ///     assert_eq!(tree.prefix_sum(4), 8);
+    /// }
+    /// ```
+    ///
+    pub fn add(&mut self, index: usize, delta: T) {
+        assert!(
+            index < self.len(),
+            "index {} out of bounds for length {}",
+            index,
+            self.len()
+        );
+        let mut node = index + 1;
+        while node < self.tree.len() {
+            self.tree[node] = self.tree[node] + delta;
+            node += lowest_bit(node);
+        }
+    }
+
+    /// Returns the element at `index`.
+    pub fn get(&self, index: usize) -> T {
+        self.range_sum(index..=index)
+    }
+
+    /// Returns the sum of the first `end` elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - Number of elements to sum, at most `len()`
+    ///
+    pub fn prefix_sum(&self, end: usize) -> T {
+        assert!(
+            end <= self.len(),
+            "end {} out of bounds for length {}",
+            end,
+            self.len()
+        );
+        let mut sum = T::default();
+        let mut node = end;
+        while node > 0 {
+            sum = sum + self.tree[node];
+            node -= lowest_bit(node);
+        }
+        sum
+    }
+
+    /// Returns the sum of the elements in `range`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of indices, e.g. `2..5`, `..=3` or `..`
+    ///
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = range_to_bounds(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Replace the element at `index` with `value`.
+    ///
+    /// The difference with the old value may be negative, so `T` must be signed.
+    pub fn set(&mut self, index: usize, value: T) {
+        let delta = value - self.get(index);
+        self.add(index, delta)
+    }
+}
+
+/// FenwickTree supporting range updates and range queries, both in O(log n).
+///
+/// With `d` the difference array of the updates, the sum of the first `p` updated values is
+/// `p * Σ d[j] - Σ j * d[j]` for `j < p`, so two FenwickTrees store `d[j]` and `j * d[j]`.
+/// The initial values live in a third tree so building stays O(n). Ending an update stores
+/// `-delta`, so `T` must be signed.
+///
+/// # Attributes
+///
+/// * `base` - The initial values
+/// * `slope` - The difference array `d`
+/// * `offset` - `j * d[j]` for every `j`
+///
+#[derive(Clone, Debug)]
+pub struct RangeFenwickTree<T> {
+    base: FenwickTree<T>,
+    slope: FenwickTree<T>,
+    offset: FenwickTree<T>,
+}
+
+impl<T> RangeFenwickTree<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Create a RangeFenwickTree of `len` elements equal to `T::default()`.
+    pub fn new(len: usize) -> Self {
+        Self::from_slice(&vec![T::default(); len])
+    }
+
+    /// Build a RangeFenwickTree from `values` in O(n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::RangeFenwickTree;
+    /// fn main() {
+    ///     let mut tree = RangeFenwickTree::from_slice(&[1, 1, 1, 1, 1]);
+    ///     tree.range_add(1..4, 10);
+    ///     assert_eq!(tree.range_sum(..), 35);
+    ///     assert_eq!(tree.range_sum(3..), 12);
+    /// }
+    /// ```
+    ///
+    pub fn from_slice(values: &[T]) -> Self {
+        // the difference array has one extra slot so updates may end at `len`
+        Self {
+            base: FenwickTree::from_slice(values),
+            slope: FenwickTree::new(values.len() + 1),
+            offset: FenwickTree::new(values.len() + 1),
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    /// Returns true if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+
+    /// Add `delta` to every element in `range`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of indices
+    /// * `delta` - The value to add
+    ///
+    pub fn range_add<R: RangeBounds<usize>>(&mut self, range: R, delta: T) {
+        let (start, end) = range_to_bounds(range, self.len());
+        if start == end {
+            return;
+        }
+        let negated = -delta;
+        self.slope.add(start, delta);
+        self.slope.add(end, negated);
+        self.offset.add(start, scale(delta, start));
+        self.offset.add(end, scale(negated, end));
+    }
+
+    /// Returns the element at `index`.
+    pub fn get(&self, index: usize) -> T {
+        self.range_sum(index..=index)
+    }
+
+    /// Returns the sum of the first `end` elements.
+    pub fn prefix_sum(&self, end: usize) -> T {
+        self.base.prefix_sum(end) + scale(self.slope.prefix_sum(end), end)
+            - self.offset.prefix_sum(end)
+    }
+
+    /// Returns the sum of the elements in `range`.
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = range_to_bounds(range, self.len());
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+}
+
+fn lowest_bit(node: usize) -> usize {
+    node & node.wrapping_neg()
+}
+
+// `value` added `times` times, by doubling, so only `Add` is required from `T`
+fn scale<T: Copy + Default + Add<Output = T>>(value: T, mut times: usize) -> T {
+    let mut result = T::default();
+    let mut power = value;
+    while times > 0 {
+        if times & 1 == 1 {
+            result = result + power;
+        }
+        times >>= 1;
+        if times > 0 {
+            power = power + power;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod fenwick_tree_tests {
+    use super::*;
+
+    #[test]
+    fn point_update_range_query() {
+        let mut naive: Vec<i64> = (0..41).map(|x| (x * 37) % 19 - 9).collect();
+        let mut tree = FenwickTree::from_slice(&naive);
+        assert_eq!(tree.len(), 41);
+        for step in 0..100usize {
+            let index = (step * 7) % naive.len();
+            tree.add(index, step as i64);
+            naive[index] += step as i64;
+            let start = (step * 3) % naive.len();
+            let end = start + step % (naive.len() - start + 1);
+            assert_eq!(
+                tree.range_sum(start..end),
+                naive[start..end].iter().sum::<i64>()
+            );
+        }
+        tree.set(5, 100);
+        assert_eq!(tree.get(5), 100);
+    }
+
+    #[test]
+    fn range_update_range_query() {
+        let mut naive: Vec<i64> = (0..30).collect();
+        let mut tree = RangeFenwickTree::from_slice(&naive);
+        for step in 0..100usize {
+            let start = (step * 11) % 30;
+            let end = start + (step * 5) % (31 - start);
+            let delta = (step as i64 * 17) % 13 - 6;
+            tree.range_add(start..end, delta);
+            for element in naive[start..end].iter_mut() {
+                *element += delta;
+            }
+            let (low, high) = ((step * 7) % 30, 30 - step % 10);
+            let (low, high) = (low.min(high), low.max(high));
+            assert_eq!(
+                tree.range_sum(low..high),
+                naive[low..high].iter().sum::<i64>()
+            );
+        }
+        for (index, value) in naive.iter().enumerate() {
+            assert_eq!(tree.get(index), *value);
+        }
+    }
+
+    #[test]
+    fn floats() {
+        let mut tree = RangeFenwickTree::from_slice(&[0.5, 1.5, 2.0]);
+        tree.range_add(.., 0.25);
+        assert_eq!(tree.range_sum(..), 4.75);
+    }
+
+    #[test]
+    fn unsigned() {
+        let mut tree = FenwickTree::from_slice(&[5u32, 6, 7]);
+        tree.add(1, 2);
+        assert_eq!(tree.get(1), 8);
+        assert_eq!(tree.range_sum(1..), 15);
+        let mut wide: FenwickTree<u64> = FenwickTree::new(4);
+        wide.add(3, u64::MAX - 1);
+        wide.add(0, 1);
+        assert_eq!(wide.prefix_sum(4), u64::MAX);
+        assert_eq!(wide.range_sum(1..3), 0);
+    }
+}
@@ -0,0 +1,1116 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{BuildHasher, Hash};
+use std::iter::FusedIterator;
+use std::mem;
+use std::ops::Index;
+use std::slice;
+use std::vec;
+
+const MIN_CAPACITY: usize = 8;
+// The maximum load factor (7/8) is kept as a fraction to stay in integer arithmetic
+const MAX_LOAD_NUMERATOR: usize = 7;
+const MAX_LOAD_DENOMINATOR: usize = 8;
+
+#[derive(Clone)]
+struct Bucket<K, V> {
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+/// Distance between the bucket `index` and the ideal bucket of `hash`.
+fn displacement(hash: u64, index: usize, mask: usize) -> usize {
+    index.wrapping_sub(hash as usize & mask) & mask
+}
+
+/// HashMap structure using open addressing with Robin Hood hashing.
+///
+/// Every entry remembers how far it sits from its ideal bucket. On insertion an entry
+/// that is further from home than the resident one takes its place ("steals from the rich"),
+/// which keeps probe sequences short and lets lookups stop early. Removal shifts the
+/// following entries back instead of leaving tombstones.
+///
+/// # Attributes
+///
+/// * `buckets` - The table, its size is always zero or a power of two
+/// * `length` - Number of entries in the map
+/// * `hash_builder` - The `BuildHasher` used to hash the keys
+///
+#[derive(Clone)]
+pub struct HashMap<K, V, S = RandomState> {
+    buckets: Vec<Option<Bucket<K, V>>>,
+    length: usize,
+    hash_builder: S,
+}
+
+/// Probe-length statistics of a `HashMap`, see [`HashMap::probe_stats`].
+///
+/// # Attributes
+///
+/// * `max_probe_length` - Number of buckets examined by the worst successful lookup
+/// * `mean_probe_length` - Mean number of buckets examined by a successful lookup
+/// * `histogram` - `histogram[i]` is the number of entries found after examining `i + 1` buckets
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbeStats {
+    pub max_probe_length: usize,
+    pub mean_probe_length: f64,
+    pub histogram: Vec<usize>,
+}
+
+impl<K, V> HashMap<K, V, RandomState> {
+    /// Create an empty HashMap, it does not allocate until the first insertion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map: HashMap<&str, i32> = HashMap::new();
+    ///     map.insert("one", 1);
+    ///     assert_eq!(map.get("one"), Some(&1));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `HashMap<K, V>` - The HashMap that was created
+    ///
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Create an empty HashMap able to hold at least `capacity` entries without resizing.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of entries to make room for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let map: HashMap<i32, i32> = HashMap::with_capacity(100);
+    ///     assert!(map.capacity() >= 100);
+    /// }
+    /// ```
+    ///
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S> {
+    /// Create an empty HashMap which will use `hash_builder` to hash the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash_builder` - Any `BuildHasher`, e.g. `RandomState` or `BuildHasherDefault<H>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    /// fn main() {
+    ///     let mut map = HashMap::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    ///     map.insert(1, "a");
+    ///     assert_eq!(map[&1], "a");
+    /// }
+    /// ```
+    ///
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            buckets: Vec::new(),
+            length: 0,
+            hash_builder,
+        }
+    }
+
+    /// Create an empty HashMap with room for `capacity` entries, using `hash_builder` to hash the keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Number of entries to make room for
+    /// * `hash_builder` - The `BuildHasher` used to hash the keys
+    ///
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        let mut map = Self::with_hasher(hash_builder);
+        if capacity > 0 {
+            map.resize(Self::buckets_for(capacity));
+        }
+        map
+    }
+
+    /// Returns the number of entries the map can hold without resizing.
+    pub fn capacity(&self) -> usize {
+        self.buckets.len() * MAX_LOAD_NUMERATOR / MAX_LOAD_DENOMINATOR
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the ratio between the number of entries and the number of buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     assert_eq!(map.load_factor(), 0.0);
+    ///     map.insert(1, 1);
+    ///     assert!(map.load_factor() > 0.0 && map.load_factor() <= 0.875);
+    /// }
+    /// ```
+    ///
+    pub fn load_factor(&self) -> f64 {
+        if self.buckets.is_empty() {
+            return 0.0;
+        }
+        self.length as f64 / self.buckets.len() as f64
+    }
+
+    /// Returns a reference to the map's `BuildHasher`.
+    pub fn hasher(&self) -> &S {
+        &self.hash_builder
+    }
+
+    /// Removes all entries, keeping the allocated buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     map.insert(1, 1);
+    ///     map.clear();
+    ///     assert!(map.is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn clear(&mut self) {
+        for bucket in self.buckets.iter_mut() {
+            *bucket = None;
+        }
+        self.length = 0;
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let map: HashMap<i32, i32> = (0..5).map(|x| (x, x * x)).collect();
+    ///     let total: i32 = map.iter().map(|(_, value)| value).sum();
+    ///     assert_eq!(total, 30);
+    /// }
+    /// ```
+    ///
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            buckets: self.buckets.iter(),
+            remaining: self.length,
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order, with mutable references to the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            buckets: self.buckets.iter_mut(),
+            remaining: self.length,
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// An iterator visiting all values mutably in arbitrary order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+
+    /// Keeps only the entries for which `keep` returns true.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Predicate receiving each key and a mutable reference to its value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map: HashMap<i32, i32> = (0..10).map(|x| (x, x)).collect();
+    ///     map.retain(|key, _| key % 2 == 0);
+    ///     assert_eq!(map.len(), 5);
+    /// }
+    /// ```
+    ///
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut keep: F) {
+        // backward shifts could wrap visited entries around, so the kept entries are reinserted instead
+        let mut table = Vec::with_capacity(self.buckets.len());
+        table.resize_with(self.buckets.len(), || None);
+        let old = mem::replace(&mut self.buckets, table);
+        self.length = 0;
+        for mut bucket in old.into_iter().flatten() {
+            if keep(&bucket.key, &mut bucket.value) {
+                self.insert_new(bucket);
+            }
+        }
+    }
+
+    /// Computes probe-length statistics of the current table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let map: HashMap<i32, i32> = (0..1000).map(|x| (x, x)).collect();
+    ///     let stats = map.probe_stats();
+    ///     assert_eq!(stats.histogram.iter().sum::<usize>(), 1000);
+    ///     assert!(stats.mean_probe_length >= 1.0);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `ProbeStats` - The maximum and mean probe lengths and their histogram
+    ///
+    pub fn probe_stats(&self) -> ProbeStats {
+        let mask = self.mask();
+        let mut histogram: Vec<usize> = Vec::new();
+        let mut total = 0;
+        for (index, bucket) in self.buckets.iter().enumerate() {
+            if let Some(bucket) = bucket {
+                let distance = displacement(bucket.hash, index, mask);
+                if histogram.len() <= distance {
+                    histogram.resize(distance + 1, 0);
+                }
+                histogram[distance] += 1;
+                total += distance + 1;
+            }
+        }
+        ProbeStats {
+            max_probe_length: histogram.len(),
+            mean_probe_length: if self.length == 0 {
+                0.0
+            } else {
+                total as f64 / self.length as f64
+            },
+            histogram,
+        }
+    }
+
+    fn mask(&self) -> usize {
+        self.buckets.len().wrapping_sub(1)
+    }
+
+    // Number of buckets needed to hold `capacity` entries under the maximum load factor
+    fn buckets_for(capacity: usize) -> usize {
+        let buckets = capacity * MAX_LOAD_DENOMINATOR / MAX_LOAD_NUMERATOR + 1;
+        buckets.next_power_of_two().max(MIN_CAPACITY)
+    }
+
+    // Rebuild the table with `buckets` buckets, entries keep their stored hash so no rehashing is needed
+    fn resize(&mut self, buckets: usize) {
+        let mut table = Vec::with_capacity(buckets);
+        table.resize_with(buckets, || None);
+        let old = mem::replace(&mut self.buckets, table);
+        self.length = 0;
+        for bucket in old.into_iter().flatten() {
+            self.insert_new(bucket);
+        }
+    }
+
+    // Make sure `additional` more entries fit under the maximum load factor
+    fn grow(&mut self, additional: usize) {
+        let needed = self.length + additional;
+        if needed * MAX_LOAD_DENOMINATOR > self.buckets.len() * MAX_LOAD_NUMERATOR {
+            self.resize(Self::buckets_for(needed).max(self.buckets.len() * 2));
+        }
+    }
+
+    // Place a bucket whose key is not in the map and return the index where it ended up.
+    // The table must have room for it.
+    fn insert_new(&mut self, mut bucket: Bucket<K, V>) -> usize {
+        let mask = self.mask();
+        let mut index = bucket.hash as usize & mask;
+        let mut distance = 0;
+        let mut placed = None;
+        loop {
+            match &mut self.buckets[index] {
+                None => {
+                    self.buckets[index] = Some(bucket);
+                    self.length += 1;
+                    return placed.unwrap_or(index);
+                }
+                Some(resident) => {
+                    let resident_distance = displacement(resident.hash, index, mask);
+                    if resident_distance < distance {
+                        // the resident is closer to home than us: take its bucket and carry it on
+                        mem::swap(resident, &mut bucket);
+                        placed.get_or_insert(index);
+                        distance = resident_distance;
+                    }
+                }
+            }
+            index = (index + 1) & mask;
+            distance += 1;
+        }
+    }
+
+    // Remove the bucket at `index` and shift the following displaced entries back by one
+    fn remove_at(&mut self, index: usize) -> Bucket<K, V> {
+        let mask = self.mask();
+        let removed = self.buckets[index].take().expect("bucket is occupied");
+        let mut hole = index;
+        loop {
+            let next = (hole + 1) & mask;
+            match &self.buckets[next] {
+                Some(bucket) if displacement(bucket.hash, next, mask) > 0 => {
+                    self.buckets[hole] = self.buckets[next].take();
+                    hole = next;
+                }
+                _ => break,
+            }
+        }
+        self.length -= 1;
+        removed
+    }
+
+    fn bucket(&self, index: usize) -> &Bucket<K, V> {
+        self.buckets[index].as_ref().expect("bucket is occupied")
+    }
+
+    fn bucket_mut(&mut self, index: usize) -> &mut Bucket<K, V> {
+        self.buckets[index].as_mut().expect("bucket is occupied")
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> HashMap<K, V, S> {
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq,
+    {
+        if self.length == 0 {
+            return None;
+        }
+        let mask = self.mask();
+        let mut index = hash as usize & mask;
+        let mut distance = 0;
+        while let Some(bucket) = &self.buckets[index] {
+            // Robin Hood invariant: our key would have displaced this entry, so it is absent
+            if distance > displacement(bucket.hash, index, mask) {
+                return None;
+            }
+            if bucket.hash == hash && bucket.key.borrow() == key {
+                return Some(index);
+            }
+            index = (index + 1) & mask;
+            distance += 1;
+        }
+        None
+    }
+
+    fn find_key<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find(self.hash_builder.hash_one(key), key)
+    }
+
+    /// Reserves room for at least `additional` more entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - Number of entries about to be inserted
+    ///
+    pub fn reserve(&mut self, additional: usize) {
+        self.grow(additional)
+    }
+
+    /// Shrinks the table as much as possible while respecting the maximum load factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map: HashMap<i32, i32> = HashMap::with_capacity(1000);
+    ///     map.insert(1, 1);
+    ///     map.shrink_to_fit();
+    ///     assert!(map.capacity() < 1000);
+    ///     assert_eq!(map.get(&1), Some(&1));
+    /// }
+    /// ```
+    ///
+    pub fn shrink_to_fit(&mut self) {
+        if self.length == 0 {
+            self.buckets = Vec::new();
+        } else {
+            let buckets = Self::buckets_for(self.length);
+            if buckets < self.buckets.len() {
+                self.resize(buckets);
+            }
+        }
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry
+    /// * `value` - The value to associate with `key`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     assert_eq!(map.insert(37, "a"), None);
+    ///     assert_eq!(map.insert(37, "b"), Some("a"));
+    ///     assert_eq!(map[&37], "b");
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Option<V>` - The previous value of `key`, or None if it was not in the map
+    ///
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for, any borrowed form of the key type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     map.insert(String::from("key"), 1);
+    ///     assert_eq!(map.get("key"), Some(&1));
+    ///     assert_eq!(map.get("other"), None);
+    /// }
+    /// ```
+    ///
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find_key(key).map(|index| &self.bucket(index).value)
+    }
+
+    /// Returns the key-value pair corresponding to the key.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find_key(key).map(|index| {
+            let bucket = self.bucket(index);
+            (&bucket.key, &bucket.value)
+        })
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     map.insert(1, 10);
+    ///     if let Some(value) = map.get_mut(&1) {
+    ///         *value += 1;
+    ///     }
+    ///     assert_eq!(map[&1], 11);
+    /// }
+    /// ```
+    ///
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        match self.find_key(key) {
+            Some(index) => Some(&mut self.bucket_mut(index).value),
+            None => None,
+        }
+    }
+
+    /// Returns true if the map contains a value for the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find_key(key).is_some()
+    }
+
+    /// Removes a key from the map, returning its value if the key was in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     map.insert(1, "a");
+    ///     assert_eq!(map.remove(&1), Some("a"));
+    ///     assert_eq!(map.remove(&1), None);
+    /// }
+    /// ```
+    ///
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes a key from the map, returning the stored key and its value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find_key(key).map(|index| {
+            let bucket = self.remove_at(index);
+            (bucket.key, bucket.value)
+        })
+    }
+
+    /// Gets the entry of `key` for in-place manipulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut counts = HashMap::new();
+    ///     for word in "a b a c a".split(' ') {
+    ///         *counts.entry(word).or_insert(0) += 1;
+    ///     }
+    ///     assert_eq!(counts["a"], 3);
+    /// }
+    /// ```
+    ///
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hash_builder.hash_one(&key);
+        match self.find(hash, &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                key,
+            }),
+        }
+    }
+}
+
+impl<K, V, S: Default> Default for HashMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Debug, V: Debug, S> Debug for HashMap<K, V, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> PartialEq for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, S: BuildHasher> Eq for HashMap<K, V, S> {}
+
+impl<K, Q, V, S> Index<&Q> for HashMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: ?Sized + Hash + Eq,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in HashMap")
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for HashMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for HashMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// A view into a single entry of a `HashMap`, which may be vacant or occupied.
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+/// A view into an occupied entry of a `HashMap`.
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    index: usize,
+}
+
+/// A view into a vacant entry of a `HashMap`.
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S> {
+    /// Returns the value of the entry, inserting `default` if it is vacant.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Returns the value of the entry, inserting the result of `default` if it is vacant.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Returns the value of the entry, inserting `default(&key)` if it is vacant.
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(&entry.key);
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Calls `modify` on the value if the entry is occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::HashMap;
+    /// fn main() {
+    ///     let mut map = HashMap::new();
+    ///     map.entry("a").and_modify(|x| *x += 1).or_insert(1);
+    ///     map.entry("a").and_modify(|x| *x += 1).or_insert(1);
+    ///     assert_eq!(map["a"], 2);
+    /// }
+    /// ```
+    ///
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, modify: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            modify(entry.get_mut());
+        }
+        self
+    }
+
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+impl<'a, K, V: Default, S> Entry<'a, K, V, S> {
+    /// Returns the value of the entry, inserting `V::default()` if it is vacant.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.map.bucket(self.index).key
+    }
+
+    /// Returns a reference to the value of the entry.
+    pub fn get(&self) -> &V {
+        &self.map.bucket(self.index).value
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.bucket_mut(self.index).value
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to the map's lifetime.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.bucket_mut(self.index).value
+    }
+
+    /// Replaces the value of the entry and returns the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map and returns its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the map and returns its key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        let bucket = self.map.remove_at(self.index);
+        (bucket.key, bucket.value)
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S> {
+    /// Returns the key that would be used when inserting through this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes ownership of the key.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts `value` under the entry's key and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let map = self.map;
+        map.grow(1);
+        let index = map.insert_new(Bucket {
+            hash: self.hash,
+            key: self.key,
+            value,
+        });
+        &mut map.bucket_mut(index).value
+    }
+}
+
+/// Iterator over the entries of a `HashMap`, see [`HashMap::iter`].
+pub struct Iter<'a, K, V> {
+    buckets: slice::Iter<'a, Option<Bucket<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let bucket = self.buckets.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((&bucket.key, &bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Mutable iterator over the entries of a `HashMap`, see [`HashMap::iter_mut`].
+pub struct IterMut<'a, K, V> {
+    buckets: slice::IterMut<'a, Option<Bucket<K, V>>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let bucket = self.buckets.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((&bucket.key, &mut bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Iterator over the keys of a `HashMap`, see [`HashMap::keys`].
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+/// Iterator over the values of a `HashMap`, see [`HashMap::values`].
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+/// Mutable iterator over the values of a `HashMap`, see [`HashMap::values_mut`].
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for ValuesMut<'_, K, V> {}
+
+/// Owning iterator over the entries of a `HashMap`.
+pub struct IntoIter<K, V> {
+    buckets: vec::IntoIter<Option<Bucket<K, V>>>,
+    remaining: usize,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let bucket = self.buckets.by_ref().flatten().next()?;
+        self.remaining -= 1;
+        Some((bucket.key, bucket.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            remaining: self.length,
+            buckets: self.buckets.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod hash_map_tests {
+    use super::*;
+    use std::hash::{BuildHasherDefault, Hasher};
+
+    // Sends every key to the same bucket, forcing the longest possible probe sequences
+    #[derive(Default)]
+    struct CollidingHasher;
+
+    impl Hasher for CollidingHasher {
+        fn finish(&self) -> u64 {
+            0
+        }
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    type Colliding = BuildHasherDefault<CollidingHasher>;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = HashMap::new();
+        assert!(map.is_empty());
+        for i in 0..1000 {
+            assert_eq!(map.insert(i, i * 2), None);
+        }
+        assert_eq!(map.len(), 1000);
+        assert_eq!(map.insert(10, 0), Some(20));
+        for i in 0..1000 {
+            assert!(map.contains_key(&i));
+        }
+        assert_eq!(map.get(&1000), None);
+        for i in (0..1000).step_by(2) {
+            assert!(map.remove(&i).is_some());
+        }
+        assert_eq!(map.len(), 500);
+        for i in 0..1000 {
+            assert_eq!(map.get(&i).is_some(), i % 2 == 1);
+        }
+    }
+
+    #[test]
+    fn backward_shift_with_collisions() {
+        let mut map: HashMap<i32, i32, Colliding> = HashMap::default();
+        for i in 0..20 {
+            map.insert(i, i);
+        }
+        assert_eq!(map.probe_stats().max_probe_length, 20);
+        assert_eq!(map.remove(&0), Some(0));
+        assert_eq!(map.remove(&7), Some(7));
+        for i in 1..20 {
+            assert_eq!(map.get(&i).is_some(), i != 7);
+        }
+        // no tombstones: the cluster is contiguous from the home bucket
+        assert_eq!(map.probe_stats().max_probe_length, 18);
+        assert_eq!(map.probe_stats().histogram, vec![1; 18]);
+    }
+
+    #[test]
+    fn load_factor_and_resize() {
+        let mut map = HashMap::new();
+        for i in 0..10_000 {
+            map.insert(i, ());
+            assert!(map.load_factor() <= 0.875);
+        }
+        assert!(map.capacity() >= 10_000);
+        map.retain(|key, _| *key < 10);
+        map.shrink_to_fit();
+        assert!(map.capacity() < 100);
+        assert_eq!(map.len(), 10);
+    }
+
+    #[test]
+    fn entry_api() {
+        let mut map: HashMap<String, usize> = HashMap::new();
+        for word in "the quick the lazy the dog".split(' ') {
+            *map.entry(word.to_string()).or_default() += 1;
+        }
+        assert_eq!(map["the"], 3);
+        match map.entry(String::from("dog")) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+            Entry::Vacant(_) => panic!("dog should be present"),
+        }
+        match map.entry(String::from("cat")) {
+            Entry::Occupied(_) => panic!("cat should be absent"),
+            Entry::Vacant(entry) => *entry.insert(4) += 1,
+        }
+        assert_eq!(map.get("cat"), Some(&5));
+        assert_eq!(map.get("dog"), None);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut map: HashMap<i32, i32> = (0..100).map(|x| (x, x)).collect();
+        assert_eq!(map.iter().len(), 100);
+        for value in map.values_mut() {
+            *value *= 2;
+        }
+        let mut keys: Vec<i32> = map.keys().copied().collect();
+        keys.sort();
+        assert_eq!(keys, (0..100).collect::<Vec<_>>());
+        assert_eq!(map.values().sum::<i32>(), 9900);
+        let mut pairs: Vec<(i32, i32)> = map.clone().into_iter().collect();
+        pairs.sort();
+        assert_eq!(pairs[3], (3, 6));
+        let other: HashMap<i32, i32> = pairs.into_iter().collect();
+        assert_eq!(map, other);
+    }
+}
@@ -0,0 +1,585 @@
+use std::cmp::{self, Ordering};
+use std::fmt::{self, Debug, Formatter};
+use std::mem;
+use std::ops::Range;
+
+type Link<K, V> = Option<Box<IntervalNode<K, V>>>;
+
+#[derive(Clone, Debug)]
+struct IntervalNode<K, V> {
+    range: Range<K>,
+    value: V,
+    max_end: K,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+impl<K: Ord + Clone, V> IntervalNode<K, V> {
+    fn new(range: Range<K>, value: V) -> Self {
+        Self {
+            max_end: range.end.clone(),
+            range,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn cmp_range(&self, range: &Range<K>) -> Ordering {
+        (&range.start, &range.end).cmp(&(&self.range.start, &self.range.end))
+    }
+
+    fn balance(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+
+    // Recompute the height and the augmented maximum end from the children
+    fn update(&mut self) {
+        self.height = 1 + cmp::max(height(&self.left), height(&self.right));
+        let mut max_end = &self.range.end;
+        for child in [&self.left, &self.right].into_iter().flatten() {
+            if child.max_end > *max_end {
+                max_end = &child.max_end;
+            }
+        }
+        self.max_end = max_end.clone();
+    }
+}
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn rotate_right<K: Ord + Clone, V>(mut node: Box<IntervalNode<K, V>>) -> Box<IntervalNode<K, V>> {
+    let mut left = node.left.take().expect("rotation needs a left child");
+    node.left = left.right.take();
+    node.update();
+    left.right = Some(node);
+    left.update();
+    left
+}
+
+fn rotate_left<K: Ord + Clone, V>(mut node: Box<IntervalNode<K, V>>) -> Box<IntervalNode<K, V>> {
+    let mut right = node.right.take().expect("rotation needs a right child");
+    node.right = right.left.take();
+    node.update();
+    right.left = Some(node);
+    right.update();
+    right
+}
+
+// Restore the AVL property at `node` after one of its subtrees changed height by one
+fn rebalance<K: Ord + Clone, V>(mut node: Box<IntervalNode<K, V>>) -> Box<IntervalNode<K, V>> {
+    node.update();
+    let balance = node.balance();
+    if balance > 1 {
+        if node.left.as_ref().is_some_and(|left| left.balance() < 0) {
+            node.left = node.left.take().map(rotate_left);
+        }
+        return rotate_right(node);
+    }
+    if balance < -1 {
+        if node.right.as_ref().is_some_and(|right| right.balance() > 0) {
+            node.right = node.right.take().map(rotate_right);
+        }
+        return rotate_left(node);
+    }
+    node
+}
+
+/// IntervalTree structure, a map from half-open intervals `start..end` to values that finds
+/// every interval overlapping a range or containing a point.
+///
+/// The intervals are kept in an AVL tree ordered by `(start, end)`, and every node also stores
+/// the largest `end` of its subtree, so whole subtrees that end before the query are skipped.
+/// Queries take O(log n + m) for m results.
+///
+/// # Attributes
+///
+/// * `root` - The root node of the tree
+/// * `length` - Number of intervals
+///
+#[derive(Clone)]
+pub struct IntervalTree<K, V> {
+    root: Link<K, V>,
+    length: usize,
+}
+
+impl<K: Ord + Clone, V> IntervalTree<K, V> {
+    /// Create an empty IntervalTree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::IntervalTree;
+    /// fn main() {
+    ///     let mut meetings = IntervalTree::new();
+    ///     meetings.insert(9..11, "standup");
+    ///     meetings.insert(10..12, "review");
+    ///     meetings.insert(14..15, "one to one");
+    ///     let at_ten: Vec<&str> = meetings.containing(&10).map(|(_, name)| *name).collect();
+    ///     assert_eq!(at_ten, vec!["standup", "review"]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `IntervalTree<K, V>` - The IntervalTree that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Returns the number of intervals.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the tree contains no interval.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Removes all intervals.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.length = 0;
+    }
+
+    /// Inserts an interval with its value, in O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - A non empty half-open interval `start..end`
+    /// * `value` - The value to associate with `range`
+    ///
+    /// # Panics
+    ///
+    /// If `range` is empty.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<V>` - The previous value of exactly the same interval, or None
+    ///
+    pub fn insert(&mut self, range: Range<K>, value: V) -> Option<V> {
+        assert!(range.start < range.end, "intervals must not be empty");
+        let mut previous = None;
+        self.root = Some(Self::insert_node(
+            self.root.take(),
+            range,
+            value,
+            &mut previous,
+        ));
+        if previous.is_none() {
+            self.length += 1;
+        }
+        previous
+    }
+
+    fn insert_node(
+        link: Link<K, V>,
+        range: Range<K>,
+        value: V,
+        previous: &mut Option<V>,
+    ) -> Box<IntervalNode<K, V>> {
+        let mut node = match link {
+            None => return Box::new(IntervalNode::new(range, value)),
+            Some(node) => node,
+        };
+        match node.cmp_range(&range) {
+            Ordering::Less => {
+                node.left = Some(Self::insert_node(node.left.take(), range, value, previous))
+            }
+            Ordering::Greater => {
+                node.right = Some(Self::insert_node(node.right.take(), range, value, previous))
+            }
+            Ordering::Equal => {
+                *previous = Some(mem::replace(&mut node.value, value));
+                return node;
+            }
+        }
+        rebalance(node)
+    }
+
+    /// Removes exactly the interval `range`, returning its value if it was in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::IntervalTree;
+    /// fn main() {
+    ///     let mut tree = IntervalTree::new();
+    ///     tree.insert(1..5, 'a');
+    ///     assert_eq!(tree.remove(&(1..4)), None);
+    ///     assert_eq!(tree.remove(&(1..5)), Some('a'));
+    ///     assert!(tree.is_empty());
+    /// }
+    /// ```
+    ///
+    pub fn remove(&mut self, range: &Range<K>) -> Option<V> {
+        let mut removed = None;
+        self.root = Self::remove_node(self.root.take(), range, &mut removed);
+        if removed.is_some() {
+            self.length -= 1;
+        }
+        removed
+    }
+
+    fn remove_node(link: Link<K, V>, range: &Range<K>, removed: &mut Option<V>) -> Link<K, V> {
+        let mut node = link?;
+        match node.cmp_range(range) {
+            Ordering::Less => node.left = Self::remove_node(node.left.take(), range, removed),
+            Ordering::Greater => node.right = Self::remove_node(node.right.take(), range, removed),
+            Ordering::Equal => {
+                let node = *node;
+                *removed = Some(node.value);
+                return match (node.left, node.right) {
+                    (None, child) | (child, None) => child,
+                    (Some(left), Some(right)) => {
+                        // the smallest interval of the right subtree takes the place of the removed node
+                        let (right, mut successor) = Self::remove_min(right);
+                        successor.left = Some(left);
+                        successor.right = right;
+                        Some(rebalance(successor))
+                    }
+                };
+            }
+        }
+        Some(rebalance(node))
+    }
+
+    // Detach the smallest node of the subtree, returns the remaining subtree and that node
+    fn remove_min(mut node: Box<IntervalNode<K, V>>) -> (Link<K, V>, Box<IntervalNode<K, V>>) {
+        match node.left.take() {
+            None => (node.right.take(), node),
+            Some(left) => {
+                let (left, min) = Self::remove_min(left);
+                node.left = left;
+                (Some(rebalance(node)), min)
+            }
+        }
+    }
+
+    /// Returns the value of exactly the interval `range`.
+    pub fn get(&self, range: &Range<K>) -> Option<&V> {
+        let mut current = self.root.as_ref();
+        while let Some(node) = current {
+            current = match node.cmp_range(range) {
+                Ordering::Less => node.left.as_ref(),
+                Ordering::Greater => node.right.as_ref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// An iterator over the intervals overlapping `range`, ordered by `(start, end)`.
+    ///
+    /// Two half-open intervals overlap when they share at least one point, so `1..3` and `3..5` do not,
+    /// and an empty `range` such as `5..5` overlaps nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The half-open interval to compare with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::IntervalTree;
+    /// fn main() {
+    ///     let tree: IntervalTree<i32, ()> = [0..2, 1..4, 3..5, 6..9].into_iter().map(|r| (r, ())).collect();
+    ///     let found: Vec<_> = tree.overlapping(&(2..4)).map(|(range, _)| range.clone()).collect();
+    ///     assert_eq!(found, vec![1..4, 3..5]);
+    /// }
+    /// ```
+    ///
+    pub fn overlapping<'a>(&'a self, range: &'a Range<K>) -> Overlapping<'a, K, V> {
+        let root = if range.start < range.end {
+            self.root.as_deref()
+        } else {
+            None
+        };
+        Overlapping::new(root, &range.start, &range.end, false)
+    }
+
+    /// An iterator over the intervals containing `point`, that is `start <= point < end`.
+    pub fn containing<'a>(&'a self, point: &'a K) -> Overlapping<'a, K, V> {
+        Overlapping::new(self.root.as_deref(), point, point, true)
+    }
+
+    /// An iterator over all the intervals, ordered by `(start, end)`.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.length,
+        };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+
+    /// Returns the union of all the intervals of the tree, see [`merge_overlapping`].
+    pub fn merged(&self) -> Vec<Range<K>> {
+        merge_overlapping(self.iter().map(|(range, _)| range.clone()).collect())
+    }
+}
+
+impl<K: Ord + Clone, V> Default for IntervalTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Clone + Debug, V: Debug> Debug for IntervalTree<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord + Clone, V> FromIterator<(Range<K>, V)> for IntervalTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        for (range, value) in iter {
+            tree.insert(range, value);
+        }
+        tree
+    }
+}
+
+/// Iterator over the intervals overlapping a range, see [`IntervalTree::overlapping`] and [`IntervalTree::containing`].
+pub struct Overlapping<'a, K, V> {
+    stack: Vec<&'a IntervalNode<K, V>>,
+    start: &'a K,
+    end: &'a K,
+    end_inclusive: bool,
+}
+
+impl<'a, K: Ord, V> Overlapping<'a, K, V> {
+    fn new(
+        root: Option<&'a IntervalNode<K, V>>,
+        start: &'a K,
+        end: &'a K,
+        end_inclusive: bool,
+    ) -> Self {
+        let mut iter = Self {
+            stack: Vec::new(),
+            start,
+            end,
+            end_inclusive,
+        };
+        iter.push_left(root);
+        iter
+    }
+
+    // Intervals starting at or after the end of the query cannot overlap it
+    fn starts_before_end(&self, node: &IntervalNode<K, V>) -> bool {
+        if self.end_inclusive {
+            node.range.start <= *self.end
+        } else {
+            node.range.start < *self.end
+        }
+    }
+
+    fn push_left(&mut self, mut link: Option<&'a IntervalNode<K, V>>) {
+        while let Some(node) = link {
+            // no interval of this subtree ends after the query starts
+            if node.max_end <= *self.start {
+                break;
+            }
+            self.stack.push(node);
+            link = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K: Ord, V> Iterator for Overlapping<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            if !self.starts_before_end(node) {
+                // the right subtree starts even later, and the left one is already on the stack
+                continue;
+            }
+            self.push_left(node.right.as_deref());
+            if node.range.end > *self.start {
+                return Some((&node.range, &node.value));
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over all the intervals of an `IntervalTree`, see [`IntervalTree::iter`].
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a IntervalNode<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left(&mut self, mut link: Option<&'a IntervalNode<K, V>>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.range, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// Merge overlapping intervals into the smallest set of disjoint intervals covering the same points.
+///
+/// Touching intervals such as `1..3` and `3..5` are merged too, since their union is `1..5`.
+/// Empty intervals are dropped.
+///
+/// # Arguments
+///
+/// * `intervals` - Half-open intervals, in any order
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::collections::interval_tree::merge_overlapping;
+/// fn main() {
+///     let merged = merge_overlapping(vec![8..10, 1..3, 2..6, 6..7, 15..18]);
+///     assert_eq!(merged, vec![1..7, 8..10, 15..18]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Vec<Range<K>>` - The merged intervals, sorted by start
+///
+pub fn merge_overlapping<K: Ord + Clone>(mut intervals: Vec<Range<K>>) -> Vec<Range<K>> {
+    intervals.retain(|range| range.start < range.end);
+    intervals.sort_by(|a, b| a.start.cmp(&b.start));
+    let mut merged: Vec<Range<K>> = Vec::with_capacity(intervals.len());
+    for range in intervals {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => {
+                if range.end > last.end {
+                    last.end = range.end;
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod interval_tree_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    fn check_balanced<K: Ord + Clone, V>(link: &Link<K, V>) -> usize {
+        match link {
+            None => 0,
+            Some(node) => {
+                let left = check_balanced(&node.left);
+                let right = check_balanced(&node.right);
+                assert!(left.abs_diff(right) <= 1);
+                assert_eq!(node.height, 1 + left.max(right));
+                node.height
+            }
+        }
+    }
+
+    #[test]
+    fn matches_naive_queries() {
+        let mut rng = Rng::new(3);
+        let mut tree = IntervalTree::new();
+        let mut naive: Vec<(Range<usize>, usize)> = Vec::new();
+        for step in 0..600 {
+            let start = rng.gen_range(0..200);
+            let range = start..start + rng.gen_range(1..30);
+            if step % 4 == 3 && !naive.is_empty() {
+                let (range, value) = naive.swap_remove(rng.gen_range(0..naive.len()));
+                assert_eq!(tree.remove(&range), Some(value));
+            } else if !naive.iter().any(|(existing, _)| *existing == range) {
+                tree.insert(range.clone(), step);
+                naive.push((range, step));
+            }
+        }
+        check_balanced(&tree.root);
+        assert_eq!(tree.len(), naive.len());
+        naive.sort_by_key(|(range, _)| (range.start, range.end));
+        assert!(tree
+            .iter()
+            .map(|(range, value)| (range.clone(), *value))
+            .eq(naive.clone()));
+        for query_start in (0..230).step_by(7) {
+            let query = query_start..query_start + 5;
+            let expected: Vec<_> = naive
+                .iter()
+                .filter(|(range, _)| range.start < query.end && query.start < range.end)
+                .map(|(range, value)| (range.clone(), *value))
+                .collect();
+            let found: Vec<_> = tree
+                .overlapping(&query)
+                .map(|(range, value)| (range.clone(), *value))
+                .collect();
+            assert_eq!(found, expected);
+            let expected = naive
+                .iter()
+                .filter(|(range, _)| range.contains(&query_start))
+                .count();
+            assert_eq!(tree.containing(&query_start).count(), expected);
+        }
+    }
+
+    #[test]
+    fn empty_query() {
+        let tree: IntervalTree<i32, ()> =
+            [0..10, 4..6, 5..7].into_iter().map(|r| (r, ())).collect();
+        assert_eq!(tree.overlapping(&(5..5)).count(), 0);
+        assert_eq!(tree.overlapping(&Range { start: 6, end: 4 }).count(), 0);
+        assert_eq!(tree.containing(&5).count(), 3);
+    }
+
+    #[test]
+    fn insert_replace_remove() {
+        let mut tree = IntervalTree::new();
+        assert_eq!(tree.insert(1..4, "a"), None);
+        assert_eq!(tree.insert(1..4, "b"), Some("a"));
+        assert_eq!(tree.insert(1..6, "c"), None);
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.get(&(1..4)), Some(&"b"));
+        assert_eq!(tree.remove(&(1..4)), Some("b"));
+        assert_eq!(tree.remove(&(1..4)), None);
+        assert_eq!(tree.containing(&5).count(), 1);
+        assert_eq!(tree.containing(&6).count(), 0);
+    }
+
+    #[test]
+    fn merge() {
+        assert_eq!(merge_overlapping::<i32>(vec![]), vec![]);
+        assert_eq!(merge_overlapping(vec![5..6, 1..10, 2..3]), vec![1..10]);
+        let tree: IntervalTree<i32, ()> =
+            [(0..2, ()), (4..6, ()), (1..3, ())].into_iter().collect();
+        assert_eq!(tree.merged(), vec![0..3, 4..6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_interval() {
+        IntervalTree::new().insert(3..3, ());
+    }
+}
@@ -217,13 +217,18 @@ impl<T: Copy + PartialEq> LinkedList<T> {
         }
     }
 
-    fn get_back(&mut self) -> Option<T> {
+    fn get_back(&self) -> Option<T> {
         match &self.last {
             Some(last) => Some(last.borrow_mut().value),
             None => None,
         }
     }
-    fn get_back_ref(&mut self) -> Option<Ref<T>> {
+
+    fn get_front(&self) -> Option<T> {
+        self.head.as_ref().map(|head| head.borrow().value)
+    }
+
+    fn get_back_ref(&self) -> Option<Ref<T>> {
         self.last
             .as_ref()
             .map(|node| Ref::map(node.borrow(), |node| &node.value))
@@ -249,10 +254,29 @@ impl<T: Copy + PartialEq> LinkedList<T> {
     /// }
     /// ```
     ///
-    pub fn back(&mut self) -> Option<T> {
+    pub fn back(&self) -> Option<T> {
         self.get_back()
     }
 
+    /// Provides value of front element, or None if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::LinkedList;
+    /// fn main() {
+    ///     let mut liste : LinkedList<i32> = LinkedList::new();
+    ///     assert_eq!(liste.front(), None);
+    ///     liste.push_back(5);
+    ///     liste.push_back(6);
+    ///     assert_eq!(liste.front(), Some(5));
+    /// }
+    /// ```
+    ///
+    pub fn front(&self) -> Option<T> {
+        self.get_front()
+    }
+
     /// Provides a reference to the back element, or None if the list is empty.
     ///
     /// # Examples
@@ -267,7 +291,7 @@ impl<T: Copy + PartialEq> LinkedList<T> {
     /// }
     /// ```
     ///
-    pub fn back_ref(&mut self) -> Option<Ref<T>> {
+    pub fn back_ref(&self) -> Option<Ref<T>> {
         self.get_back_ref()
     }
 
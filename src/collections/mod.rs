@@ -1,5 +1,35 @@
 #[doc(hidden)]
 pub mod linked_list;
+#[doc(hidden)]
+pub mod hash_map;
+#[doc(hidden)]
+pub mod disjoint_set;
+#[doc(hidden)]
+pub mod segment_tree;
+#[doc(hidden)]
+pub mod fenwick_tree;
+#[doc(hidden)]
+pub mod skip_list;
+#[doc(hidden)]
+pub mod bloom_filter;
+#[doc(hidden)]
+pub mod count_min_sketch;
+#[doc(hidden)]
+pub mod interval_tree;
+#[doc(hidden)]
+pub mod bit_set;
+#[doc(hidden)]
+pub mod rank_select;
+#[doc(hidden)]
+pub mod rope;
+#[doc(hidden)]
+pub mod unrolled_list;
+#[doc(hidden)]
+pub mod stack;
+#[doc(hidden)]
+pub mod queue;
+pub mod sparse;
+mod util;
 
 #[doc(inline)]
 // pub use l_list::LinkedList;
@@ -7,3 +37,45 @@ pub use linked_list::LinkedList;
 
 #[doc(inline)]
 pub use linked_list::Node;
+
+#[doc(inline)]
+pub use hash_map::{Entry, HashMap, ProbeStats};
+
+#[doc(inline)]
+pub use disjoint_set::{DisjointSet, RollbackDisjointSet};
+
+#[doc(inline)]
+pub use segment_tree::{Gcd, Max, Min, Monoid, SegmentTree, Sum};
+
+#[doc(inline)]
+pub use fenwick_tree::{FenwickTree, RangeFenwickTree};
+
+#[doc(inline)]
+pub use skip_list::SkipList;
+
+#[doc(inline)]
+pub use bloom_filter::BloomFilter;
+
+#[doc(inline)]
+pub use count_min_sketch::CountMinSketch;
+
+#[doc(inline)]
+pub use interval_tree::IntervalTree;
+
+#[doc(inline)]
+pub use bit_set::BitSet;
+
+#[doc(inline)]
+pub use rank_select::RankSelect;
+
+#[doc(inline)]
+pub use rope::Rope;
+
+#[doc(inline)]
+pub use unrolled_list::UnrolledList;
+
+#[doc(inline)]
+pub use stack::{MinStack, Stack};
+
+#[doc(inline)]
+pub use queue::{MaxQueue, Queue};
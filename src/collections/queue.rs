@@ -0,0 +1,243 @@
+use super::LinkedList;
+use std::fmt::{self, Debug, Formatter};
+
+/// Queue structure, a first in first out adapter over `LinkedList`.
+///
+/// # Attributes
+///
+/// * `list` - The elements, entering at the back and leaving from the front
+///
+pub struct Queue<T: Copy + PartialEq> {
+    list: LinkedList<T>,
+}
+
+impl<T: Copy + PartialEq> Queue<T> {
+    /// Create an empty Queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Queue;
+    /// fn main() {
+    ///     let mut queue = Queue::new();
+    ///     queue.enqueue(1);
+    ///     queue.enqueue(2);
+    ///     assert_eq!(queue.peek(), Some(1));
+    ///     assert_eq!(queue.dequeue(), Some(1));
+    ///     assert_eq!(queue.dequeue(), Some(2));
+    ///     assert_eq!(queue.dequeue(), None);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Queue<T>` - The Queue that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Add `value` at the back of the queue.
+    pub fn enqueue(&mut self, value: T) {
+        self.list.push_back(value)
+    }
+
+    /// Removes the front element and returns it, or None if the queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Provides value of the front element, the next to leave, or None if the queue is empty.
+    pub fn peek(&self) -> Option<T> {
+        self.list.front()
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+}
+
+impl<T: Copy + PartialEq> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + PartialEq + Debug> Debug for Queue<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Queue(len: {}, front: {:?})", self.len(), self.peek())
+    }
+}
+
+impl<T: Copy + PartialEq> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        for value in iter {
+            queue.enqueue(value);
+        }
+        queue
+    }
+}
+
+impl<T: Copy + PartialEq> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = super::linked_list::IntoIter<T>;
+    /// Dequeues the values from the front to the back.
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+/// MaxQueue structure, a Queue that also reports its maximum in O(1).
+///
+/// Next to the values, a monotonic deque keeps the values that may still become the
+/// maximum, in non increasing order: a new value evicts the smaller ones from its back,
+/// since they leave the queue before it. Every operation is amortized O(1), which makes
+/// the maximum of a sliding window O(1) per step.
+///
+/// # Attributes
+///
+/// * `values` - The elements
+/// * `candidates` - The monotonic deque, the current maximum at its front
+///
+pub struct MaxQueue<T: Copy + PartialOrd> {
+    values: Queue<T>,
+    candidates: LinkedList<T>,
+}
+
+impl<T: Copy + PartialOrd> MaxQueue<T> {
+    /// Create an empty MaxQueue.
+    ///
+    /// # Examples
+    ///
+    /// Maximum of every window of 3 values:
+    ///
+    /// ```
+    /// use rust_algo::collections::MaxQueue;
+    /// fn main() {
+    ///     let values = [1, 3, -1, -3, 5, 3, 6, 7];
+    ///     let mut window = MaxQueue::new();
+    ///     let mut maximums = Vec::new();
+    ///     for (index, &value) in values.iter().enumerate() {
+    ///         window.enqueue(value);
+    ///         if index >= 3 {
+    ///             window.dequeue();
+    ///         }
+    ///         if index >= 2 {
+    ///             maximums.push(window.max().unwrap());
+    ///         }
+    ///     }
+    ///     assert_eq!(maximums, vec![3, 3, 5, 5, 6, 7]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `MaxQueue<T>` - The MaxQueue that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            values: Queue::new(),
+            candidates: LinkedList::new(),
+        }
+    }
+
+    /// Add `value` at the back of the queue.
+    pub fn enqueue(&mut self, value: T) {
+        // equal values are kept, so dequeuing one of them keeps the others counted
+        while self.candidates.back().is_some_and(|last| last < value) {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back(value);
+        self.values.enqueue(value);
+    }
+
+    /// Removes the front element and returns it, or None if the queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let value = self.values.dequeue()?;
+        if self.candidates.front() == Some(value) {
+            self.candidates.pop_front();
+        }
+        Some(value)
+    }
+
+    /// Provides value of the front element, or None if the queue is empty.
+    pub fn peek(&self) -> Option<T> {
+        self.values.peek()
+    }
+
+    /// Returns the largest element in O(1), or None if the queue is empty.
+    pub fn max(&self) -> Option<T> {
+        self.candidates.front()
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.candidates.clear();
+    }
+}
+
+impl<T: Copy + PartialOrd> Default for MaxQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod queue_tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn queue() {
+        let mut queue: Queue<i32> = (0..5).collect();
+        assert_eq!(queue.peek(), Some(0));
+        queue.enqueue(10);
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 10]);
+    }
+
+    #[test]
+    fn max_queue_matches_naive() {
+        let mut queue = MaxQueue::new();
+        let mut naive: VecDeque<i64> = VecDeque::new();
+        for step in 0..500i64 {
+            if step % 5 == 3 || step % 7 == 0 {
+                assert_eq!(queue.dequeue(), naive.pop_front());
+            } else {
+                let value = (step * 41) % 17;
+                queue.enqueue(value);
+                naive.push_back(value);
+            }
+            assert_eq!(queue.max(), naive.iter().copied().max());
+            assert_eq!(queue.peek(), naive.front().copied());
+        }
+        queue.clear();
+        assert_eq!(queue.max(), None);
+    }
+}
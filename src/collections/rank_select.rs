@@ -0,0 +1,263 @@
+use super::BitSet;
+
+const WORD_BITS: usize = 64;
+const WORDS_PER_BLOCK: usize = 8;
+const BLOCK_BITS: usize = WORD_BITS * WORDS_PER_BLOCK;
+
+/// RankSelect structure, an immutable bit vector answering rank in O(1) and select in O(log n).
+///
+/// Two levels of counts are stored next to the bits: the number of ones before every
+/// block of 512 bits, and for every word the number of ones before it inside its block.
+/// A rank query adds both counts to the popcount of a masked word. This costs about 37%
+/// of extra space, and is the building block of FM-indexes and wavelet trees.
+///
+/// # Attributes
+///
+/// * `words` - The bits
+/// * `length` - Number of bits
+/// * `blocks` - Number of ones before each block of 8 words
+/// * `word_ranks` - Number of ones before each word, counted from the start of its block
+///
+#[derive(Clone, Debug)]
+pub struct RankSelect {
+    words: Vec<u64>,
+    length: usize,
+    blocks: Vec<u64>,
+    word_ranks: Vec<u16>,
+}
+
+impl RankSelect {
+    /// Build the rank directory of `bits` in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `bits` - The bits to index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{BitSet, RankSelect};
+    /// fn main() {
+    ///     let bits = BitSet::from_bools(&[true, false, true, true, false]);
+    ///     let index = RankSelect::new(&bits);
+    ///     assert_eq!(index.rank1(3), 2);
+    ///     assert_eq!(index.select1(2), Some(3));
+    ///     assert_eq!(index.select0(1), Some(4));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `RankSelect` - The RankSelect that was created
+    ///
+    pub fn new(bits: &BitSet) -> Self {
+        let words = bits.as_words().to_vec();
+        let mut blocks = Vec::with_capacity(words.len() / WORDS_PER_BLOCK + 1);
+        let mut word_ranks = Vec::with_capacity(words.len());
+        let mut total = 0u64;
+        let mut in_block = 0u16;
+        for (index, word) in words.iter().enumerate() {
+            if index % WORDS_PER_BLOCK == 0 {
+                blocks.push(total);
+                in_block = 0;
+            }
+            word_ranks.push(in_block);
+            in_block += word.count_ones() as u16;
+            total += word.count_ones() as u64;
+        }
+        // sentinel block holding the total, so select can binary search over all blocks
+        blocks.push(total);
+        Self {
+            words,
+            length: bits.len(),
+            blocks,
+            word_ranks,
+        }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if there is no bit.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of bits set to 1.
+    pub fn count_ones(&self) -> usize {
+        self.blocks[self.blocks.len() - 1] as usize
+    }
+
+    /// Returns the number of bits set to 0.
+    pub fn count_zeros(&self) -> usize {
+        self.length - self.count_ones()
+    }
+
+    /// Returns the value of the bit at `index`.
+    pub fn get(&self, index: usize) -> bool {
+        assert!(
+            index < self.length,
+            "bit {} out of bounds for length {}",
+            index,
+            self.length
+        );
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Returns the number of ones in the first `index` bits, in O(1).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Number of bits to count, at most `len()`
+    ///
+    pub fn rank1(&self, index: usize) -> usize {
+        assert!(
+            index <= self.length,
+            "rank {} out of bounds for length {}",
+            index,
+            self.length
+        );
+        let word = index / WORD_BITS;
+        if word == self.words.len() {
+            return self.count_ones();
+        }
+        let mask = (1u64 << (index % WORD_BITS)) - 1;
+        self.blocks[word / WORDS_PER_BLOCK] as usize
+            + self.word_ranks[word] as usize
+            + (self.words[word] & mask).count_ones() as usize
+    }
+
+    /// Returns the number of zeros in the first `index` bits, in O(1).
+    pub fn rank0(&self, index: usize) -> usize {
+        index - self.rank1(index)
+    }
+
+    /// Returns the position of the one of rank `rank` (the first one has rank 0), in O(log n).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{BitSet, RankSelect};
+    /// fn main() {
+    ///     let mut bits = BitSet::new(1000);
+    ///     for i in (0..1000).step_by(7) {
+    ///         bits.set(i);
+    ///     }
+    ///     let index = RankSelect::new(&bits);
+    ///     assert_eq!(index.select1(100), Some(700));
+    ///     assert_eq!(index.select1(1000), None);
+    /// }
+    /// ```
+    ///
+    pub fn select1(&self, rank: usize) -> Option<usize> {
+        if rank >= self.count_ones() {
+            return None;
+        }
+        self.select(rank, |_, ones| ones, |word| word)
+    }
+
+    /// Returns the position of the zero of rank `rank`, in O(log n).
+    pub fn select0(&self, rank: usize) -> Option<usize> {
+        if rank >= self.count_zeros() {
+            return None;
+        }
+        // the padding bits of the last word are ones once inverted, but rank < count_zeros never reaches them
+        self.select(rank, |position, ones| position - ones, |word| !word)
+    }
+
+    // Shared select: `count(position, ones)` turns a number of ones before `position` into the
+    // number of wanted bits, and `view` turns a word into a word whose set bits are the wanted ones.
+    fn select<C, W>(&self, rank: usize, count: C, view: W) -> Option<usize>
+    where
+        C: Fn(usize, usize) -> usize,
+        W: Fn(u64) -> u64,
+    {
+        let rank = rank as u64;
+        // last block whose count of wanted bits before it is at most `rank`
+        let (mut low, mut high) = (0, self.blocks.len() - 1);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if count(middle * BLOCK_BITS, self.blocks[middle] as usize) as u64 <= rank {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        let block = low;
+        let first_word = block * WORDS_PER_BLOCK;
+        let last_word = (first_word + WORDS_PER_BLOCK).min(self.words.len());
+        let mut word = first_word;
+        for next in first_word + 1..last_word {
+            let ones = self.blocks[block] as usize + self.word_ranks[next] as usize;
+            if count(next * WORD_BITS, ones) as u64 > rank {
+                break;
+            }
+            word = next;
+        }
+        let ones = self.blocks[block] as usize + self.word_ranks[word] as usize;
+        let mut remaining = rank - count(word * WORD_BITS, ones) as u64;
+        let mut bits = view(self.words[word]);
+        while remaining > 0 {
+            bits &= bits - 1;
+            remaining -= 1;
+        }
+        let position = word * WORD_BITS + bits.trailing_zeros() as usize;
+        (position < self.length).then_some(position)
+    }
+}
+
+impl From<BitSet> for RankSelect {
+    fn from(bits: BitSet) -> Self {
+        Self::new(&bits)
+    }
+}
+
+#[cfg(test)]
+mod rank_select_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    #[test]
+    fn matches_naive() {
+        let mut rng = Rng::new(9);
+        for len in [0, 1, 63, 64, 65, 511, 512, 513, 3000] {
+            let values: Vec<bool> = (0..len).map(|_| rng.next_bool(0.3)).collect();
+            let index = RankSelect::new(&BitSet::from_bools(&values));
+            let mut ones = 0;
+            let mut one_positions = Vec::new();
+            let mut zero_positions = Vec::new();
+            for (position, &value) in values.iter().enumerate() {
+                assert_eq!(index.rank1(position), ones);
+                assert_eq!(index.rank0(position), position - ones);
+                assert_eq!(index.get(position), value);
+                if value {
+                    ones += 1;
+                    one_positions.push(position);
+                } else {
+                    zero_positions.push(position);
+                }
+            }
+            assert_eq!(index.rank1(len), ones);
+            for (rank, position) in one_positions.iter().enumerate() {
+                assert_eq!(index.select1(rank), Some(*position));
+            }
+            for (rank, position) in zero_positions.iter().enumerate() {
+                assert_eq!(index.select0(rank), Some(*position));
+            }
+            assert_eq!(index.select1(one_positions.len()), None);
+            assert_eq!(index.select0(zero_positions.len()), None);
+        }
+    }
+
+    #[test]
+    fn dense_and_sparse() {
+        let index = RankSelect::from(BitSet::new_filled(2000));
+        assert_eq!(index.select1(1999), Some(1999));
+        assert_eq!(index.select0(0), None);
+        let index = RankSelect::from(BitSet::new(2000));
+        assert_eq!(index.select0(1234), Some(1234));
+        assert_eq!(index.rank1(2000), 0);
+    }
+}
@@ -0,0 +1,833 @@
+use super::util::range_to_bounds;
+use crate::searching::boyer_moore_horspool_chunks;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::ops::RangeBounds;
+
+// Leaves are merged while they fit in this many bytes
+const MAX_LEAF_BYTES: usize = 1024;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+struct Metrics {
+    bytes: usize,
+    chars: usize,
+    newlines: usize,
+}
+
+impl Metrics {
+    fn of(text: &str) -> Self {
+        Self {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            newlines: text.bytes().filter(|&byte| byte == b'\n').count(),
+        }
+    }
+
+    fn add(self, other: Metrics) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Node {
+    Leaf {
+        text: String,
+        metrics: Metrics,
+    },
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        metrics: Metrics,
+        height: usize,
+    },
+}
+
+impl Node {
+    fn leaf(text: String) -> Self {
+        let metrics = Metrics::of(&text);
+        Node::Leaf { text, metrics }
+    }
+
+    fn branch(left: Node, right: Node) -> Self {
+        Node::Branch {
+            metrics: left.metrics().add(right.metrics()),
+            height: 1 + left.height().max(right.height()),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn metrics(&self) -> Metrics {
+        match self {
+            Node::Leaf { metrics, .. } | Node::Branch { metrics, .. } => *metrics,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf { .. } => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    // Balanced tree over `text`, cut in leaves of at most MAX_LEAF_BYTES on char boundaries
+    fn build(text: &str) -> Self {
+        let mut leaves = Vec::new();
+        let mut rest = text;
+        while rest.len() > MAX_LEAF_BYTES {
+            let mut cut = MAX_LEAF_BYTES;
+            while !rest.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            leaves.push(Node::leaf(rest[..cut].to_string()));
+            rest = &rest[cut..];
+        }
+        leaves.push(Node::leaf(rest.to_string()));
+        Self::build_balanced(leaves)
+    }
+
+    fn build_balanced(mut leaves: Vec<Node>) -> Self {
+        if leaves.len() == 1 {
+            return leaves.pop().unwrap();
+        }
+        let right = leaves.split_off(leaves.len() / 2);
+        Node::branch(Self::build_balanced(leaves), Self::build_balanced(right))
+    }
+}
+
+// Concatenate two trees, keeping the AVL invariant: the taller tree is walked down its
+// inner spine until the heights match, and every node on the way back is rebalanced.
+fn join(left: Node, right: Node) -> Node {
+    if left.metrics().bytes == 0 {
+        return right;
+    }
+    if right.metrics().bytes == 0 {
+        return left;
+    }
+    match (left, right) {
+        (Node::Leaf { text: mut a, .. }, Node::Leaf { text: b, .. })
+            if a.len() + b.len() <= MAX_LEAF_BYTES =>
+        {
+            a.push_str(&b);
+            Node::leaf(a)
+        }
+        (left, right) if left.height() > right.height() + 1 => match left {
+            Node::Branch {
+                left: outer,
+                right: inner,
+                ..
+            } => rebalance(Node::branch(*outer, join(*inner, right))),
+            Node::Leaf { .. } => unreachable!(),
+        },
+        (left, right) if right.height() > left.height() + 1 => match right {
+            Node::Branch {
+                left: inner,
+                right: outer,
+                ..
+            } => rebalance(Node::branch(join(left, *inner), *outer)),
+            Node::Leaf { .. } => unreachable!(),
+        },
+        (left, right) => Node::branch(left, right),
+    }
+}
+
+// Single or double rotation of a branch whose children heights differ by 2
+fn rebalance(node: Node) -> Node {
+    let (left, right) = match node {
+        Node::Branch { left, right, .. } => (*left, *right),
+        leaf => return leaf,
+    };
+    if left.height() > right.height() + 1 {
+        let (outer, inner) = children(left);
+        if outer.height() >= inner.height() {
+            Node::branch(outer, Node::branch(inner, right))
+        } else {
+            let (inner_left, inner_right) = children(inner);
+            Node::branch(
+                Node::branch(outer, inner_left),
+                Node::branch(inner_right, right),
+            )
+        }
+    } else if right.height() > left.height() + 1 {
+        let (inner, outer) = children(right);
+        if outer.height() >= inner.height() {
+            Node::branch(Node::branch(left, inner), outer)
+        } else {
+            let (inner_left, inner_right) = children(inner);
+            Node::branch(
+                Node::branch(left, inner_left),
+                Node::branch(inner_right, outer),
+            )
+        }
+    } else {
+        Node::branch(left, right)
+    }
+}
+
+fn children(node: Node) -> (Node, Node) {
+    match node {
+        Node::Branch { left, right, .. } => (*left, *right),
+        Node::Leaf { .. } => unreachable!("a leaf has no children"),
+    }
+}
+
+// Split in the first `char_index` chars and the rest
+fn split(node: Node, char_index: usize) -> (Node, Node) {
+    match node {
+        Node::Leaf { mut text, .. } => {
+            let byte = char_to_byte_in(&text, char_index);
+            let right = text[byte..].to_string();
+            text.truncate(byte);
+            (Node::leaf(text), Node::leaf(right))
+        }
+        Node::Branch { left, right, .. } => {
+            let left_chars = left.metrics().chars;
+            if char_index < left_chars {
+                let (a, b) = split(*left, char_index);
+                (a, join(b, *right))
+            } else if char_index == left_chars {
+                (*left, *right)
+            } else {
+                let (a, b) = split(*right, char_index - left_chars);
+                (join(*left, a), b)
+            }
+        }
+    }
+}
+
+fn char_to_byte_in(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(byte, _)| byte)
+}
+
+/// Rope structure, a text stored as a balanced tree of string chunks.
+///
+/// Inserting or deleting anywhere costs O(log n) plus the size of a chunk, instead of
+/// moving the whole tail of a `String`. Every node caches the number of bytes, chars and
+/// newlines below it, so char, byte and line positions are converted in O(log n) too.
+/// Positions are counted in chars (Unicode scalar values) and lines are separated by `'\n'`.
+///
+/// # Attributes
+///
+/// * `root` - The tree, an AVL tree whose leaves hold the chunks in order
+///
+#[derive(Clone)]
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    /// Create an empty Rope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let mut rope = Rope::new();
+    ///     rope.insert(0, "world");
+    ///     rope.insert(0, "hello ");
+    ///     assert_eq!(rope.to_string(), "hello world");
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Rope` - The Rope that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            root: Node::leaf(String::new()),
+        }
+    }
+
+    /// Returns the number of chars.
+    pub fn len_chars(&self) -> usize {
+        self.root.metrics().chars
+    }
+
+    /// Returns the number of bytes of the UTF-8 text.
+    pub fn len_bytes(&self) -> usize {
+        self.root.metrics().bytes
+    }
+
+    /// Returns the number of lines, one more than the number of `'\n'`.
+    pub fn len_lines(&self) -> usize {
+        self.root.metrics().newlines + 1
+    }
+
+    /// Returns true if the Rope holds no text.
+    pub fn is_empty(&self) -> bool {
+        self.len_bytes() == 0
+    }
+
+    /// Insert `text` before the char at `char_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `char_index` - Position of the insertion, at most `len_chars()`
+    /// * `text` - The text to insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let mut rope = Rope::from("Hello, wörld!");
+    ///     rope.insert(9, "o");
+    ///     assert_eq!(rope.to_string(), "Hello, wöorld!");
+    /// }
+    /// ```
+    ///
+    pub fn insert(&mut self, char_index: usize, text: &str) {
+        self.check(char_index);
+        if text.is_empty() {
+            return;
+        }
+        let root = std::mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (left, right) = split(root, char_index);
+        self.root = join(join(left, Node::build(text)), right);
+    }
+
+    /// Remove the chars in `range`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of char indices, e.g. `2..5`, `..=3` or `..`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let mut rope = Rope::from("Hello, world!");
+    ///     rope.delete(5..12);
+    ///     assert_eq!(rope.to_string(), "Hello!");
+    /// }
+    /// ```
+    ///
+    pub fn delete<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = range_to_bounds(range, self.len_chars());
+        if start == end {
+            return;
+        }
+        let root = std::mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (left, rest) = split(root, start);
+        let (_, right) = split(rest, end - start);
+        self.root = join(left, right);
+    }
+
+    /// Returns a new Rope holding a copy of the chars in `range`.
+    ///
+    /// Only the chunks overlapping `range` are visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let rope = Rope::from("Hello, world!");
+    ///     assert_eq!(rope.slice(7..12).to_string(), "world");
+    /// }
+    /// ```
+    ///
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Rope {
+        let (start, end) = range_to_bounds(range, self.len_chars());
+        let mut text = String::new();
+        collect_range(&self.root, start, end, &mut text);
+        Rope::from(text.as_str())
+    }
+
+    /// Returns the char at `char_index`.
+    pub fn char(&self, char_index: usize) -> char {
+        assert!(
+            char_index < self.len_chars(),
+            "char {} out of bounds for length {}",
+            char_index,
+            self.len_chars()
+        );
+        let (text, offset) = self.leaf_at_char(char_index);
+        text.chars().nth(offset).unwrap()
+    }
+
+    // The leaf holding the char at `char_index`, and the index of the char inside it
+    fn leaf_at_char(&self, mut char_index: usize) -> (&str, usize) {
+        let mut node = &self.root;
+        loop {
+            match node {
+                Node::Leaf { text, .. } => return (text, char_index),
+                Node::Branch { left, right, .. } => {
+                    let left_chars = left.metrics().chars;
+                    if char_index < left_chars {
+                        node = left;
+                    } else {
+                        char_index -= left_chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the byte offset of the char at `char_index`.
+    pub fn char_to_byte(&self, char_index: usize) -> usize {
+        self.check(char_index);
+        let mut node = &self.root;
+        let mut remaining = char_index;
+        let mut bytes = 0;
+        loop {
+            match node {
+                Node::Leaf { text, .. } => return bytes + char_to_byte_in(text, remaining),
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if remaining < left_metrics.chars {
+                        node = left;
+                    } else {
+                        remaining -= left_metrics.chars;
+                        bytes += left_metrics.bytes;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the char containing the byte at `byte_index`.
+    pub fn byte_to_char(&self, byte_index: usize) -> usize {
+        assert!(
+            byte_index <= self.len_bytes(),
+            "byte {} out of bounds for length {}",
+            byte_index,
+            self.len_bytes()
+        );
+        let mut node = &self.root;
+        let mut remaining = byte_index;
+        let mut chars = 0;
+        loop {
+            match node {
+                Node::Leaf { text, metrics } => {
+                    if remaining >= text.len() {
+                        return chars + metrics.chars;
+                    }
+                    let starts = text.char_indices().take_while(|(b, _)| *b <= remaining);
+                    return chars + starts.count() - 1;
+                }
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if remaining < left_metrics.bytes {
+                        node = left;
+                    } else {
+                        remaining -= left_metrics.bytes;
+                        chars += left_metrics.chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the line containing the char at `char_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let rope = Rope::from("one\ntwo\nthree");
+    ///     assert_eq!(rope.char_to_line(0), 0);
+    ///     assert_eq!(rope.char_to_line(5), 1);
+    ///     assert_eq!(rope.line_to_char(2), 8);
+    /// }
+    /// ```
+    ///
+    pub fn char_to_line(&self, char_index: usize) -> usize {
+        self.check(char_index);
+        let mut node = &self.root;
+        let mut remaining = char_index;
+        let mut lines = 0;
+        loop {
+            match node {
+                Node::Leaf { text, .. } => {
+                    return lines + text.chars().take(remaining).filter(|&c| c == '\n').count()
+                }
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if remaining < left_metrics.chars {
+                        node = left;
+                    } else {
+                        remaining -= left_metrics.chars;
+                        lines += left_metrics.newlines;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the first char of the line `line_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_index` - The line, lower than `len_lines()`
+    ///
+    pub fn line_to_char(&self, line_index: usize) -> usize {
+        assert!(
+            line_index < self.len_lines(),
+            "line {} out of bounds for {} lines",
+            line_index,
+            self.len_lines()
+        );
+        if line_index == 0 {
+            return 0;
+        }
+        // the line starts right after the `line_index`-th newline
+        let mut node = &self.root;
+        let mut remaining = line_index;
+        let mut chars = 0;
+        loop {
+            match node {
+                Node::Leaf { text, .. } => {
+                    let position = text
+                        .chars()
+                        .enumerate()
+                        .filter(|(_, c)| *c == '\n')
+                        .nth(remaining - 1)
+                        .map(|(position, _)| position)
+                        .unwrap();
+                    return chars + position + 1;
+                }
+                Node::Branch { left, right, .. } => {
+                    let left_metrics = left.metrics();
+                    if remaining <= left_metrics.newlines {
+                        node = left;
+                    } else {
+                        remaining -= left_metrics.newlines;
+                        chars += left_metrics.chars;
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the line `line_index`, without its `'\n'`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let rope = Rope::from("one\ntwo\nthree");
+    ///     assert_eq!(rope.line(1), "two");
+    ///     assert_eq!(rope.line(2), "three");
+    /// }
+    /// ```
+    ///
+    pub fn line(&self, line_index: usize) -> String {
+        let start = self.line_to_char(line_index);
+        let end = if line_index + 1 < self.len_lines() {
+            self.line_to_char(line_index + 1) - 1
+        } else {
+            self.len_chars()
+        };
+        let mut text = String::new();
+        collect_range(&self.root, start, end, &mut text);
+        text
+    }
+
+    /// Returns the char index of the first occurrence of `needle`, searching the chunks in
+    /// place with `searching::boyer_moore_horspool_chunks`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let mut rope = Rope::from("the café is open");
+    ///     assert_eq!(rope.find("is"), Some(9));
+    ///     rope.insert(9, "really ");
+    ///     assert_eq!(rope.find("is"), Some(16));
+    ///     assert_eq!(rope.find("closed"), None);
+    /// }
+    /// ```
+    ///
+    pub fn find(&self, needle: &str) -> Option<usize> {
+        let chunks: Vec<&str> = self.chunks().collect();
+        boyer_moore_horspool_chunks(needle, &chunks).map(|byte| self.byte_to_char(byte))
+    }
+
+    /// An iterator over the chunks of text, in order.
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+
+    /// An iterator over the chars.
+    pub fn chars(&self) -> Chars<'_> {
+        Chars {
+            chunks: self.chunks(),
+            current: "".chars(),
+        }
+    }
+
+    /// An iterator over the `len_lines()` lines, without their `'\n'`.
+    ///
+    /// Unlike `str::lines`, a text ending with `'\n'` has a last empty line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Rope;
+    /// fn main() {
+    ///     let rope = Rope::from("a\nb\n");
+    ///     assert_eq!(rope.lines().collect::<Vec<_>>(), vec!["a", "b", ""]);
+    /// }
+    /// ```
+    ///
+    pub fn lines(&self) -> Lines<'_> {
+        Lines {
+            chars: self.chars(),
+            done: false,
+        }
+    }
+
+    fn check(&self, char_index: usize) {
+        assert!(
+            char_index <= self.len_chars(),
+            "char {} out of bounds for length {}",
+            char_index,
+            self.len_chars()
+        );
+    }
+}
+
+// Append the chars `start..end` of `node` to `out`, skipping the subtrees outside the range
+fn collect_range(node: &Node, start: usize, end: usize, out: &mut String) {
+    if start >= end {
+        return;
+    }
+    match node {
+        Node::Leaf { text, .. } => {
+            let from = char_to_byte_in(text, start);
+            let to = char_to_byte_in(text, end);
+            out.push_str(&text[from..to]);
+        }
+        Node::Branch { left, right, .. } => {
+            let left_chars = left.metrics().chars;
+            collect_range(left, start, end.min(left_chars), out);
+            collect_range(
+                right,
+                start.saturating_sub(left_chars),
+                end.saturating_sub(left_chars),
+                out,
+            );
+        }
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        Self {
+            root: Node::build(text),
+        }
+    }
+}
+
+impl From<String> for Rope {
+    fn from(text: String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Rope {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl PartialEq for Rope {
+    fn eq(&self, other: &Rope) -> bool {
+        self.len_bytes() == other.len_bytes()
+            && self
+                .chunks()
+                .flat_map(str::bytes)
+                .eq(other.chunks().flat_map(str::bytes))
+    }
+}
+
+impl Eq for Rope {}
+
+impl PartialEq<&str> for Rope {
+    fn eq(&self, other: &&str) -> bool {
+        self.len_bytes() == other.len() && self.chunks().flat_map(str::bytes).eq(other.bytes())
+    }
+}
+
+/// Iterator over the chunks of a `Rope`, see [`Rope::chunks`].
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            match self.stack.pop()? {
+                Node::Leaf { text, .. } if !text.is_empty() => return Some(text),
+                Node::Leaf { .. } => {}
+                Node::Branch { left, right, .. } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the chars of a `Rope`, see [`Rope::chars`].
+pub struct Chars<'a> {
+    chunks: Chunks<'a>,
+    current: std::str::Chars<'a>,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.current.next() {
+                return Some(c);
+            }
+            self.current = self.chunks.next()?.chars();
+        }
+    }
+}
+
+/// Iterator over the lines of a `Rope`, see [`Rope::lines`].
+pub struct Lines<'a> {
+    chars: Chars<'a>,
+    done: bool,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = String;
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        let mut line = String::new();
+        for c in self.chars.by_ref() {
+            if c == '\n' {
+                return Some(line);
+            }
+            line.push(c);
+        }
+        self.done = true;
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod rope_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    fn check_balanced(node: &Node) {
+        if let Node::Branch { left, right, .. } = node {
+            assert!(left.height().abs_diff(right.height()) <= 1);
+            check_balanced(left);
+            check_balanced(right);
+        }
+    }
+
+    #[test]
+    fn edits_match_string() {
+        let mut rng = Rng::new(33);
+        let alphabet: Vec<char> = "abc dé\nfg€𝄞".chars().collect();
+        let mut rope = Rope::new();
+        let mut naive: Vec<char> = Vec::new();
+        for _ in 0..2000 {
+            if naive.is_empty() || rng.next_bool(0.6) {
+                let at = rng.gen_range(0..naive.len() + 1);
+                let text: String = (0..rng.gen_range(1..300))
+                    .map(|_| alphabet[rng.gen_range(0..alphabet.len())])
+                    .collect();
+                rope.insert(at, &text);
+                naive.splice(at..at, text.chars());
+            } else {
+                let start = rng.gen_range(0..naive.len());
+                let end = rng.gen_range(start..(start + 200).min(naive.len()) + 1);
+                rope.delete(start..end);
+                naive.drain(start..end);
+            }
+            assert_eq!(rope.len_chars(), naive.len());
+        }
+        check_balanced(&rope.root);
+        let text: String = naive.iter().collect();
+        assert_eq!(rope.to_string(), text);
+        assert_eq!(rope.len_bytes(), text.len());
+        assert!(rope.chars().eq(text.chars()));
+        assert_eq!(rope, text.as_str());
+        for index in (0..naive.len()).step_by(97) {
+            assert_eq!(rope.char(index), naive[index]);
+            let byte = rope.char_to_byte(index);
+            assert_eq!(text[byte..].chars().next(), Some(naive[index]));
+            assert_eq!(rope.byte_to_char(byte), index);
+            let slice: String = naive[index..(index + 500).min(naive.len())]
+                .iter()
+                .collect();
+            assert_eq!(
+                rope.slice(index..index + slice.chars().count()),
+                slice.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn lines() {
+        let text: String = (0..3000).map(|i| format!("line {}\n", i)).collect();
+        let rope = Rope::from(text.as_str());
+        assert_eq!(rope.len_lines(), 3001);
+        assert_eq!(rope.line(1234), "line 1234");
+        assert_eq!(rope.line(3000), "");
+        let start = rope.line_to_char(2000);
+        assert_eq!(rope.char_to_line(start), 2000);
+        assert_eq!(rope.char_to_line(start - 1), 1999);
+        let lines: Vec<String> = rope.lines().collect();
+        assert_eq!(lines.len(), 3001);
+        assert!(lines.iter().zip(text.split('\n')).all(|(a, b)| a == b));
+        assert_eq!(Rope::new().lines().count(), 1);
+    }
+
+    #[test]
+    fn find_across_chunks() {
+        let mut text = "é".repeat(MAX_LEAF_BYTES);
+        text.push_str("needle in a haystack");
+        let mut rope = Rope::from(text.as_str());
+        assert!(rope.chunks().count() > 2);
+        assert_eq!(rope.find("needle"), Some(MAX_LEAF_BYTES));
+        rope.insert(MAX_LEAF_BYTES + 3, "-");
+        assert_eq!(rope.find("needle"), None);
+        assert_eq!(rope.find("ee-dle"), Some(MAX_LEAF_BYTES + 1));
+        assert_eq!(rope.find("éne"), Some(MAX_LEAF_BYTES - 1));
+    }
+}
@@ -0,0 +1,464 @@
+use super::util::range_to_bounds;
+use std::marker::PhantomData;
+use std::ops::{Add, RangeBounds};
+
+/// An associative operation with an identity element, used to aggregate ranges.
+///
+/// `combine` must be associative and `identity()` must be neutral for it.
+/// `combine` does not need to be commutative: aggregates are always computed left to right.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::collections::{Monoid, SegmentTree};
+/// // concatenation of strings is a non-commutative monoid
+/// struct Concat;
+/// impl Monoid<String> for Concat {
+///     fn identity() -> String {
+///         String::new()
+///     }
+///     fn combine(a: &String, b: &String) -> String {
+///         format!("{a}{b}")
+///     }
+/// }
+/// fn main() {
+///     let words: Vec<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+///     let tree: SegmentTree<String, Concat> = SegmentTree::from_slice(&words);
+///     assert_eq!(tree.query(1..3), "bc");
+/// }
+/// ```
+///
+pub trait Monoid<T> {
+    fn identity() -> T;
+    fn combine(a: &T, b: &T) -> T;
+}
+
+/// Sum monoid, the identity is `T::default()`.
+pub struct Sum;
+/// Minimum monoid, the identity is the largest value of `T`.
+pub struct Min;
+/// Maximum monoid, the identity is the smallest value of `T`.
+pub struct Max;
+/// Greatest common divisor monoid, the identity is 0.
+///
+/// The result is never negative, so for signed types combining panics when the gcd is `MIN`,
+/// as for `MIN` and 0 or `MIN` and `MIN`, whose absolute value does not fit.
+pub struct Gcd;
+
+impl<T: Clone + Default + Add<Output = T>> Monoid<T> for Sum {
+    fn identity() -> T {
+        T::default()
+    }
+    fn combine(a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+}
+
+macro_rules! impl_integer_monoids {
+    ($($t:ty),*) => {$(
+        impl Monoid<$t> for Min {
+            fn identity() -> $t {
+                <$t>::MAX
+            }
+            fn combine(a: &$t, b: &$t) -> $t {
+                *a.min(b)
+            }
+        }
+
+        impl Monoid<$t> for Max {
+            fn identity() -> $t {
+                <$t>::MIN
+            }
+            fn combine(a: &$t, b: &$t) -> $t {
+                *a.max(b)
+            }
+        }
+
+        impl Monoid<$t> for Gcd {
+            fn identity() -> $t {
+                0
+            }
+            #[allow(unused_comparisons)]
+            fn combine(a: &$t, b: &$t) -> $t {
+                let (mut a, mut b) = (*a, *b);
+                while b != 0 {
+                    // MIN % -1 overflows, but the remainder is 0
+                    let rest = a.wrapping_rem(b);
+                    a = b;
+                    b = rest;
+                }
+                if a < 0 {
+                    a.checked_neg().expect("the gcd is MIN, whose absolute value does not fit")
+                } else {
+                    a
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer_monoids!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float_monoids {
+    ($($t:ty),*) => {$(
+        impl Monoid<$t> for Min {
+            fn identity() -> $t {
+                <$t>::INFINITY
+            }
+            fn combine(a: &$t, b: &$t) -> $t {
+                a.min(*b)
+            }
+        }
+
+        impl Monoid<$t> for Max {
+            fn identity() -> $t {
+                <$t>::NEG_INFINITY
+            }
+            fn combine(a: &$t, b: &$t) -> $t {
+                a.max(*b)
+            }
+        }
+    )*};
+}
+
+impl_float_monoids!(f32, f64);
+
+/// SegmentTree structure answering range aggregates over any `Monoid`.
+///
+/// Supports point update and range query in O(log n), and assignment of a whole range in
+/// O(log n) thanks to lazy propagation: pending assignments are stored on the highest nodes
+/// they cover and pushed down only when a query or an update needs to look inside.
+///
+/// # Attributes
+///
+/// * `length` - Number of elements
+/// * `leaves` - Number of leaves, the smallest power of two greater than or equal to `length`
+/// * `tree` - Aggregates, node `i` has children `2i` and `2i + 1`, the root is node 1
+/// * `lazy` - Pending assignment of each node
+///
+pub struct SegmentTree<T, Op> {
+    length: usize,
+    leaves: usize,
+    tree: Vec<T>,
+    lazy: Vec<Option<T>>,
+    operation: PhantomData<Op>,
+}
+
+impl<T: Clone, Op: Monoid<T>> SegmentTree<T, Op> {
+    /// Create a SegmentTree of `len` elements equal to the identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Number of elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{SegmentTree, Sum};
+    /// fn main() {
+    ///     let mut tree: SegmentTree<i64, Sum> = SegmentTree::new(10);
+    ///     tree.update(3, 5);
+    ///     assert_eq!(tree.query(..), 5);
+    /// }
+    /// ```
+    ///
+    pub fn new(len: usize) -> Self {
+        Self::from_slice(&vec![Op::identity(); len])
+    }
+
+    /// Build a SegmentTree from `values` in O(n).
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The initial elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{Min, SegmentTree};
+    /// fn main() {
+    ///     let tree: SegmentTree<i32, Min> = SegmentTree::from_slice(&[5, 2, 8, 1, 9]);
+    ///     assert_eq!(tree.query(0..3), 2);
+    ///     assert_eq!(tree.query(..), 1);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `SegmentTree<T, Op>` - The SegmentTree that was created
+    ///
+    pub fn from_slice(values: &[T]) -> Self {
+        let leaves = values.len().next_power_of_two();
+        let mut tree = vec![Op::identity(); 2 * leaves];
+        tree[leaves..leaves + values.len()].clone_from_slice(values);
+        for node in (1..leaves).rev() {
+            tree[node] = Op::combine(&tree[2 * node], &tree[2 * node + 1]);
+        }
+        Self {
+            length: values.len(),
+            leaves,
+            tree,
+            lazy: vec![None; 2 * leaves],
+            operation: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the element at `index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the element, must be lower than `len()`
+    ///
+    pub fn get(&self, index: usize) -> T {
+        self.query(index..=index)
+    }
+
+    /// Replace the element at `index` with `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the element, must be lower than `len()`
+    /// * `value` - The new value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{Max, SegmentTree};
+    /// fn main() {
+    ///     let mut tree: SegmentTree<i32, Max> = SegmentTree::from_slice(&[1, 2, 3]);
+    ///     tree.update(0, 10);
+    ///     assert_eq!(tree.query(..2), 10);
+    /// }
+    /// ```
+    ///
+    pub fn update(&mut self, index: usize, value: T) {
+        self.update_range(index..=index, value)
+    }
+
+    /// Returns the aggregate of the elements in `range`, or the identity if it is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of indices, e.g. `2..5`, `..=3` or `..`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{Gcd, SegmentTree};
+    /// fn main() {
+    ///     let tree: SegmentTree<u32, Gcd> = SegmentTree::from_slice(&[12, 18, 24, 7]);
+    ///     assert_eq!(tree.query(..3), 6);
+    ///     assert_eq!(tree.query(..), 1);
+    /// }
+    /// ```
+    ///
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = range_to_bounds(range, self.length);
+        if start == end {
+            return Op::identity();
+        }
+        self.query_node(1, 0, self.leaves, start, end)
+    }
+
+    /// Set every element in `range` to `value`, lazily in O(log n).
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of indices
+    /// * `value` - The new value of the elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::{SegmentTree, Sum};
+    /// fn main() {
+    ///     let mut tree: SegmentTree<i64, Sum> = SegmentTree::from_slice(&[1, 2, 3, 4, 5]);
+    ///     tree.update_range(1..4, 10);
+    ///     assert_eq!(tree.query(..), 36);
+    ///     assert_eq!(tree.get(3), 10);
+    /// }
+    /// ```
+    ///
+    pub fn update_range<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let (start, end) = range_to_bounds(range, self.length);
+        if start < end {
+            self.assign_node(1, 0, self.leaves, start, end, &value);
+        }
+    }
+
+    /// Returns all the elements.
+    pub fn to_vec(&self) -> Vec<T> {
+        (0..self.length).map(|index| self.get(index)).collect()
+    }
+
+    // Aggregate of `value` repeated `count` times, by doubling
+    fn repeat(value: &T, mut count: usize) -> T {
+        let mut result = Op::identity();
+        let mut power = value.clone();
+        while count > 0 {
+            if count & 1 == 1 {
+                result = Op::combine(&result, &power);
+            }
+            count >>= 1;
+            if count > 0 {
+                power = Op::combine(&power, &power);
+            }
+        }
+        result
+    }
+
+    fn apply(&mut self, node: usize, length: usize, value: &T) {
+        self.tree[node] = Self::repeat(value, length);
+        if node < self.leaves {
+            self.lazy[node] = Some(value.clone());
+        }
+    }
+
+    fn push_down(&mut self, node: usize, length: usize) {
+        if let Some(value) = self.lazy[node].take() {
+            self.apply(2 * node, length / 2, &value);
+            self.apply(2 * node + 1, length / 2, &value);
+        }
+    }
+
+    fn assign_node(
+        &mut self,
+        node: usize,
+        low: usize,
+        high: usize,
+        start: usize,
+        end: usize,
+        value: &T,
+    ) {
+        if end <= low || high <= start {
+            return;
+        }
+        if start <= low && high <= end {
+            self.apply(node, high - low, value);
+            return;
+        }
+        self.push_down(node, high - low);
+        let middle = (low + high) / 2;
+        self.assign_node(2 * node, low, middle, start, end, value);
+        self.assign_node(2 * node + 1, middle, high, start, end, value);
+        self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+
+    // Queries do not push down: a pending assignment answers for the whole subtree
+    fn query_node(&self, node: usize, low: usize, high: usize, start: usize, end: usize) -> T {
+        if start <= low && high <= end {
+            return self.tree[node].clone();
+        }
+        if let Some(value) = &self.lazy[node] {
+            return Self::repeat(value, end.min(high) - start.max(low));
+        }
+        let middle = (low + high) / 2;
+        if end <= middle {
+            self.query_node(2 * node, low, middle, start, end)
+        } else if middle <= start {
+            self.query_node(2 * node + 1, middle, high, start, end)
+        } else {
+            Op::combine(
+                &self.query_node(2 * node, low, middle, start, end),
+                &self.query_node(2 * node + 1, middle, high, start, end),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod segment_tree_tests {
+    use super::*;
+
+    #[test]
+    fn query_and_update() {
+        let values: Vec<i64> = (0..37).map(|x| (x * 7919) % 101 - 50).collect();
+        let mut naive = values.clone();
+        let mut sums: SegmentTree<i64, Sum> = SegmentTree::from_slice(&values);
+        let mut mins: SegmentTree<i64, Min> = SegmentTree::from_slice(&values);
+        for step in 0..200usize {
+            let index = (step * 13) % naive.len();
+            let value = (step as i64 * 31) % 97 - 40;
+            naive[index] = value;
+            sums.update(index, value);
+            mins.update(index, value);
+            let start = (step * 7) % naive.len();
+            let end = start + (step * 3) % (naive.len() - start + 1);
+            assert_eq!(
+                sums.query(start..end),
+                naive[start..end].iter().sum::<i64>()
+            );
+            assert_eq!(
+                mins.query(start..end),
+                naive[start..end].iter().copied().min().unwrap_or(i64::MAX)
+            );
+        }
+    }
+
+    #[test]
+    fn lazy_range_update() {
+        let mut naive: Vec<i32> = (0..50).collect();
+        let mut sums: SegmentTree<i32, Sum> = SegmentTree::from_slice(&naive);
+        let mut maxs: SegmentTree<i32, Max> = SegmentTree::from_slice(&naive);
+        for step in 0..100usize {
+            let start = (step * 11) % 50;
+            let end = start + (step * 17) % (51 - start);
+            let value = (step as i32 * 13) % 23 - 11;
+            for element in naive[start..end].iter_mut() {
+                *element = value;
+            }
+            sums.update_range(start..end, value);
+            maxs.update_range(start..end, value);
+            if step % 3 == 0 {
+                sums.update(step % 50, step as i32);
+                maxs.update(step % 50, step as i32);
+                naive[step % 50] = step as i32;
+            }
+            let (low, high) = ((step * 5) % 50, 50 - (step * 3) % 25);
+            let (low, high) = (low.min(high), low.max(high));
+            assert_eq!(sums.query(low..high), naive[low..high].iter().sum::<i32>());
+            assert_eq!(
+                maxs.query(low..high),
+                naive[low..high].iter().copied().max().unwrap_or(i32::MIN)
+            );
+        }
+        assert_eq!(sums.to_vec(), naive);
+    }
+
+    #[test]
+    fn gcd_and_bounds() {
+        let tree: SegmentTree<i32, Gcd> = SegmentTree::from_slice(&[-4, 6, 10, 15]);
+        assert_eq!(tree.query(..3), 2);
+        assert_eq!(tree.query(2..), 5);
+        assert_eq!(tree.query(1..1), 0);
+        assert_eq!(<Gcd as Monoid<i8>>::combine(&i8::MIN, &-1), 1);
+        assert_eq!(<Gcd as Monoid<i8>>::combine(&i8::MIN, &-6), 2);
+        assert_eq!(<Gcd as Monoid<i8>>::combine(&i8::MIN, &64), 64);
+        let empty: SegmentTree<i32, Sum> = SegmentTree::new(0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.query(..), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the gcd is MIN")]
+    fn gcd_of_min() {
+        <Gcd as Monoid<i64>>::combine(&i64::MIN, &0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let tree: SegmentTree<i32, Sum> = SegmentTree::new(4);
+        tree.query(2..5);
+    }
+}
@@ -0,0 +1,630 @@
+use crate::math::Rng;
+use std::borrow::Borrow;
+use std::fmt::{self, Debug, Formatter};
+use std::ops::{Bound, RangeBounds};
+
+const MAX_LEVEL: usize = 32;
+const DEFAULT_SEED: u64 = 0x5EED_5EED;
+
+// Link of a node at one level.
+// `width` is the number of positions skipped: the head is at position 0, the nodes at
+// positions 1..=len and the end of the list (`next` is None) at position len + 1.
+#[derive(Clone, Copy, Debug)]
+struct Link {
+    next: Option<usize>,
+    width: usize,
+}
+
+#[derive(Clone, Debug)]
+struct SkipNode<K, V> {
+    key: K,
+    value: V,
+    links: Vec<Link>,
+}
+
+/// SkipList structure, an ordered map built on several levels of linked lists.
+///
+/// Every node appears on level 0 and on each next level with probability 1/2, so the
+/// higher levels act as express lanes and search, insert and remove take O(log n)
+/// expected time. Links remember how many nodes they skip, which gives access by index.
+/// Nodes live in a vector and are linked by index.
+///
+/// # Attributes
+///
+/// * `nodes` - The nodes, in no particular order
+/// * `head` - Links leaving the head of the list, one per level
+/// * `rng` - Generator drawing the level of new nodes
+///
+#[derive(Clone)]
+pub struct SkipList<K, V> {
+    nodes: Vec<SkipNode<K, V>>,
+    head: Vec<Link>,
+    rng: Rng,
+}
+
+impl<K: Ord, V> SkipList<K, V> {
+    /// Create an empty SkipList with a fixed seed, so runs are reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let mut list = SkipList::new();
+    ///     list.insert(3, "c");
+    ///     list.insert(1, "a");
+    ///     assert_eq!(list.get(&1), Some(&"a"));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `SkipList<K, V>` - The SkipList that was created
+    ///
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// Create an empty SkipList whose levels are drawn from a generator seeded with `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed of the level generator
+    ///
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            nodes: Vec::new(),
+            head: vec![Link {
+                next: None,
+                width: 1,
+            }],
+            rng: Rng::new(seed),
+        }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the list contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = vec![Link {
+            next: None,
+            width: 1,
+        }];
+    }
+
+    fn links(&self, node: Option<usize>) -> &[Link] {
+        match node {
+            Some(index) => &self.nodes[index].links,
+            None => &self.head,
+        }
+    }
+
+    fn link_mut(&mut self, node: Option<usize>, level: usize) -> &mut Link {
+        match node {
+            Some(index) => &mut self.nodes[index].links[level],
+            None => &mut self.head[level],
+        }
+    }
+
+    // Walk down the levels towards `key`, calling `visit` with each level, the last node (None
+    // for the head) whose key is before `key` on it and its position; return those of level 0.
+    // With `inclusive` keys equal to `key` count as before.
+    fn walk<Q, F>(&self, key: &Q, inclusive: bool, mut visit: F) -> (Option<usize>, usize)
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        F: FnMut(usize, Option<usize>, usize),
+    {
+        let mut current = None;
+        let mut position = 0;
+        for level in (0..self.head.len()).rev() {
+            loop {
+                let link = self.links(current)[level];
+                match link.next {
+                    Some(next) if self.is_before(&self.nodes[next].key, key, inclusive) => {
+                        current = Some(next);
+                        position += link.width;
+                    }
+                    _ => break,
+                }
+            }
+            visit(level, current, position);
+        }
+        (current, position)
+    }
+
+    // For every level, the last node (None for the head) whose key is before `key` and its position.
+    fn search<Q>(&self, key: &Q, inclusive: bool) -> (Vec<Option<usize>>, Vec<usize>)
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let levels = self.head.len();
+        let mut predecessors = vec![None; levels];
+        let mut positions = vec![0; levels];
+        self.walk(key, inclusive, |level, current, position| {
+            predecessors[level] = current;
+            positions[level] = position;
+        });
+        (predecessors, positions)
+    }
+
+    // The last node whose key is before `key` and its position, without recording the path.
+    fn locate<Q>(&self, key: &Q, inclusive: bool) -> (Option<usize>, usize)
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.walk(key, inclusive, |_, _, _| {})
+    }
+
+    fn is_before<Q>(&self, node_key: &K, key: &Q, inclusive: bool) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        if inclusive {
+            node_key.borrow() <= key
+        } else {
+            node_key.borrow() < key
+        }
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let (predecessor, _) = self.locate(key, false);
+        self.links(predecessor)[0]
+            .next
+            .filter(|&next| self.nodes[next].key.borrow() == key)
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.next_u64() & 1 == 1 {
+            level += 1;
+        }
+        level
+    }
+
+    /// Inserts a key-value pair in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the entry
+    /// * `value` - The value to associate with `key`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let mut list = SkipList::new();
+    ///     assert_eq!(list.insert(1, "a"), None);
+    ///     assert_eq!(list.insert(1, "b"), Some("a"));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Option<V>` - The previous value of `key`, or None if it was not in the list
+    ///
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(index) = self.find(&key) {
+            return Some(std::mem::replace(&mut self.nodes[index].value, value));
+        }
+        let level = self.random_level();
+        while self.head.len() < level {
+            self.head.push(Link {
+                next: None,
+                width: self.len() + 1,
+            });
+        }
+        let (predecessors, positions) = self.search(&key, false);
+        let index = self.nodes.len();
+        let position = positions[0] + 1;
+        let mut links = Vec::with_capacity(level);
+        for (current, &predecessor) in predecessors.iter().enumerate() {
+            let link = self.link_mut(predecessor, current);
+            if current < level {
+                // the new node splits the link of its predecessor in two
+                links.push(Link {
+                    next: link.next,
+                    width: link.width + positions[current] + 1 - position,
+                });
+                *link = Link {
+                    next: Some(index),
+                    width: position - positions[current],
+                };
+            } else {
+                link.width += 1;
+            }
+        }
+        self.nodes.push(SkipNode { key, value, links });
+        None
+    }
+
+    /// Removes a key from the list, returning its value if it was in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let mut list = SkipList::new();
+    ///     list.insert(1, "a");
+    ///     assert_eq!(list.remove(&1), Some("a"));
+    ///     assert_eq!(list.remove(&1), None);
+    /// }
+    /// ```
+    ///
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes a key from the list, returning the stored key and its value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let (predecessors, _) = self.search(key, false);
+        let index = self.links(predecessors[0])[0]
+            .next
+            .filter(|&next| self.nodes[next].key.borrow() == key)?;
+        for (level, &predecessor) in predecessors.iter().enumerate() {
+            let removed = self.nodes[index].links.get(level).copied();
+            let link = self.link_mut(predecessor, level);
+            match removed {
+                Some(removed) => {
+                    link.next = removed.next;
+                    link.width += removed.width - 1;
+                }
+                None => link.width -= 1,
+            }
+        }
+        while self.head.len() > 1 && self.head[self.head.len() - 1].next.is_none() {
+            self.head.pop();
+        }
+        self.relocate_last(index, &predecessors);
+        let node = self.nodes.swap_remove(index);
+        Some((node.key, node.value))
+    }
+
+    // The last node of the vector is about to move into the free slot `index`: redirect its incoming links.
+    // `path` holds the predecessors of the node just removed, where the walk can start.
+    fn relocate_last(&mut self, index: usize, path: &[Option<usize>]) {
+        let last = self.nodes.len() - 1;
+        if index == last {
+            return;
+        }
+        let levels = self.nodes[last].links.len();
+        let mut current = None;
+        for level in (0..self.head.len()).rev() {
+            // any node of this level before the last one is a start, the further the better
+            if let Some(&Some(node)) = path.get(level) {
+                let key = &self.nodes[node].key;
+                if *key < self.nodes[last].key
+                    && current.is_none_or(|current: usize| self.nodes[current].key < *key)
+                {
+                    current = Some(node);
+                }
+            }
+            while let Some(next) = self.links(current)[level].next {
+                if self.nodes[next].key >= self.nodes[last].key {
+                    break;
+                }
+                current = Some(next);
+            }
+            if level < levels {
+                let link = self.link_mut(current, level);
+                if link.next == Some(last) {
+                    link.next = Some(index);
+                }
+            }
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to look for, any borrowed form of the key type
+    ///
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.find(key).map(|index| &self.nodes[index].value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        match self.find(key) {
+            Some(index) => Some(&mut self.nodes[index].value),
+            None => None,
+        }
+    }
+
+    /// Returns true if the list contains the key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Returns the number of keys lower than `key`, which is the index of `key` when it is in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let list: SkipList<i32, ()> = [10, 20, 30].into_iter().map(|x| (x, ())).collect();
+    ///     assert_eq!(list.rank(&20), 1);
+    ///     assert_eq!(list.rank(&25), 2);
+    ///     assert_eq!(list.rank(&5), 0);
+    /// }
+    /// ```
+    ///
+    pub fn rank<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.locate(key, false).1
+    }
+
+    /// Returns the entry at `index` in key order, in O(log n) expected time.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position of the entry, 0 for the smallest key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let list: SkipList<i32, char> = [(30, 'c'), (10, 'a'), (20, 'b')].into_iter().collect();
+    ///     assert_eq!(list.select(1), Some((&20, &'b')));
+    ///     assert_eq!(list.select(3), None);
+    /// }
+    /// ```
+    ///
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        let node = self.node_at(index + 1)?;
+        Some((&self.nodes[node].key, &self.nodes[node].value))
+    }
+
+    // The node at `position` (1-based), walking down the levels
+    fn node_at(&self, position: usize) -> Option<usize> {
+        if position == 0 || position > self.len() {
+            return None;
+        }
+        let mut current = None;
+        let mut reached = 0;
+        for level in (0..self.head.len()).rev() {
+            loop {
+                let link = self.links(current)[level];
+                if link.next.is_some() && reached + link.width <= position {
+                    current = link.next;
+                    reached += link.width;
+                } else {
+                    break;
+                }
+            }
+        }
+        current
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.select(0)
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.len()
+            .checked_sub(1)
+            .and_then(|index| self.select(index))
+    }
+
+    /// An iterator visiting all entries in key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            list: self,
+            next: self.head[0].next,
+            remaining: self.len(),
+        }
+    }
+
+    /// An iterator visiting the entries whose key is in `range`, in key order.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Any range of keys, e.g. `3..8`, `..=5` or `..`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::SkipList;
+    /// fn main() {
+    ///     let list: SkipList<i32, i32> = (0..10).map(|x| (x * 10, x)).collect();
+    ///     let keys: Vec<i32> = list.range(25..=60).map(|(key, _)| *key).collect();
+    ///     assert_eq!(keys, vec![30, 40, 50, 60]);
+    /// }
+    /// ```
+    ///
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        let (predecessor, position) = match range.start_bound() {
+            Bound::Included(start) => self.locate(start, false),
+            Bound::Excluded(start) => self.locate(start, true),
+            Bound::Unbounded => (None, 0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.locate(end, true).1,
+            Bound::Excluded(end) => self.locate(end, false).1,
+            Bound::Unbounded => self.len(),
+        };
+        Iter {
+            list: self,
+            next: self.links(predecessor)[0].next,
+            remaining: end.saturating_sub(position),
+        }
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> Debug for SkipList<K, V> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SkipList<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SkipList<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a SkipList<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// Iterator over the entries of a `SkipList` in key order, see [`SkipList::iter`] and [`SkipList::range`].
+pub struct Iter<'a, K, V> {
+    list: &'a SkipList<K, V>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.list.nodes[self.next?];
+        self.next = node.links[0].next;
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+#[cfg(test)]
+mod skip_list_tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut list = SkipList::new();
+        assert!(list.is_empty());
+        for i in (0..100).rev() {
+            assert_eq!(list.insert(i, i * 10), None);
+        }
+        assert_eq!(list.len(), 100);
+        assert_eq!(list.get(&42), Some(&420));
+        assert_eq!(list.insert(42, 0), Some(420));
+        for i in (0..100).step_by(3) {
+            assert_eq!(list.remove(&i), Some(if i == 42 { 0 } else { i * 10 }));
+        }
+        assert_eq!(list.remove(&3), None);
+        assert_eq!(list.len(), 66);
+        for i in 0..100 {
+            assert_eq!(list.contains_key(&i), i % 3 != 0);
+        }
+        list.clear();
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn matches_btree_map() {
+        let mut rng = Rng::new(1);
+        let mut list = SkipList::with_seed(2);
+        let mut reference = BTreeMap::new();
+        for _ in 0..2000 {
+            let key = rng.gen_range(0..300);
+            if rng.next_bool(0.6) {
+                assert_eq!(list.insert(key, key * 2), reference.insert(key, key * 2));
+            } else {
+                assert_eq!(list.remove(&key), reference.remove(&key));
+            }
+        }
+        assert_eq!(list.len(), reference.len());
+        assert!(list.iter().eq(reference.iter()));
+        for (index, (key, value)) in reference.iter().enumerate() {
+            assert_eq!(list.select(index), Some((key, value)));
+            assert_eq!(list.rank(key), index);
+        }
+        assert!(list.range(50..150).eq(reference.range(50..150)));
+        assert!(list.range(..=77).eq(reference.range(..=77)));
+        assert!(list
+            .range((Bound::Excluded(10), Bound::Unbounded))
+            .eq(reference.range((Bound::Excluded(10), Bound::Unbounded))));
+        assert_eq!(list.first(), reference.iter().next());
+        assert_eq!(list.last(), reference.iter().next_back());
+    }
+
+    #[test]
+    fn deterministic_with_seed() {
+        let build = || -> SkipList<u32, ()> { (0..500).map(|x| (x, ())).collect() };
+        assert_eq!(build().head.len(), build().head.len());
+        let list = build();
+        assert_eq!(list.select(499), Some((&499, &())));
+        assert_eq!(list.range(600..).len(), 0);
+    }
+}
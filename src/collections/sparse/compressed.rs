@@ -0,0 +1,181 @@
+use super::{is_zero, Scalar};
+
+// Storage shared by CSR and CSC: `major` lanes (rows for CSR, columns for CSC), lane `i`
+// holding the minor indices `indices[offsets[i]..offsets[i + 1]]`, strictly increasing,
+// and their non zero values.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Compressed<T> {
+    pub major: usize,
+    pub minor: usize,
+    pub offsets: Vec<usize>,
+    pub indices: Vec<usize>,
+    pub values: Vec<T>,
+}
+
+impl<T: Scalar> Compressed<T> {
+    // Build from `(major, minor, value)` in any order, summing duplicates and dropping zeros
+    pub fn from_triplets(major: usize, minor: usize, mut triplets: Vec<(usize, usize, T)>) -> Self {
+        for &(i, j, _) in &triplets {
+            assert!(
+                i < major && j < minor,
+                "entry ({}, {}) out of bounds for a {}x{} storage",
+                i,
+                j,
+                major,
+                minor
+            );
+        }
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(triplets.len());
+        for (i, j, value) in triplets {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (i, j) => last.2 = last.2 + value,
+                _ => merged.push((i, j, value)),
+            }
+        }
+        merged.retain(|(_, _, value)| !is_zero(value));
+
+        let mut offsets = vec![0; major + 1];
+        for &(i, _, _) in &merged {
+            offsets[i + 1] += 1;
+        }
+        for i in 0..major {
+            offsets[i + 1] += offsets[i];
+        }
+        let indices = merged.iter().map(|&(_, j, _)| j).collect();
+        let values = merged.into_iter().map(|(_, _, value)| value).collect();
+        Self {
+            major,
+            minor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    pub fn lane(&self, major: usize) -> (&[usize], &[T]) {
+        assert!(
+            major < self.major,
+            "lane {} out of bounds for {} lanes",
+            major,
+            self.major
+        );
+        let range = self.offsets[major]..self.offsets[major + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    pub fn get(&self, major: usize, minor: usize) -> T {
+        assert!(
+            minor < self.minor,
+            "index {} out of bounds for length {}",
+            minor,
+            self.minor
+        );
+        let (indices, values) = self.lane(major);
+        indices
+            .binary_search(&minor)
+            .map_or(T::default(), |position| values[position])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.major).flat_map(move |major| {
+            let (indices, values) = self.lane(major);
+            indices
+                .iter()
+                .zip(values)
+                .map(move |(&minor, value)| (major, minor, value))
+        })
+    }
+
+    // The same entries compressed along the other axis, in O(nnz + major + minor):
+    // this is the CSC of a CSR matrix, the CSR of a CSC matrix, and the transpose of both
+    pub fn recompress(&self) -> Self {
+        let mut offsets = vec![0; self.minor + 1];
+        for &minor in &self.indices {
+            offsets[minor + 1] += 1;
+        }
+        for i in 0..self.minor {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![T::default(); self.values.len()];
+        for (major, minor, value) in self.iter() {
+            indices[next[minor]] = major;
+            values[next[minor]] = *value;
+            next[minor] += 1;
+        }
+        Self {
+            major: self.minor,
+            minor: self.major,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    // Gustavson's product of two compressed storages: lane `i` of the result is the sum of
+    // the lanes `k` of `other` scaled by the entries `(i, k)` of `self`
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.minor, other.major,
+            "cannot multiply: inner dimensions {} and {} differ",
+            self.minor, other.major
+        );
+        let mut accumulator = vec![T::default(); other.minor];
+        let mut touched_flag = vec![false; other.minor];
+        let mut touched = Vec::new();
+        let mut offsets = Vec::with_capacity(self.major + 1);
+        offsets.push(0);
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        for major in 0..self.major {
+            let (inner, left) = self.lane(major);
+            for (&k, &a) in inner.iter().zip(left) {
+                let (columns, right) = other.lane(k);
+                for (&j, &b) in columns.iter().zip(right) {
+                    if !touched_flag[j] {
+                        touched_flag[j] = true;
+                        touched.push(j);
+                    }
+                    accumulator[j] = accumulator[j] + a * b;
+                }
+            }
+            touched.sort_unstable();
+            for &j in &touched {
+                if !is_zero(&accumulator[j]) {
+                    indices.push(j);
+                    values.push(accumulator[j]);
+                }
+                accumulator[j] = T::default();
+                touched_flag[j] = false;
+            }
+            touched.clear();
+            offsets.push(indices.len());
+        }
+        Self {
+            major: self.major,
+            minor: other.minor,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    pub fn to_dense(&self, transposed: bool) -> Vec<Vec<T>> {
+        let (rows, cols) = if transposed {
+            (self.minor, self.major)
+        } else {
+            (self.major, self.minor)
+        };
+        let mut dense = vec![vec![T::default(); cols]; rows];
+        for (major, minor, value) in self.iter() {
+            if transposed {
+                dense[minor][major] = *value;
+            } else {
+                dense[major][minor] = *value;
+            }
+        }
+        dense
+    }
+}
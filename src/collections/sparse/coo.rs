@@ -0,0 +1,136 @@
+use super::{check_dense, is_zero, CscMatrix, CsrMatrix, Scalar};
+
+/// CooMatrix structure, a sparse matrix as a list of `(row, col, value)` entries.
+///
+/// The simplest format to build incrementally: entries are appended in any order and may
+/// repeat a position, repeated entries add up. Convert to `CsrMatrix` or `CscMatrix` for
+/// computations.
+///
+/// # Attributes
+///
+/// * `rows` - Number of rows
+/// * `cols` - Number of columns
+/// * `entries` - The entries, in insertion order
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    /// Create a `rows` x `cols` matrix with no entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CooMatrix;
+    /// fn main() {
+    ///     let mut matrix = CooMatrix::new(2, 2);
+    ///     matrix.push(0, 1, 3);
+    ///     matrix.push(1, 0, 4);
+    ///     matrix.push(0, 1, 1);
+    ///     assert_eq!(matrix.to_dense(), vec![vec![0, 4], vec![4, 0]]);
+    ///     assert_eq!(matrix.to_csr().nnz(), 2);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `CooMatrix<T>` - The CooMatrix that was created
+    ///
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Build a matrix from its dense rows, keeping the non zero values.
+    pub fn from_dense(dense: &[Vec<T>]) -> Self {
+        let (rows, cols) = check_dense(dense);
+        let mut coo = Self::new(rows, cols);
+        for (row, values) in dense.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                if !is_zero(&value) {
+                    coo.push(row, col, value);
+                }
+            }
+        }
+        coo
+    }
+
+    /// Add `value` at `(row, col)`, on top of the entries already there.
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "entry ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        self.entries.push((row, col, value));
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of entries, counting repeated positions as many times as they were pushed.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// An iterator over the `(row, col, value)` entries, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.entries
+            .iter()
+            .map(|(row, col, value)| (*row, *col, value))
+    }
+
+    /// Returns the transpose, in O(nnz).
+    pub fn transpose(&self) -> CooMatrix<T> {
+        CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries: self
+                .entries
+                .iter()
+                .map(|&(row, col, value)| (col, row, value))
+                .collect(),
+        }
+    }
+
+    /// Convert to compressed sparse row format, summing repeated positions.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_triplets(self.rows, self.cols, self.entries.clone())
+    }
+
+    /// Convert to compressed sparse column format, summing repeated positions.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix::from_triplets(self.rows, self.cols, self.entries.clone())
+    }
+
+    /// Returns the dense rows of the matrix.
+    pub fn to_dense(&self) -> Vec<Vec<T>> {
+        let mut dense = vec![vec![T::default(); self.cols]; self.rows];
+        for &(row, col, value) in &self.entries {
+            dense[row][col] = dense[row][col] + value;
+        }
+        dense
+    }
+}
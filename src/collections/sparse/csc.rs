@@ -0,0 +1,180 @@
+use super::compressed::Compressed;
+use super::{CooMatrix, CsrMatrix, Scalar};
+
+/// CscMatrix structure, a sparse matrix in compressed sparse column format.
+///
+/// The non zero values are stored column after column; inside a column they are sorted by
+/// row. It is the CSR layout of the transpose, so reading a column is fast where `CsrMatrix`
+/// reads rows.
+///
+/// # Attributes
+///
+/// * `storage` - Column `c` holds the rows `indices[offsets[c]..offsets[c + 1]]` and their values
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<T> {
+    storage: Compressed<T>,
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    /// Create a `rows` x `cols` matrix of zeros.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::from_triplets(rows, cols, Vec::new())
+    }
+
+    /// Build a `rows` x `cols` matrix from `(row, col, value)` entries in any order.
+    ///
+    /// Duplicate entries are summed, and entries summing to zero are not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CscMatrix;
+    /// fn main() {
+    ///     let matrix = CscMatrix::from_triplets(3, 2, vec![(2, 1, 5.0), (0, 1, 1.5)]);
+    ///     let column: Vec<_> = matrix.col(1).map(|(row, &value)| (row, value)).collect();
+    ///     assert_eq!(column, vec![(0, 1.5), (2, 5.0)]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `CscMatrix<T>` - The CscMatrix that was created
+    ///
+    pub fn from_triplets(rows: usize, cols: usize, triplets: Vec<(usize, usize, T)>) -> Self {
+        let swapped = triplets
+            .into_iter()
+            .map(|(row, col, value)| (col, row, value))
+            .collect();
+        Self {
+            storage: Compressed::from_triplets(cols, rows, swapped),
+        }
+    }
+
+    /// Build a matrix from its dense rows, keeping the non zero values.
+    pub fn from_dense(dense: &[Vec<T>]) -> Self {
+        CsrMatrix::from_dense(dense).to_csc()
+    }
+
+    pub(super) fn from_storage(storage: Compressed<T>) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.storage.minor
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.storage.major
+    }
+
+    /// Returns `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    /// Returns the number of stored (non zero) values.
+    pub fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Returns the offsets of the columns in `row_indices()` and `values()`, `cols() + 1` of them.
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    /// Returns the row of every stored value.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Returns the stored values, column after column.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+
+    /// Returns the value at `(row, col)`, zero if it is not stored.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.storage.get(col, row)
+    }
+
+    /// An iterator over the `(row, value)` non zero entries of `col`, by increasing row.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = (usize, &T)> {
+        let (indices, values) = self.storage.lane(col);
+        indices.iter().copied().zip(values)
+    }
+
+    /// An iterator over the `(row, col, value)` non zero entries, column after column.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.storage
+            .iter()
+            .map(|(col, row, value)| (row, col, value))
+    }
+
+    /// Returns the transpose, in O(nnz + rows + cols).
+    pub fn transpose(&self) -> CscMatrix<T> {
+        Self::from_storage(self.storage.recompress())
+    }
+
+    /// Convert to compressed sparse row format, in O(nnz + rows + cols).
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_storage(self.storage.recompress())
+    }
+
+    /// Convert to coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(self.rows(), self.cols());
+        for (row, col, &value) in self.iter() {
+            coo.push(row, col, value);
+        }
+        coo
+    }
+
+    /// Returns the dense rows of the matrix.
+    pub fn to_dense(&self) -> Vec<Vec<T>> {
+        self.storage.to_dense(true)
+    }
+
+    /// Returns the product of the matrix and the dense vector `vector`, in O(nnz + rows + cols).
+    ///
+    /// Every column is scaled by its coefficient and added to the result.
+    ///
+    pub fn mul_vec(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(
+            vector.len(),
+            self.cols(),
+            "cannot multiply a matrix with {} columns by a vector of length {}",
+            self.cols(),
+            vector.len()
+        );
+        let mut result = vec![T::default(); self.rows()];
+        for (col, &coefficient) in vector.iter().enumerate() {
+            for (row, &value) in self.col(col) {
+                result[row] = result[row] + value * coefficient;
+            }
+        }
+        result
+    }
+
+    /// Returns the product of two sparse matrices.
+    ///
+    /// The storage of a CscMatrix is the CSR storage of its transpose, and
+    /// `(A B)ᵀ = Bᵀ Aᵀ`, so this is the CSR product of the operands in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CscMatrix;
+    /// fn main() {
+    ///     let a = CscMatrix::from_dense(&[vec![1, 2], vec![0, 3]]);
+    ///     let b = CscMatrix::from_dense(&[vec![0, 1], vec![1, 0]]);
+    ///     assert_eq!(a.mul(&b).to_dense(), vec![vec![2, 1], vec![3, 0]]);
+    /// }
+    /// ```
+    ///
+    pub fn mul(&self, other: &CscMatrix<T>) -> CscMatrix<T> {
+        Self::from_storage(other.storage.mul(&self.storage))
+    }
+}
@@ -0,0 +1,355 @@
+use super::compressed::Compressed;
+use super::{check_dense, is_zero, CooMatrix, CscMatrix, Scalar, SparseVector};
+
+/// CsrMatrix structure, a sparse matrix in compressed sparse row format.
+///
+/// The non zero values are stored row after row; inside a row they are sorted by column.
+/// Reading a row and the matrix-vector product are O(nnz of the row), reading any entry is
+/// O(log nnz of the row).
+///
+/// # Attributes
+///
+/// * `storage` - Row `r` holds the columns `indices[offsets[r]..offsets[r + 1]]` and their values
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsrMatrix<T> {
+    storage: Compressed<T>,
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    /// Create a `rows` x `cols` matrix of zeros.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self::from_triplets(rows, cols, Vec::new())
+    }
+
+    /// Build a `rows` x `cols` matrix from `(row, col, value)` entries in any order.
+    ///
+    /// Duplicate entries are summed, and entries summing to zero are not stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - Number of rows
+    /// * `cols` - Number of columns
+    /// * `triplets` - The entries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CsrMatrix;
+    /// fn main() {
+    ///     let matrix = CsrMatrix::from_triplets(2, 3, vec![(1, 2, 5), (0, 0, 1), (1, 2, 2)]);
+    ///     assert_eq!(matrix.nnz(), 2);
+    ///     assert_eq!(matrix.get(1, 2), 7);
+    ///     assert_eq!(matrix.get(1, 1), 0);
+    ///     assert_eq!(matrix.mul_vec(&[1, 1, 1]), vec![1, 7]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `CsrMatrix<T>` - The CsrMatrix that was created
+    ///
+    pub fn from_triplets(rows: usize, cols: usize, triplets: Vec<(usize, usize, T)>) -> Self {
+        Self {
+            storage: Compressed::from_triplets(rows, cols, triplets),
+        }
+    }
+
+    /// Build a matrix from its dense rows, keeping the non zero values.
+    pub fn from_dense(dense: &[Vec<T>]) -> Self {
+        let (rows, cols) = check_dense(dense);
+        let triplets = dense
+            .iter()
+            .enumerate()
+            .flat_map(|(row, values)| {
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, value)| !is_zero(*value))
+                    .map(move |(col, &value)| (row, col, value))
+            })
+            .collect();
+        Self::from_triplets(rows, cols, triplets)
+    }
+
+    pub(super) fn from_storage(storage: Compressed<T>) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.storage.major
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.storage.minor
+    }
+
+    /// Returns `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    /// Returns the number of stored (non zero) values.
+    pub fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Returns the offsets of the rows in `col_indices()` and `values()`, `rows() + 1` of them.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    /// Returns the column of every stored value.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Returns the stored values, row after row.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+
+    /// Returns the value at `(row, col)`, zero if it is not stored.
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.storage.get(row, col)
+    }
+
+    /// An iterator over the `(col, value)` non zero entries of `row`, by increasing column.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> {
+        let (indices, values) = self.storage.lane(row);
+        indices.iter().copied().zip(values)
+    }
+
+    /// An iterator over the `(row, col, value)` non zero entries, row after row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CsrMatrix;
+    /// fn main() {
+    ///     let matrix = CsrMatrix::from_dense(&[vec![0, 4], vec![3, 0]]);
+    ///     let entries: Vec<_> = matrix.iter().map(|(r, c, &v)| (r, c, v)).collect();
+    ///     assert_eq!(entries, vec![(0, 1, 4), (1, 0, 3)]);
+    /// }
+    /// ```
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.storage.iter()
+    }
+
+    /// Returns the transpose, in O(nnz + rows + cols).
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        Self::from_storage(self.storage.recompress())
+    }
+
+    /// Convert to compressed sparse column format, in O(nnz + rows + cols).
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix::from_storage(self.storage.recompress())
+    }
+
+    /// Convert to coordinate format.
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        let mut coo = CooMatrix::new(self.rows(), self.cols());
+        for (row, col, &value) in self.iter() {
+            coo.push(row, col, value);
+        }
+        coo
+    }
+
+    /// Returns the dense rows of the matrix.
+    pub fn to_dense(&self) -> Vec<Vec<T>> {
+        self.storage.to_dense(false)
+    }
+
+    /// Returns the product of the matrix and the dense vector `vector`, in O(nnz + rows).
+    ///
+    /// # Arguments
+    ///
+    /// * `vector` - A vector of `cols()` values
+    ///
+    pub fn mul_vec(&self, vector: &[T]) -> Vec<T> {
+        assert_eq!(
+            vector.len(),
+            self.cols(),
+            "cannot multiply a matrix with {} columns by a vector of length {}",
+            self.cols(),
+            vector.len()
+        );
+        (0..self.rows())
+            .map(|row| {
+                self.row(row)
+                    .fold(T::default(), |sum, (col, &value)| sum + value * vector[col])
+            })
+            .collect()
+    }
+
+    /// Returns the product of the matrix and the dense matrix `dense`, given by rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CsrMatrix;
+    /// fn main() {
+    ///     let a = CsrMatrix::from_dense(&[vec![1, 0], vec![0, 2]]);
+    ///     assert_eq!(a.mul_dense(&[vec![1, 2], vec![3, 4]]), vec![vec![1, 2], vec![6, 8]]);
+    /// }
+    /// ```
+    ///
+    pub fn mul_dense(&self, dense: &[Vec<T>]) -> Vec<Vec<T>> {
+        let (rows, cols) = check_dense(dense);
+        assert_eq!(
+            rows,
+            self.cols(),
+            "cannot multiply: inner dimensions {} and {} differ",
+            self.cols(),
+            rows
+        );
+        (0..self.rows())
+            .map(|row| {
+                let mut result = vec![T::default(); cols];
+                for (inner, &value) in self.row(row) {
+                    for (sum, &other) in result.iter_mut().zip(&dense[inner]) {
+                        *sum = *sum + value * other;
+                    }
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// Returns the product of two sparse matrices, with Gustavson's row by row algorithm.
+    ///
+    /// The cost is the number of multiplications performed, plus sorting the columns of
+    /// each result row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::CsrMatrix;
+    /// fn main() {
+    ///     let a = CsrMatrix::from_dense(&[vec![1, 2], vec![0, 3]]);
+    ///     let b = CsrMatrix::from_dense(&[vec![0, 1], vec![1, 0]]);
+    ///     assert_eq!(a.mul(&b).to_dense(), vec![vec![2, 1], vec![3, 0]]);
+    /// }
+    /// ```
+    ///
+    pub fn mul(&self, other: &CsrMatrix<T>) -> CsrMatrix<T> {
+        Self::from_storage(self.storage.mul(&other.storage))
+    }
+
+    /// Returns the product of the matrix and the sparse vector `vector`.
+    pub fn mul_sparse_vector(&self, vector: &SparseVector<T>) -> SparseVector<T> {
+        assert_eq!(
+            vector.len(),
+            self.cols(),
+            "cannot multiply a matrix with {} columns by a vector of length {}",
+            self.cols(),
+            vector.len()
+        );
+        let pairs = (0..self.rows())
+            .map(|row| {
+                let (indices, values) = self.storage.lane(row);
+                (row, vector.dot_sorted(indices, values))
+            })
+            .collect();
+        SparseVector::from_pairs(self.rows(), pairs)
+    }
+}
+
+#[cfg(test)]
+mod csr_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    fn random_dense(rng: &mut Rng, rows: usize, cols: usize) -> Vec<Vec<i64>> {
+        (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| {
+                        if rng.next_bool(0.2) {
+                            rng.gen_range(0..19) as i64 - 9
+                        } else {
+                            0
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn dense_mul(a: &[Vec<i64>], b: &[Vec<i64>]) -> Vec<Vec<i64>> {
+        let cols = b.first().map_or(0, Vec::len);
+        a.iter()
+            .map(|row| {
+                (0..cols)
+                    .map(|j| row.iter().zip(b).map(|(x, b_row)| x * b_row[j]).sum())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let mut rng = Rng::new(36);
+        let dense = random_dense(&mut rng, 13, 7);
+        let csr = CsrMatrix::from_dense(&dense);
+        let csc = CscMatrix::from_dense(&dense);
+        let coo = CooMatrix::from_dense(&dense);
+        assert_eq!(csr.to_dense(), dense);
+        assert_eq!(csc.to_dense(), dense);
+        assert_eq!(coo.to_dense(), dense);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(coo.to_csr(), csr);
+        assert_eq!(coo.to_csc(), csc);
+        assert_eq!(csr.to_coo().to_csr(), csr);
+        assert_eq!(csc.to_coo().to_csc(), csc);
+        for (row, col, &value) in csr.iter() {
+            assert_eq!(dense[row][col], value);
+            assert_eq!(csc.get(row, col), value);
+        }
+        assert_eq!(csr.nnz(), csc.iter().count());
+        assert_eq!(csr.row_offsets().len(), 14);
+
+        let transposed: Vec<Vec<i64>> = (0..7)
+            .map(|col| dense.iter().map(|row| row[col]).collect())
+            .collect();
+        assert_eq!(csr.transpose().to_dense(), transposed);
+        assert_eq!(csc.transpose().to_dense(), transposed);
+        assert_eq!(coo.transpose().to_dense(), transposed);
+    }
+
+    #[test]
+    fn products_match_dense() {
+        let mut rng = Rng::new(360);
+        let a = random_dense(&mut rng, 9, 11);
+        let b = random_dense(&mut rng, 11, 6);
+        let expected = dense_mul(&a, &b);
+        let (sparse_a, sparse_b) = (CsrMatrix::from_dense(&a), CsrMatrix::from_dense(&b));
+        assert_eq!(sparse_a.mul(&sparse_b).to_dense(), expected);
+        assert_eq!(sparse_a.mul_dense(&b), expected);
+        let product = CscMatrix::from_dense(&a).mul(&CscMatrix::from_dense(&b));
+        assert_eq!(product.to_dense(), expected);
+        assert_eq!(product, CsrMatrix::from_dense(&expected).to_csc());
+
+        let x: Vec<i64> = (0..11).map(|i| i * 2 - 7).collect();
+        let expected: Vec<i64> = a
+            .iter()
+            .map(|row| row.iter().zip(&x).map(|(a, b)| a * b).sum())
+            .collect();
+        assert_eq!(sparse_a.mul_vec(&x), expected);
+        assert_eq!(CscMatrix::from_dense(&a).mul_vec(&x), expected);
+        assert_eq!(
+            sparse_a.mul_sparse_vector(&SparseVector::from_dense(&x)),
+            SparseVector::from_dense(&expected)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn dimension_mismatch() {
+        CsrMatrix::<i32>::new(2, 3).mul(&CsrMatrix::new(2, 3));
+    }
+}
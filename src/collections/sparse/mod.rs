@@ -0,0 +1,45 @@
+//! Sparse matrices and vectors, storing only their non zero values.
+//!
+//! * [`CooMatrix`] - coordinate list of `(row, col, value)`, easy to build
+//! * [`CsrMatrix`] - compressed sparse rows, fast row access and matrix-vector product
+//! * [`CscMatrix`] - compressed sparse columns, fast column access
+//! * [`SparseVector`] - sorted `(index, value)` pairs
+//!
+//! Every format converts to the others, and `T::default()` is the zero that is not stored.
+//! A graph gives its adjacency matrix in CSR form with
+//! [`Graph::adjacency_matrix`](crate::graph::Graph::adjacency_matrix).
+
+mod compressed;
+pub mod coo;
+pub mod csc;
+pub mod csr;
+pub mod vector;
+
+use std::ops::{Add, Mul};
+
+#[doc(inline)]
+pub use self::coo::CooMatrix;
+#[doc(inline)]
+pub use self::csc::CscMatrix;
+#[doc(inline)]
+pub use self::csr::CsrMatrix;
+#[doc(inline)]
+pub use self::vector::SparseVector;
+
+/// Values that can be stored in a sparse matrix, `T::default()` being the zero.
+pub trait Scalar: Copy + Default + PartialEq + Add<Output = Self> + Mul<Output = Self> {}
+
+impl<T: Copy + Default + PartialEq + Add<Output = T> + Mul<Output = T>> Scalar for T {}
+
+fn is_zero<T: Scalar>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn check_dense<T>(dense: &[Vec<T>]) -> (usize, usize) {
+    let cols = dense.first().map_or(0, Vec::len);
+    assert!(
+        dense.iter().all(|row| row.len() == cols),
+        "every row of a dense matrix must have the same length"
+    );
+    (dense.len(), cols)
+}
@@ -0,0 +1,201 @@
+use super::{is_zero, Scalar};
+
+/// SparseVector structure, a vector storing its non zero values sorted by index.
+///
+/// # Attributes
+///
+/// * `length` - Dimension of the vector
+/// * `indices` - Positions of the non zero values, strictly increasing
+/// * `values` - The non zero values
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseVector<T> {
+    length: usize,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> SparseVector<T> {
+    /// Create a vector of `len` zeros.
+    pub fn new(len: usize) -> Self {
+        Self {
+            length: len,
+            indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Build a vector of dimension `len` from `(index, value)` pairs in any order.
+    ///
+    /// Duplicate indices are summed, and zeros are not stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Dimension of the vector
+    /// * `pairs` - The entries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::sparse::SparseVector;
+    /// fn main() {
+    ///     let a = SparseVector::from_pairs(1000, vec![(999, 2), (3, 1), (3, 1)]);
+    ///     let b = SparseVector::from_pairs(1000, vec![(3, 5), (500, 7)]);
+    ///     assert_eq!(a.nnz(), 2);
+    ///     assert_eq!(a.get(3), 2);
+    ///     assert_eq!(a.dot(&b), 10);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `SparseVector<T>` - The SparseVector that was created
+    ///
+    pub fn from_pairs(len: usize, mut pairs: Vec<(usize, T)>) -> Self {
+        pairs.sort_by_key(|&(index, _)| index);
+        let mut merged: Vec<(usize, T)> = Vec::with_capacity(pairs.len());
+        for (index, value) in pairs {
+            assert!(
+                index < len,
+                "index {} out of bounds for length {}",
+                index,
+                len
+            );
+            match merged.last_mut() {
+                Some(last) if last.0 == index => last.1 = last.1 + value,
+                _ => merged.push((index, value)),
+            }
+        }
+        merged.retain(|(_, value)| !is_zero(value));
+        let (indices, values) = merged.into_iter().unzip();
+        Self {
+            length: len,
+            indices,
+            values,
+        }
+    }
+
+    /// Build a vector from its dense values, keeping the non zero ones.
+    pub fn from_dense(values: &[T]) -> Self {
+        let pairs = values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !is_zero(*value))
+            .map(|(index, &value)| (index, value))
+            .collect();
+        Self::from_pairs(values.len(), pairs)
+    }
+
+    /// Returns the dimension of the vector.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the dimension is 0.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of stored (non zero) values.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the value at `index`, zero if it is not stored.
+    pub fn get(&self, index: usize) -> T {
+        assert!(
+            index < self.length,
+            "index {} out of bounds for length {}",
+            index,
+            self.length
+        );
+        self.indices
+            .binary_search(&index)
+            .map_or(T::default(), |position| self.values[position])
+    }
+
+    /// An iterator over the `(index, value)` non zero entries, by increasing index.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.indices.iter().copied().zip(&self.values)
+    }
+
+    /// Returns the dense values of the vector.
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut dense = vec![T::default(); self.length];
+        for (index, &value) in self.iter() {
+            dense[index] = value;
+        }
+        dense
+    }
+
+    /// Returns the dot product with `other`, in O(nnz of both) by merging the indices.
+    pub fn dot(&self, other: &SparseVector<T>) -> T {
+        assert_eq!(
+            self.length, other.length,
+            "cannot multiply vectors of lengths {} and {}",
+            self.length, other.length
+        );
+        self.dot_sorted(&other.indices, &other.values)
+    }
+
+    /// Returns the dot product with the dense vector `other`, in O(nnz).
+    pub fn dot_dense(&self, other: &[T]) -> T {
+        assert_eq!(
+            self.length,
+            other.len(),
+            "cannot multiply vectors of lengths {} and {}",
+            self.length,
+            other.len()
+        );
+        self.iter().fold(T::default(), |sum, (index, &value)| {
+            sum + value * other[index]
+        })
+    }
+
+    // Dot product with the entries `indices`/`values`, `indices` strictly increasing
+    pub(super) fn dot_sorted(&self, indices: &[usize], values: &[T]) -> T {
+        let (mut i, mut j) = (0, 0);
+        let mut sum = T::default();
+        while i < self.indices.len() && j < indices.len() {
+            match self.indices[i].cmp(&indices[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    sum = sum + self.values[i] * values[j];
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod vector_tests {
+    use super::*;
+
+    #[test]
+    fn dot_products() {
+        let dense_a: Vec<i64> = (0..200)
+            .map(|i| if i % 3 == 0 { i - 50 } else { 0 })
+            .collect();
+        let dense_b: Vec<i64> = (0..200)
+            .map(|i| if i % 4 == 0 { 7 - i } else { 0 })
+            .collect();
+        let a = SparseVector::from_dense(&dense_a);
+        let b = SparseVector::from_dense(&dense_b);
+        let expected: i64 = dense_a.iter().zip(&dense_b).map(|(x, y)| x * y).sum();
+        assert_eq!(a.dot(&b), expected);
+        assert_eq!(a.dot_dense(&dense_b), expected);
+        assert_eq!(a.to_dense(), dense_a);
+        assert_eq!(a.nnz(), dense_a.iter().filter(|&&x| x != 0).count());
+    }
+
+    #[test]
+    fn zeros_are_not_stored() {
+        let vector = SparseVector::from_pairs(5, vec![(1, 2), (1, -2), (4, 0), (0, 1)]);
+        assert_eq!(vector.iter().collect::<Vec<_>>(), vec![(0, &1)]);
+        assert_eq!(vector.get(1), 0);
+    }
+}
@@ -0,0 +1,238 @@
+use super::LinkedList;
+use std::fmt::{self, Debug, Formatter};
+
+/// Stack structure, a last in first out adapter over `LinkedList`.
+///
+/// # Attributes
+///
+/// * `list` - The elements, the top of the stack is the back of the list
+///
+pub struct Stack<T: Copy + PartialEq> {
+    list: LinkedList<T>,
+}
+
+impl<T: Copy + PartialEq> Stack<T> {
+    /// Create an empty Stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::Stack;
+    /// fn main() {
+    ///     let mut stack = Stack::new();
+    ///     stack.push(1);
+    ///     stack.push(2);
+    ///     assert_eq!(stack.peek(), Some(2));
+    ///     assert_eq!(stack.pop(), Some(2));
+    ///     assert_eq!(stack.pop(), Some(1));
+    ///     assert_eq!(stack.pop(), None);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Stack<T>` - The Stack that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            list: LinkedList::new(),
+        }
+    }
+
+    /// Add `value` on top of the stack.
+    pub fn push(&mut self, value: T) {
+        self.list.push_back(value)
+    }
+
+    /// Removes the top element and returns it, or None if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Provides value of the top element, or None if the stack is empty.
+    pub fn peek(&self) -> Option<T> {
+        self.list.back()
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.list.clear()
+    }
+}
+
+impl<T: Copy + PartialEq> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + PartialEq + Debug> Debug for Stack<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Stack(len: {}, top: {:?})", self.len(), self.peek())
+    }
+}
+
+impl<T: Copy + PartialEq> FromIterator<T> for Stack<T> {
+    /// Push the values in order, the last one ends on top.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Self::new();
+        for value in iter {
+            stack.push(value);
+        }
+        stack
+    }
+}
+
+impl<T: Copy + PartialEq> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<super::linked_list::IntoIter<T>>;
+    /// Pops the values from the top to the bottom.
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter().rev()
+    }
+}
+
+/// MinStack structure, a Stack that also reports its minimum in O(1).
+///
+/// A second stack holds the successive minimums: a value is pushed on it when it is not
+/// greater than the current minimum, and popped from it when it leaves the stack.
+///
+/// # Attributes
+///
+/// * `values` - The elements
+/// * `minimums` - The minimums, the current one on top
+///
+pub struct MinStack<T: Copy + PartialOrd> {
+    values: Stack<T>,
+    minimums: Stack<T>,
+}
+
+impl<T: Copy + PartialOrd> MinStack<T> {
+    /// Create an empty MinStack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::MinStack;
+    /// fn main() {
+    ///     let mut stack = MinStack::new();
+    ///     for value in [5, 3, 7, 3, 1] {
+    ///         stack.push(value);
+    ///     }
+    ///     assert_eq!(stack.min(), Some(1));
+    ///     stack.pop();
+    ///     assert_eq!(stack.min(), Some(3));
+    ///     stack.pop();
+    ///     stack.pop();
+    ///     assert_eq!(stack.min(), Some(3));
+    ///     stack.pop();
+    ///     assert_eq!(stack.min(), Some(5));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `MinStack<T>` - The MinStack that was created
+    ///
+    pub fn new() -> Self {
+        Self {
+            values: Stack::new(),
+            minimums: Stack::new(),
+        }
+    }
+
+    /// Add `value` on top of the stack.
+    pub fn push(&mut self, value: T) {
+        // equal values are pushed too, so popping one of them keeps the others counted
+        if self.minimums.peek().is_none_or(|minimum| value <= minimum) {
+            self.minimums.push(value);
+        }
+        self.values.push(value);
+    }
+
+    /// Removes the top element and returns it, or None if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.values.pop()?;
+        if self.minimums.peek() == Some(value) {
+            self.minimums.pop();
+        }
+        Some(value)
+    }
+
+    /// Provides value of the top element, or None if the stack is empty.
+    pub fn peek(&self) -> Option<T> {
+        self.values.peek()
+    }
+
+    /// Returns the smallest element in O(1), or None if the stack is empty.
+    pub fn min(&self) -> Option<T> {
+        self.minimums.peek()
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.minimums.clear();
+    }
+}
+
+impl<T: Copy + PartialOrd> Default for MinStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod stack_tests {
+    use super::*;
+
+    #[test]
+    fn stack() {
+        let mut stack: Stack<i32> = (0..5).collect();
+        assert_eq!(stack.len(), 5);
+        assert_eq!(stack.peek(), Some(4));
+        stack.push(10);
+        assert_eq!(stack.pop(), Some(10));
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn min_stack_matches_naive() {
+        let mut stack = MinStack::new();
+        let mut naive: Vec<i64> = Vec::new();
+        for step in 0..500i64 {
+            if step % 3 == 2 {
+                assert_eq!(stack.pop(), naive.pop());
+            } else {
+                let value = (step * 37) % 23;
+                stack.push(value);
+                naive.push(value);
+            }
+            assert_eq!(stack.min(), naive.iter().copied().min());
+            assert_eq!(stack.len(), naive.len());
+        }
+        stack.clear();
+        assert!(stack.is_empty());
+        assert_eq!(stack.min(), None);
+    }
+}
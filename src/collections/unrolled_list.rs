@@ -0,0 +1,810 @@
+use std::fmt::{self, Debug, Formatter};
+use std::iter::Flatten;
+
+// One node of the list: up to N values stored contiguously
+#[derive(Clone)]
+struct Chunk<T> {
+    items: Vec<T>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// UnrolledList structure, a doubly linked list whose nodes hold up to `N` values each.
+///
+/// Values of a node are stored contiguously, so walking the list touches one node every `N`
+/// values instead of following one pointer per value like `LinkedList`. Nodes live in an arena
+/// and are linked by index; a full node is split in two halves on insertion, and a node is
+/// merged with its successor when a removal leaves both fitting in one node.
+///
+/// # Attributes
+///
+/// * `nodes` - Arena of nodes, in no particular order
+/// * `free` - Indices of the unused nodes of the arena
+/// * `head` - The first node of the list
+/// * `last` - The last node of the list
+/// * `length` - Size of list
+///
+#[derive(Clone)]
+pub struct UnrolledList<T: Copy + PartialEq, const N: usize = 16> {
+    nodes: Vec<Chunk<T>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    last: Option<usize>,
+    length: usize,
+}
+
+impl<T: Copy + PartialEq, const N: usize> UnrolledList<T, N> {
+    /// Create an UnrolledList, `N` is the capacity of each node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let mut liste: UnrolledList<i32, 4> = UnrolledList::new();
+    ///     for i in 0..10 {
+    ///         liste.push_back(i);
+    ///     }
+    ///     liste.push_front(-1);
+    ///     assert_eq!(liste.len(), 11);
+    ///     assert_eq!(liste.pop_front(), Some(-1));
+    ///     assert_eq!(liste.pop_back(), Some(9));
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `UnrolledList<T, N>` - The UnrolledList that was created
+    ///
+    pub fn new() -> Self {
+        assert!(N >= 2, "an UnrolledList node must hold at least 2 values");
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            last: None,
+            length: 0,
+        }
+    }
+
+    // Take a node from the free list or grow the arena
+    fn alloc(&mut self, prev: Option<usize>, next: Option<usize>) -> usize {
+        match self.free.pop() {
+            Some(node) => {
+                self.nodes[node].prev = prev;
+                self.nodes[node].next = next;
+                node
+            }
+            None => {
+                self.nodes.push(Chunk {
+                    items: Vec::with_capacity(N),
+                    prev,
+                    next,
+                });
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    // Add an empty node after `prev`, or at the front if `prev` is None
+    fn link_after(&mut self, prev: Option<usize>) -> usize {
+        let next = match prev {
+            Some(prev) => self.nodes[prev].next,
+            None => self.head,
+        };
+        let node = self.alloc(prev, next);
+        match prev {
+            Some(prev) => self.nodes[prev].next = Some(node),
+            None => self.head = Some(node),
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = Some(node),
+            None => self.last = Some(node),
+        }
+        node
+    }
+
+    fn unlink(&mut self, node: usize) {
+        let Chunk { prev, next, .. } = self.nodes[node];
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.last = prev,
+        }
+        self.nodes[node].items.clear();
+        self.free.push(node);
+    }
+
+    // Insert `value` at `offset` in `node`, splitting the node when it is full.
+    // Returns where the value landed.
+    fn insert_at(&mut self, node: usize, offset: usize, value: T) -> (usize, usize) {
+        self.length += 1;
+        if self.nodes[node].items.len() < N {
+            self.nodes[node].items.insert(offset, value);
+            return (node, offset);
+        }
+        let half = N / 2;
+        let new = self.link_after(Some(node));
+        let (left, right) = if node < new {
+            let (left, right) = self.nodes.split_at_mut(new);
+            (&mut left[node], &mut right[0])
+        } else {
+            let (left, right) = self.nodes.split_at_mut(node);
+            (&mut right[0], &mut left[new])
+        };
+        right.items.extend(left.items.drain(half..));
+        if offset <= half {
+            left.items.insert(offset, value);
+            (node, offset)
+        } else {
+            right.items.insert(offset - half, value);
+            (new, offset - half)
+        }
+    }
+
+    // Remove the value at `offset` in `node`, merging the node with the next one when both fit
+    // in one node. Returns the value and the position of the value that followed it.
+    fn remove_at(&mut self, node: usize, offset: usize) -> (T, Option<(usize, usize)>) {
+        self.length -= 1;
+        let value = self.nodes[node].items.remove(offset);
+        let next = self.nodes[node].next;
+        if self.nodes[node].items.is_empty() {
+            self.unlink(node);
+            return (value, next.map(|next| (next, 0)));
+        }
+        if let Some(next) = next {
+            if self.nodes[node].items.len() + self.nodes[next].items.len() <= N {
+                let moved = std::mem::take(&mut self.nodes[next].items);
+                self.nodes[node].items.extend_from_slice(&moved);
+                self.nodes[next].items = moved;
+                self.unlink(next);
+            }
+        }
+        if offset < self.nodes[node].items.len() {
+            (value, Some((node, offset)))
+        } else {
+            (value, self.nodes[node].next.map(|next| (next, 0)))
+        }
+    }
+
+    /// Provides value of front element, or None if the list is empty.
+    pub fn front(&self) -> Option<T> {
+        self.head.map(|head| self.nodes[head].items[0])
+    }
+
+    /// Provides value of back element, or None if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let mut liste: UnrolledList<i32> = UnrolledList::new();
+    ///     assert_eq!(liste.back(), None);
+    ///     liste.push_back(5);
+    ///     assert_eq!(liste.back(), Some(5));
+    /// }
+    /// ```
+    ///
+    pub fn back(&self) -> Option<T> {
+        self.back_ref().copied()
+    }
+
+    /// Provides a reference to the back element, or None if the list is empty.
+    pub fn back_ref(&self) -> Option<&T> {
+        self.last.and_then(|last| self.nodes[last].items.last())
+    }
+
+    /// Provides a mutable reference to the back element, or None if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let mut liste: UnrolledList<i32> = UnrolledList::new();
+    ///     liste.push_back(5);
+    ///     if let Some(x) = liste.back_mut() {
+    ///         *x = 8;
+    ///     }
+    ///     assert_eq!(liste.back(), Some(8));
+    /// }
+    /// ```
+    ///
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.last.and_then(|last| self.nodes[last].items.last_mut())
+    }
+
+    /// Add an element to the back of list
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add in the list
+    ///
+    pub fn push_back(&mut self, value: T) {
+        let node = match self.last {
+            Some(last) if self.nodes[last].items.len() < N => last,
+            last => self.link_after(last),
+        };
+        self.nodes[node].items.push(value);
+        self.length += 1;
+    }
+
+    /// Add an element to the back of list
+    pub fn add(&mut self, value: T) {
+        self.push_back(value)
+    }
+
+    /// Removes the last element from a list and returns it, or None if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self.last?;
+        let value = self.nodes[last].items.pop();
+        if self.nodes[last].items.is_empty() {
+            self.unlink(last);
+        }
+        self.length -= 1;
+        value
+    }
+
+    /// Add an element to the front of list
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to add in the list
+    ///
+    pub fn push_front(&mut self, value: T) {
+        let node = match self.head {
+            Some(head) if self.nodes[head].items.len() < N => head,
+            _ => self.link_after(None),
+        };
+        self.nodes[node].items.insert(0, value);
+        self.length += 1;
+    }
+
+    /// Removes the first element from a list and returns it, or None if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        let value = self.nodes[head].items.remove(0);
+        if self.nodes[head].items.is_empty() {
+            self.unlink(head);
+        }
+        self.length -= 1;
+        Some(value)
+    }
+
+    /// Removes all elements from the UnrolledList.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns true if self is empty
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns len of list
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns size of list
+    pub fn size(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the element at `index`, or None if out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.iter().nth(index).copied()
+    }
+
+    /// Returns index of _value or -1 if not in UnrolledList
+    ///
+    /// # Arguments
+    ///
+    /// * `_value` - The value whose index we want to know
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let mut liste: UnrolledList<i32> = UnrolledList::new();
+    ///     liste.push_back(1);
+    ///     liste.push_back(3);
+    ///     liste.push_back(2);
+    ///     assert_eq!(liste.index_of(3), 1);
+    ///     assert_eq!(liste.index_of(83), -1);
+    /// }
+    /// ```
+    ///
+    pub fn index_of(&self, _value: T) -> isize {
+        self.iter()
+            .position(|value| *value == _value)
+            .map_or(-1, |index| index as isize)
+    }
+
+    // Nodes in list order
+    fn order(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.nodes.len() - self.free.len());
+        let mut node = self.head;
+        while let Some(current) = node {
+            order.push(current);
+            node = self.nodes[current].next;
+        }
+        order
+    }
+
+    /// An iterator over references to the elements, from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let liste: UnrolledList<i32, 4> = (0..10).collect();
+    ///     assert_eq!(liste.iter().sum::<i32>(), 45);
+    ///     assert_eq!(liste.iter().rev().next(), Some(&9));
+    /// }
+    /// ```
+    ///
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            list: self,
+            front: self
+                .head
+                .map_or([].iter(), |head| self.nodes[head].items.iter()),
+            front_node: self.head,
+            back: self
+                .last
+                .map_or([].iter(), |last| self.nodes[last].items.iter()),
+            back_node: self.last,
+            remaining: self.length,
+        }
+    }
+
+    /// An iterator over mutable references to the elements, from front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let order = self.order();
+        let mut chunks: Vec<Option<&mut [T]>> = self
+            .nodes
+            .iter_mut()
+            .map(|chunk| Some(chunk.items.as_mut_slice()))
+            .collect();
+        let ordered: Vec<&mut [T]> = order
+            .into_iter()
+            .filter_map(|node| chunks[node].take())
+            .collect();
+        IterMut(ordered.into_iter().flatten())
+    }
+
+    /// A cursor with editing operations, placed on the front element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::collections::UnrolledList;
+    /// fn main() {
+    ///     let mut liste: UnrolledList<i32> = (1..5).collect();
+    ///     let mut cursor = liste.cursor_front_mut();
+    ///     cursor.move_next();
+    ///     cursor.insert_before(10);
+    ///     cursor.insert_after(20);
+    ///     assert_eq!(cursor.remove_current(), Some(2));
+    ///     assert_eq!(cursor.current(), Some(&mut 20));
+    ///     assert_eq!(liste.iter().copied().collect::<Vec<_>>(), vec![1, 10, 20, 3, 4]);
+    /// }
+    /// ```
+    ///
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            position: self.head.map(|head| (head, 0)),
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// A cursor with editing operations, placed on the back element.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut {
+            position: self
+                .last
+                .map(|last| (last, self.nodes[last].items.len() - 1)),
+            index: self.length.saturating_sub(1),
+            list: self,
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + PartialEq + Debug, const N: usize> Debug for UnrolledList<T, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> PartialEq for UnrolledList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> Extend<T> for UnrolledList<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Iterator over the elements of an `UnrolledList`, see [`UnrolledList::iter`].
+pub struct Iter<'a, T: Copy + PartialEq, const N: usize> {
+    list: &'a UnrolledList<T, N>,
+    front: std::slice::Iter<'a, T>,
+    front_node: Option<usize>,
+    back: std::slice::Iter<'a, T>,
+    back_node: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T: Copy + PartialEq, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // the front and the back walk the same nodes from both ends, counting
+        // the remaining elements is enough to stop them where they meet
+        loop {
+            if let Some(value) = self.front.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+            let next = self.list.nodes[self.front_node?].next;
+            self.front_node = next;
+            self.front = self.list.nodes[next?].items.iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Copy + PartialEq, const N: usize> DoubleEndedIterator for Iter<'a, T, N> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        loop {
+            if let Some(value) = self.back.next_back() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+            let prev = self.list.nodes[self.back_node?].prev;
+            self.back_node = prev;
+            self.back = self.list.nodes[prev?].items.iter();
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+/// Iterator over mutable references to the elements of an `UnrolledList`,
+/// see [`UnrolledList::iter_mut`].
+pub struct IterMut<'a, T>(Flatten<std::vec::IntoIter<&'a mut [T]>>);
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.0.next()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.0.next_back()
+    }
+}
+
+/// Owning iterator over the elements of an `UnrolledList`.
+pub struct IntoIter<T>(Flatten<std::vec::IntoIter<Vec<T>>>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> IntoIterator for UnrolledList<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(mut self) -> IntoIter<T> {
+        let chunks: Vec<Vec<T>> = self
+            .order()
+            .into_iter()
+            .map(|node| std::mem::take(&mut self.nodes[node].items))
+            .collect();
+        IntoIter(chunks.into_iter().flatten())
+    }
+}
+
+impl<'a, T: Copy + PartialEq, const N: usize> IntoIterator for &'a UnrolledList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+    fn into_iter(self) -> Iter<'a, T, N> {
+        self.iter()
+    }
+}
+
+impl<'a, T: Copy + PartialEq, const N: usize> IntoIterator for &'a mut UnrolledList<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// A cursor over an `UnrolledList`, able to insert and remove elements where it stands.
+///
+/// Past the back element the cursor is on a "ghost" position holding no element, from which
+/// `move_next` goes to the front, like `std::collections::linked_list::CursorMut`.
+///
+/// # Attributes
+///
+/// * `list` - The list being edited
+/// * `position` - The node and the offset inside it of the current element, None on the ghost
+/// * `index` - Index of the current element, `len()` on the ghost
+///
+pub struct CursorMut<'a, T: Copy + PartialEq, const N: usize> {
+    list: &'a mut UnrolledList<T, N>,
+    position: Option<(usize, usize)>,
+    index: usize,
+}
+
+impl<T: Copy + PartialEq, const N: usize> CursorMut<'_, T, N> {
+    /// Returns the index of the current element, or None on the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.position.map(|_| self.index)
+    }
+
+    /// Returns a mutable reference to the current element, or None on the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        let (node, offset) = self.position?;
+        Some(&mut self.list.nodes[node].items[offset])
+    }
+
+    /// Move to the next element, from the back element to the ghost position and from the
+    /// ghost position to the front element.
+    pub fn move_next(&mut self) {
+        self.position = match self.position {
+            None => {
+                self.index = 0;
+                self.list.head.map(|head| (head, 0))
+            }
+            Some((node, offset)) => {
+                self.index += 1;
+                if offset + 1 < self.list.nodes[node].items.len() {
+                    Some((node, offset + 1))
+                } else {
+                    self.list.nodes[node].next.map(|next| (next, 0))
+                }
+            }
+        };
+    }
+
+    /// Move to the previous element, from the front element to the ghost position and from the
+    /// ghost position to the back element.
+    pub fn move_prev(&mut self) {
+        let previous = match self.position {
+            None => self.list.last,
+            Some((node, offset)) if offset > 0 => {
+                self.position = Some((node, offset - 1));
+                self.index -= 1;
+                return;
+            }
+            Some((node, _)) => self.list.nodes[node].prev,
+        };
+        match previous {
+            Some(previous) => {
+                self.position = Some((previous, self.list.nodes[previous].items.len() - 1));
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.length - 1);
+            }
+            None => {
+                self.position = None;
+                self.index = self.list.length;
+            }
+        }
+    }
+
+    /// Insert `value` before the current element, the cursor stays on the current element.
+    /// On the ghost position, `value` is added to the back.
+    pub fn insert_before(&mut self, value: T) {
+        match self.position {
+            None => {
+                self.list.push_back(value);
+                self.index = self.list.length;
+            }
+            Some((node, offset)) => {
+                let (node, offset) = self.list.insert_at(node, offset, value);
+                // the current element is right after the inserted one
+                self.position = if offset + 1 < self.list.nodes[node].items.len() {
+                    Some((node, offset + 1))
+                } else {
+                    self.list.nodes[node].next.map(|next| (next, 0))
+                };
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Insert `value` after the current element, the cursor stays on the current element.
+    /// On the ghost position, `value` is added to the front.
+    pub fn insert_after(&mut self, value: T) {
+        match self.position {
+            None => {
+                self.list.push_front(value);
+                self.index = self.list.length;
+            }
+            Some((node, offset)) => {
+                let (inserted, at) = self.list.insert_at(node, offset + 1, value);
+                // the current element is right before the inserted one, even when a split
+                // moved both to the new node
+                self.position = Some((inserted, at - 1));
+            }
+        }
+    }
+
+    /// Remove the current element and return it, the cursor moves to the next element.
+    /// Returns None on the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let (node, offset) = self.position?;
+        let (value, next) = self.list.remove_at(node, offset);
+        self.position = next;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod unrolled_list_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    fn check_links<T: Copy + PartialEq, const N: usize>(list: &UnrolledList<T, N>) {
+        let order = list.order();
+        assert_eq!(
+            order
+                .iter()
+                .map(|&node| list.nodes[node].items.len())
+                .sum::<usize>(),
+            list.len()
+        );
+        for window in order.windows(2) {
+            assert_eq!(list.nodes[window[1]].prev, Some(window[0]));
+        }
+        assert_eq!(order.last().copied(), list.last);
+        assert!(order
+            .iter()
+            .all(|&node| (1..=N).contains(&list.nodes[node].items.len())));
+    }
+
+    #[test]
+    fn push_and_pop_both_ends() {
+        let mut list: UnrolledList<i32, 4> = UnrolledList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop_front(), None);
+        for i in 0..10 {
+            list.push_back(i);
+            list.push_front(-i);
+        }
+        check_links(&list);
+        assert_eq!(list.len(), 20);
+        assert_eq!(list.front(), Some(-9));
+        assert_eq!(list.back(), Some(9));
+        assert_eq!(list.index_of(0), 9);
+        assert_eq!(list.index_of(100), -1);
+        assert_eq!(list.get(19), Some(9));
+        for i in (0..10).rev() {
+            assert_eq!(list.pop_back(), Some(i));
+            assert_eq!(list.pop_front(), Some(-i));
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+        list.push_back(1);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list: UnrolledList<u32, 3> = (0..20).collect();
+        for value in list.iter_mut() {
+            *value *= 2;
+        }
+        assert!(list.iter().copied().eq((0..20).map(|x| x * 2)));
+        assert!(list.iter().rev().copied().eq((0..20).rev().map(|x| x * 2)));
+        let mut both = list.iter();
+        assert_eq!(both.next(), Some(&0));
+        assert_eq!(both.next_back(), Some(&38));
+        assert_eq!(both.len(), 18);
+        assert_eq!(both.count(), 18);
+        let small: UnrolledList<u32, 3> = list.iter().copied().take(4).collect();
+        assert_eq!(format!("{:?}", small), "[0, 2, 4, 6]");
+        assert_eq!(
+            list.into_iter().rev().take(2).collect::<Vec<_>>(),
+            vec![38, 36]
+        );
+    }
+
+    #[test]
+    fn cursor_matches_vec() {
+        let mut rng = Rng::new(34);
+        let mut list: UnrolledList<usize, 4> = UnrolledList::new();
+        let mut naive: Vec<usize> = Vec::new();
+        // index of the cursor in `naive`, naive.len() on the ghost position
+        let mut index = 0;
+        for step in 0..3000 {
+            let mut cursor = list.cursor_front_mut();
+            for _ in 0..index {
+                cursor.move_next();
+            }
+            match rng.gen_range(0..5) {
+                0 => {
+                    cursor.insert_before(step);
+                    naive.insert(index, step);
+                    index += 1;
+                }
+                1 => {
+                    cursor.insert_after(step);
+                    if index < naive.len() {
+                        naive.insert(index + 1, step);
+                    } else {
+                        naive.insert(0, step);
+                        index += 1;
+                    }
+                }
+                2 => {
+                    let removed = cursor.remove_current();
+                    let expected = (index < naive.len()).then(|| naive.remove(index));
+                    assert_eq!(removed, expected);
+                }
+                3 => {
+                    cursor.move_prev();
+                    index = match index {
+                        0 => naive.len(),
+                        _ => index - 1,
+                    };
+                }
+                _ => {
+                    cursor.move_next();
+                    index = if index == naive.len() { 0 } else { index + 1 };
+                }
+            }
+            assert_eq!(cursor.index(), (index < naive.len()).then_some(index));
+            assert_eq!(cursor.current().copied(), naive.get(index).copied());
+            check_links(&list);
+            assert!(list.iter().eq(naive.iter()));
+        }
+    }
+}
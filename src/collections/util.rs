@@ -0,0 +1,23 @@
+use std::ops::{Bound, RangeBounds};
+
+/// Convert any range of indices into `start..end`, panicking like slice indexing when out of bounds.
+pub(super) fn range_to_bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "range {}..{} out of bounds for length {}",
+        start,
+        end,
+        len
+    );
+    (start, end)
+}
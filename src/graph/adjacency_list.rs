@@ -0,0 +1,421 @@
+use crate::collections::sparse::{CsrMatrix, Scalar};
+use std::fmt::{self, Debug, Formatter};
+
+/// An edge of a `Graph`: its endpoints and its weight.
+///
+/// # Attributes
+///
+/// * `source` - The node the edge leaves
+/// * `target` - The node the edge enters
+/// * `weight` - The data attached to the edge
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge<E> {
+    pub source: usize,
+    pub target: usize,
+    pub weight: E,
+}
+
+/// Graph structure, nodes and edges stored as adjacency lists.
+///
+/// Nodes and edges are identified by their index, given in insertion order by `add_node` and
+/// `add_edge`. Every node carries a value of type `N` and every edge a weight of type `E`;
+/// use `()` when nothing is needed. Parallel edges and self loops are allowed.
+///
+/// In an undirected graph every edge appears in the adjacency list of both its endpoints.
+///
+/// # Attributes
+///
+/// * `nodes` - The value of each node
+/// * `edges` - The edges, in insertion order
+/// * `adjacency` - For each node, the `(neighbor, edge index)` of its outgoing edges
+/// * `directed` - Whether edges go one way only
+///
+#[derive(Clone)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<Edge<E>>,
+    adjacency: Vec<Vec<(usize, usize)>>,
+    directed: bool,
+}
+
+impl<N, E> Graph<N, E> {
+    /// Create an empty directed graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::graph::Graph;
+    /// fn main() {
+    ///     let mut graph = Graph::directed();
+    ///     let paris = graph.add_node("Paris");
+    ///     let lyon = graph.add_node("Lyon");
+    ///     graph.add_edge(paris, lyon, 465);
+    ///     assert_eq!(graph.neighbors(paris).collect::<Vec<_>>(), vec![lyon]);
+    ///     assert_eq!(graph.neighbors(lyon).count(), 0);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Graph<N, E>` - The Graph that was created
+    ///
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    /// Create an empty undirected graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::graph::Graph;
+    /// fn main() {
+    ///     let mut graph = Graph::undirected();
+    ///     let a = graph.add_node(());
+    ///     let b = graph.add_node(());
+    ///     graph.add_edge(a, b, ());
+    ///     assert!(graph.has_edge(b, a));
+    /// }
+    /// ```
+    ///
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    /// Create an empty graph, directed or not.
+    pub fn new(directed: bool) -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            adjacency: Vec::new(),
+            directed,
+        }
+    }
+
+    /// Returns true if the edges go one way only.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges, an undirected edge counting once.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Add a node carrying `value`.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index of the new node
+    ///
+    pub fn add_node(&mut self, value: N) -> usize {
+        self.nodes.push(value);
+        self.adjacency.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// Add an edge from `source` to `target` carrying `weight`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Index of the node the edge leaves
+    /// * `target` - Index of the node the edge enters
+    /// * `weight` - The data attached to the edge
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index of the new edge
+    ///
+    pub fn add_edge(&mut self, source: usize, target: usize, weight: E) -> usize {
+        for node in [source, target] {
+            assert!(
+                node < self.nodes.len(),
+                "node {} out of bounds for {} nodes",
+                node,
+                self.nodes.len()
+            );
+        }
+        let edge = self.edges.len();
+        self.edges.push(Edge {
+            source,
+            target,
+            weight,
+        });
+        self.adjacency[source].push((target, edge));
+        if !self.directed && source != target {
+            self.adjacency[target].push((source, edge));
+        }
+        edge
+    }
+
+    /// Returns the value of `node`.
+    pub fn node(&self, node: usize) -> &N {
+        &self.nodes[node]
+    }
+
+    /// Returns a mutable reference to the value of `node`.
+    pub fn node_mut(&mut self, node: usize) -> &mut N {
+        &mut self.nodes[node]
+    }
+
+    /// Returns the values of all the nodes, by index.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Returns the edge `edge`.
+    pub fn edge(&self, edge: usize) -> &Edge<E> {
+        &self.edges[edge]
+    }
+
+    /// Returns all the edges, by index.
+    pub fn edges(&self) -> &[Edge<E>] {
+        &self.edges
+    }
+
+    /// An iterator over the nodes reached by an edge from `node`, in insertion order of the
+    /// edges. A node appears once per edge leading to it.
+    pub fn neighbors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacency[node].iter().map(|&(neighbor, _)| neighbor)
+    }
+
+    /// An iterator over the `(neighbor, weight)` of the edges leaving `node`.
+    pub fn edges_from(&self, node: usize) -> impl Iterator<Item = (usize, &E)> {
+        self.adjacency[node]
+            .iter()
+            .map(move |&(neighbor, edge)| (neighbor, &self.edges[edge].weight))
+    }
+
+    /// Returns the `(neighbor, edge index)` of the edges leaving `node`, in insertion order.
+    pub fn adjacent(&self, node: usize) -> &[(usize, usize)] {
+        &self.adjacency[node]
+    }
+
+    /// Returns the number of edges leaving `node`.
+    pub fn degree(&self, node: usize) -> usize {
+        self.adjacency[node].len()
+    }
+
+    /// Returns the index of an edge from `source` to `target`, if any.
+    pub fn find_edge(&self, source: usize, target: usize) -> Option<usize> {
+        self.adjacency[source]
+            .iter()
+            .find(|&&(neighbor, _)| neighbor == target)
+            .map(|&(_, edge)| edge)
+    }
+
+    /// Returns true if there is an edge from `source` to `target`.
+    pub fn has_edge(&self, source: usize, target: usize) -> bool {
+        self.find_edge(source, target).is_some()
+    }
+
+    /// Returns the graph with every edge reversed, the same graph if it is undirected.
+    pub fn reversed(&self) -> Graph<N, E>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut reversed = Graph::new(self.directed);
+        for value in &self.nodes {
+            reversed.add_node(value.clone());
+        }
+        for edge in &self.edges {
+            reversed.add_edge(edge.target, edge.source, edge.weight.clone());
+        }
+        reversed
+    }
+
+    /// Returns a graph with the same structure and indices, the value of every node and the
+    /// weight of every edge being transformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - Called with the index and the value of each node
+    /// * `edge` - Called with the index and each edge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::graph::Graph;
+    /// fn main() {
+    ///     let graph = Graph::from_edges(true, 2, vec![(0, 1, 2.5)]);
+    ///     let labeled = graph.map(|node, _| format!("n{}", node), |_, edge| edge.weight * 2.0);
+    ///     assert_eq!(labeled.nodes(), &["n0", "n1"]);
+    ///     assert_eq!(labeled.edge(0).weight, 5.0);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Graph<M, F>` - The transformed graph
+    ///
+    pub fn map<M, F>(
+        &self,
+        mut node: impl FnMut(usize, &N) -> M,
+        mut edge: impl FnMut(usize, &Edge<E>) -> F,
+    ) -> Graph<M, F> {
+        Graph {
+            nodes: self
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(index, value)| node(index, value))
+                .collect(),
+            edges: self
+                .edges
+                .iter()
+                .enumerate()
+                .map(|(index, value)| Edge {
+                    source: value.source,
+                    target: value.target,
+                    weight: edge(index, value),
+                })
+                .collect(),
+            adjacency: self.adjacency.clone(),
+            directed: self.directed,
+        }
+    }
+
+    /// Returns the sparse adjacency matrix of the graph: the entry of row `i` and column `j`
+    /// is the weight of the edge from `i` to `j`, in both directions for an undirected graph.
+    /// Parallel edges add up.
+    ///
+    /// # Arguments
+    ///
+    /// * `weight` - The value of an edge in the matrix, e.g. `|_| 1` to count edges
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::graph::Graph;
+    /// fn main() {
+    ///     let graph = Graph::from_edges(false, 3, vec![(0, 1, 2.5), (1, 2, 1.0)]);
+    ///     let matrix = graph.adjacency_matrix(|&weight| weight);
+    ///     assert_eq!(matrix.get(1, 0), 2.5);
+    ///     assert_eq!(matrix.get(0, 2), 0.0);
+    ///     // the degrees, as the product with a vector of ones
+    ///     let degrees = graph.adjacency_matrix(|_| 1).mul_vec(&[1, 1, 1]);
+    ///     assert_eq!(degrees, vec![1, 2, 1]);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `CsrMatrix<T>` - The `node_count()` by `node_count()` matrix
+    ///
+    pub fn adjacency_matrix<T: Scalar>(&self, mut weight: impl FnMut(&E) -> T) -> CsrMatrix<T> {
+        let mut triplets = Vec::with_capacity(self.edges.len() * 2);
+        for (node, adjacent) in self.adjacency.iter().enumerate() {
+            for &(neighbor, edge) in adjacent {
+                triplets.push((node, neighbor, weight(&self.edges[edge].weight)));
+            }
+        }
+        let n = self.nodes.len();
+        CsrMatrix::from_triplets(n, n, triplets)
+    }
+}
+
+impl<E> Graph<(), E> {
+    /// Create a graph of `nodes` nodes carrying no value and the edges `(source, target, weight)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::graph::Graph;
+    /// fn main() {
+    ///     let graph = Graph::from_edges(true, 3, vec![(0, 1, 2.5), (1, 2, 1.0)]);
+    ///     assert_eq!(graph.edge_count(), 2);
+    ///     assert_eq!(graph.edges_from(1).collect::<Vec<_>>(), vec![(2, &1.0)]);
+    /// }
+    /// ```
+    ///
+    pub fn from_edges<I>(directed: bool, nodes: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, E)>,
+    {
+        let mut graph = Graph::new(directed);
+        for _ in 0..nodes {
+            graph.add_node(());
+        }
+        for (source, target, weight) in edges {
+            graph.add_edge(source, target, weight);
+        }
+        graph
+    }
+}
+
+impl<N: Debug, E: Debug> Debug for Graph<N, E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Graph")
+            .field("directed", &self.directed)
+            .field("nodes", &self.nodes)
+            .field("edges", &self.edges)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod adjacency_list_tests {
+    use super::*;
+
+    #[test]
+    fn directed_and_undirected() {
+        let mut directed = Graph::directed();
+        let mut undirected = Graph::undirected();
+        for graph in [&mut directed, &mut undirected] {
+            for name in ["a", "b", "c"] {
+                graph.add_node(name);
+            }
+            graph.add_edge(0, 1, 1);
+            graph.add_edge(1, 2, 2);
+            graph.add_edge(2, 2, 3);
+        }
+        assert!(directed.has_edge(0, 1) && !directed.has_edge(1, 0));
+        assert!(undirected.has_edge(0, 1) && undirected.has_edge(1, 0));
+        assert_eq!(directed.degree(2), 1);
+        // a self loop is listed once
+        assert_eq!(undirected.degree(2), 2);
+        assert_eq!(
+            undirected.edges_from(1).collect::<Vec<_>>(),
+            vec![(0, &1), (2, &2)]
+        );
+        assert_eq!(directed.reversed().find_edge(2, 1), Some(1));
+        *directed.node_mut(0) = "z";
+        assert_eq!(directed.nodes(), &["z", "b", "c"]);
+    }
+
+    #[test]
+    fn adjacency_matrix() {
+        let edges = vec![(0, 1, 1), (1, 2, 2), (2, 2, 3), (0, 1, 4)];
+        let directed = Graph::from_edges(true, 4, edges.clone()).adjacency_matrix(|&w| w);
+        let undirected = Graph::from_edges(false, 4, edges).adjacency_matrix(|&w| w);
+        assert_eq!(directed.shape(), (4, 4));
+        assert_eq!(
+            directed.to_dense(),
+            vec![
+                vec![0, 5, 0, 0],
+                vec![0, 0, 2, 0],
+                vec![0, 0, 3, 0],
+                vec![0; 4]
+            ]
+        );
+        assert_eq!(undirected.to_dense(), {
+            let mut dense = directed.to_dense();
+            dense[1][0] = 5;
+            dense[2][1] = 2;
+            dense
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn edge_to_missing_node() {
+        Graph::<(), ()>::from_edges(true, 2, vec![(0, 2, ())]);
+    }
+}
@@ -0,0 +1,312 @@
+use super::Graph;
+use crate::collections::DisjointSet;
+use std::collections::VecDeque;
+
+/// Group the nodes joined by a path, ignoring the direction of the edges.
+///
+/// Every edge merges the sets of its endpoints in a `DisjointSet`. For a directed graph
+/// these are the weakly connected components. O(E α(V)).
+///
+/// # Arguments
+///
+/// * `graph` - Any graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{connected_components, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(false, 6, vec![(0, 1, ()), (1, 2, ()), (3, 4, ())]);
+///     assert_eq!(connected_components(&graph), vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Vec<Vec<usize>>` - Each component with its nodes in increasing order, components ordered by their smallest node
+///
+pub fn connected_components<N, E>(graph: &Graph<N, E>) -> Vec<Vec<usize>> {
+    let mut sets = DisjointSet::new(graph.node_count());
+    for edge in graph.edges() {
+        sets.union(edge.source, edge.target);
+    }
+    sets.groups()
+}
+
+/// Strongly connected components with Tarjan's algorithm, in a single depth first search.
+///
+/// Each node gets its discovery index and the lowest index reachable from its subtree
+/// through the nodes still on the stack; a node whose two numbers are equal is the root of
+/// a component, made of the nodes above it on the stack. The search is iterative. O(V + E).
+///
+/// # Arguments
+///
+/// * `graph` - A directed graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{tarjan_scc, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(true, 5, vec![(0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ()), (3, 4, ()), (4, 3, ())]);
+///     assert_eq!(tarjan_scc(&graph), vec![vec![3, 4], vec![0, 1, 2]]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Vec<Vec<usize>>` - Each component with its nodes in increasing order, in reverse topological order: no edge leads from a component to an earlier one
+///
+pub fn tarjan_scc<N, E>(graph: &Graph<N, E>) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let mut index = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut counter = 0;
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+        let mut calls = vec![(root, 0)];
+        index[root] = counter;
+        low[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        while let Some((node, next)) = calls.last_mut() {
+            let node = *node;
+            if let Some(&(neighbor, _)) = graph.adjacent(node).get(*next) {
+                *next += 1;
+                if index[neighbor] == usize::MAX {
+                    index[neighbor] = counter;
+                    low[neighbor] = counter;
+                    counter += 1;
+                    stack.push(neighbor);
+                    on_stack[neighbor] = true;
+                    calls.push((neighbor, 0));
+                } else if on_stack[neighbor] {
+                    low[node] = low[node].min(index[neighbor]);
+                }
+                continue;
+            }
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[node]);
+            }
+            if low[node] == index[node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Strongly connected components with Kosaraju's algorithm, in two depth first searches.
+///
+/// The first search records the nodes by finishing time; the second runs on the reversed
+/// graph, taking the roots by decreasing finishing time, and each of its trees is a
+/// component. O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{kosaraju_scc, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(true, 5, vec![(0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ()), (3, 4, ()), (4, 3, ())]);
+///     assert_eq!(kosaraju_scc(&graph), vec![vec![0, 1, 2], vec![3, 4]]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Vec<Vec<usize>>` - Each component with its nodes in increasing order, in topological order: no edge leads from a component to an earlier one
+///
+pub fn kosaraju_scc<N, E>(graph: &Graph<N, E>) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let mut visited = vec![false; n];
+    let mut finished = Vec::with_capacity(n);
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            match graph.adjacent(node).get(*next) {
+                Some(&(neighbor, _)) => {
+                    *next += 1;
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        stack.push((neighbor, 0));
+                    }
+                }
+                None => {
+                    finished.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    let mut reversed = vec![Vec::new(); n];
+    for edge in graph.edges() {
+        reversed[edge.target].push(edge.source);
+        if !graph.is_directed() {
+            reversed[edge.source].push(edge.target);
+        }
+    }
+    let mut assigned = vec![false; n];
+    let mut components = Vec::new();
+    for &root in finished.iter().rev() {
+        if assigned[root] {
+            continue;
+        }
+        assigned[root] = true;
+        let mut component = vec![root];
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            for &previous in &reversed[node] {
+                if !assigned[previous] {
+                    assigned[previous] = true;
+                    component.push(previous);
+                    stack.push(previous);
+                }
+            }
+        }
+        component.sort_unstable();
+        components.push(component);
+    }
+    components
+}
+
+/// Split the nodes in two sides so that every edge joins both sides, if possible.
+///
+/// Each component is colored by a breadth first search, ignoring the direction of the
+/// edges; an edge between two nodes of the same color means an odd cycle. O(V + E).
+///
+/// # Arguments
+///
+/// * `graph` - Any graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{bipartition, Graph};
+/// fn main() {
+///     let square = Graph::from_edges(false, 4, vec![(0, 1, ()), (1, 2, ()), (2, 3, ()), (3, 0, ())]);
+///     assert_eq!(bipartition(&square), Some(vec![false, true, false, true]));
+///     let triangle = Graph::from_edges(false, 3, vec![(0, 1, ()), (1, 2, ()), (2, 0, ())]);
+///     assert_eq!(bipartition(&triangle), None);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<Vec<bool>>` - The side of each node, the smallest node of each component being on side `false`, or None if the graph is not bipartite
+///
+pub fn bipartition<N, E>(graph: &Graph<N, E>) -> Option<Vec<bool>> {
+    let n = graph.node_count();
+    let mut neighbors = vec![Vec::new(); n];
+    for edge in graph.edges() {
+        neighbors[edge.source].push(edge.target);
+        neighbors[edge.target].push(edge.source);
+    }
+    let mut side: Vec<Option<bool>> = vec![None; n];
+    let mut queue = VecDeque::new();
+    for root in 0..n {
+        if side[root].is_some() {
+            continue;
+        }
+        side[root] = Some(false);
+        queue.push_back(root);
+        while let Some(node) = queue.pop_front() {
+            let color = side[node].unwrap();
+            for &neighbor in &neighbors[node] {
+                match side[neighbor] {
+                    None => {
+                        side[neighbor] = Some(!color);
+                        queue.push_back(neighbor);
+                    }
+                    Some(other) if other == color => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Some(side.into_iter().map(Option::unwrap).collect())
+}
+
+/// Returns true if the nodes can be split in two sides with every edge joining both sides.
+pub fn is_bipartite<N, E>(graph: &Graph<N, E>) -> bool {
+    bipartition(graph).is_some()
+}
+
+#[cfg(test)]
+mod components_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    #[test]
+    fn scc_algorithms_agree() {
+        let mut rng = Rng::new(37);
+        for _ in 0..20 {
+            let n = rng.gen_range(1..40);
+            let edges: Vec<(usize, usize, ())> = (0..rng.gen_range(0..3 * n))
+                .map(|_| (rng.gen_range(0..n), rng.gen_range(0..n), ()))
+                .collect();
+            let graph = Graph::from_edges(true, n, edges);
+            let tarjan = tarjan_scc(&graph);
+            let mut kosaraju = kosaraju_scc(&graph);
+            assert_eq!(tarjan.iter().map(Vec::len).sum::<usize>(), n);
+            // same components, in opposite topological orders
+            kosaraju.reverse();
+            let mut sorted_tarjan = tarjan.clone();
+            sorted_tarjan.sort();
+            let mut sorted_kosaraju = kosaraju.clone();
+            sorted_kosaraju.sort();
+            assert_eq!(sorted_tarjan, sorted_kosaraju);
+            // no edge goes from a component to a later one in Tarjan's order
+            let mut component_of = vec![0; n];
+            for (index, component) in tarjan.iter().enumerate() {
+                for &node in component {
+                    component_of[node] = index;
+                }
+            }
+            assert!(graph
+                .edges()
+                .iter()
+                .all(|edge| component_of[edge.source] >= component_of[edge.target]));
+        }
+    }
+
+    #[test]
+    fn components_and_bipartition() {
+        // two directed edges in opposite directions still join the nodes weakly
+        let graph = Graph::from_edges(true, 5, vec![(1, 0, ()), (1, 2, ()), (4, 3, ())]);
+        assert_eq!(
+            connected_components(&graph),
+            vec![vec![0, 1, 2], vec![3, 4]]
+        );
+        assert!(is_bipartite(&graph));
+        let sides = bipartition(&graph).unwrap();
+        assert!(graph
+            .edges()
+            .iter()
+            .all(|edge| sides[edge.source] != sides[edge.target]));
+        let self_loop = Graph::from_edges(false, 1, vec![(0, 0, ())]);
+        assert!(!is_bipartite(&self_loop));
+    }
+}
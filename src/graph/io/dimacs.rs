@@ -0,0 +1,313 @@
+use super::{parse_token, ParseError};
+use crate::graph::Graph;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// A graph read from or written to the DIMACS challenge formats.
+///
+/// The problem line `p <problem> <nodes> <edges>` names the problem: `edge` and `col` graphs
+/// are undirected, the others (`sp`, `max`, `min`, ...) are directed. Nodes are numbered
+/// from 1 in the text and from 0 in the graph.
+///
+/// # Attributes
+///
+/// * `problem` - The problem name of the `p` line
+/// * `graph` - The nodes and the edges, from the `a` or `e` lines
+/// * `source` - The node designated by an `n <node> s` line, for flow problems
+/// * `sink` - The node designated by an `n <node> t` line, for flow problems
+///
+#[derive(Clone, Debug)]
+pub struct Dimacs<E> {
+    pub problem: String,
+    pub graph: Graph<(), E>,
+    pub source: Option<usize>,
+    pub sink: Option<usize>,
+}
+
+/// Returns true if the edges of a DIMACS `problem` go one way only.
+fn is_directed(problem: &str) -> bool {
+    !matches!(problem, "edge" | "col")
+}
+
+/// Parse a graph in a DIMACS format.
+///
+/// The lines are read by their first letter:
+/// * `c` - a comment
+/// * `p <problem> <nodes> <edges>` - the problem line, before any other
+/// * `a <source> <target> [weight]` or `e <source> <target> [weight]` - an edge, its weight
+///   being `E::default()` when missing
+/// * `n <node> s` or `n <node> t` - the source or the sink of a flow problem
+///
+/// # Arguments
+///
+/// * `input` - The text to parse
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::parse_dimacs;
+/// fn main() {
+///     let input = "c a small network\np max 3 2\nn 1 s\nn 3 t\na 1 2 4\na 2 3 2\n";
+///     let network = parse_dimacs::<u32>(input).unwrap();
+///     assert_eq!(network.problem, "max");
+///     assert_eq!((network.source, network.sink), (Some(0), Some(2)));
+///     assert_eq!(network.graph.edge(1).weight, 2);
+///
+///     let error = parse_dimacs::<u32>("p sp 2 1\na 1 3 1\n").unwrap_err();
+///     assert_eq!(error.to_string(), "line 2: node 3 out of range 1..=2");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<Dimacs<E>, ParseError>` - The problem and its graph, or the first problem found
+///
+pub fn parse_dimacs<E: FromStr + Default>(input: &str) -> Result<Dimacs<E>, ParseError> {
+    // the line of the problem line and the number of edges it announces
+    let mut header: Option<(usize, usize)> = None;
+    let mut dimacs = Dimacs {
+        problem: String::new(),
+        graph: Graph::new(true),
+        source: None,
+        sink: None,
+    };
+    let mut lines = 0;
+    for (index, content) in input.lines().enumerate() {
+        let line = index + 1;
+        lines = line;
+        let fields: Vec<&str> = content.split_whitespace().collect();
+        let Some(&kind) = fields.first() else {
+            continue;
+        };
+        if kind == "c" {
+            continue;
+        }
+        let node = |field: &str| -> Result<usize, ParseError> {
+            let node: usize = parse_token(field, line, "node")?;
+            let count = dimacs.graph.node_count();
+            if node == 0 || node > count {
+                return Err(ParseError::new(
+                    line,
+                    format!("node {} out of range 1..={}", node, count),
+                ));
+            }
+            Ok(node - 1)
+        };
+        match (kind, fields.len(), &header) {
+            ("p", 4, None) => {
+                let nodes: usize = parse_token(fields[2], line, "node count")?;
+                let edges = parse_token(fields[3], line, "edge count")?;
+                dimacs.problem = fields[1].to_string();
+                dimacs.graph = Graph::new(is_directed(fields[1]));
+                for _ in 0..nodes {
+                    dimacs.graph.add_node(());
+                }
+                header = Some((line, edges));
+            }
+            ("p", _, Some((first, _))) => {
+                return Err(ParseError::new(
+                    line,
+                    format!("a second problem line, the first is on line {}", first),
+                ));
+            }
+            ("p", _, None) => {
+                return Err(ParseError::new(
+                    line,
+                    "expected `p <problem> <nodes> <edges>`",
+                ));
+            }
+            (_, _, None) => {
+                return Err(ParseError::new(line, "expected the problem line first"));
+            }
+            ("a" | "e", 3 | 4, _) => {
+                let source = node(fields[1])?;
+                let target = node(fields[2])?;
+                let weight = match fields.get(3) {
+                    Some(field) => parse_token(field, line, "weight")?,
+                    None => E::default(),
+                };
+                dimacs.graph.add_edge(source, target, weight);
+            }
+            ("a" | "e", _, _) => {
+                return Err(ParseError::new(
+                    line,
+                    format!("expected `{} <source> <target> [weight]`", kind),
+                ));
+            }
+            ("n", 3, _) => {
+                let designated = node(fields[1])?;
+                match fields[2] {
+                    "s" => dimacs.source = Some(designated),
+                    "t" => dimacs.sink = Some(designated),
+                    other => {
+                        return Err(ParseError::new(
+                            line,
+                            format!("unknown node designation `{}`, expected `s` or `t`", other),
+                        ))
+                    }
+                }
+            }
+            ("n", _, _) => {
+                return Err(ParseError::new(
+                    line,
+                    "expected `n <node> s` or `n <node> t`",
+                ));
+            }
+            _ => {
+                return Err(ParseError::new(
+                    line,
+                    format!("unknown line type `{}`", kind),
+                ));
+            }
+        }
+    }
+    let Some((line, edges)) = header else {
+        return Err(ParseError::new(lines.max(1), "missing the problem line"));
+    };
+    if dimacs.graph.edge_count() != edges {
+        return Err(ParseError::new(
+            line,
+            format!(
+                "the problem line announces {} edges, found {}",
+                edges,
+                dimacs.graph.edge_count()
+            ),
+        ));
+    }
+    Ok(dimacs)
+}
+
+/// Write a graph in a DIMACS format: the problem line, the source and sink designations,
+/// then one `a <source> <target> <weight>` line per edge, or one `e <source> <target>` line
+/// for the undirected `edge` and `col` problems, which carry no weight.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::{write_dimacs, Dimacs};
+/// use rust_algo::graph::Graph;
+/// fn main() {
+///     let network = Dimacs {
+///         problem: "max".to_string(),
+///         graph: Graph::from_edges(true, 3, vec![(0, 1, 4), (1, 2, 2)]),
+///         source: Some(0),
+///         sink: Some(2),
+///     };
+///     assert_eq!(write_dimacs(&network), "p max 3 2\nn 1 s\nn 3 t\na 1 2 4\na 2 3 2\n");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `String` - The text, which `parse_dimacs` reads back
+///
+pub fn write_dimacs<E: Display>(dimacs: &Dimacs<E>) -> String {
+    let graph = &dimacs.graph;
+    assert_eq!(
+        graph.is_directed(),
+        is_directed(&dimacs.problem),
+        "a `{}` problem needs a graph that is {}directed",
+        dimacs.problem,
+        if is_directed(&dimacs.problem) {
+            ""
+        } else {
+            "un"
+        }
+    );
+    let mut output = String::new();
+    writeln!(
+        output,
+        "p {} {} {}",
+        dimacs.problem,
+        graph.node_count(),
+        graph.edge_count()
+    )
+    .unwrap();
+    for (node, designation) in [(dimacs.source, "s"), (dimacs.sink, "t")] {
+        if let Some(node) = node {
+            writeln!(output, "n {} {}", node + 1, designation).unwrap();
+        }
+    }
+    for edge in graph.edges() {
+        if graph.is_directed() {
+            writeln!(
+                output,
+                "a {} {} {}",
+                edge.source + 1,
+                edge.target + 1,
+                edge.weight
+            )
+            .unwrap();
+        } else {
+            writeln!(output, "e {} {}", edge.source + 1, edge.target + 1).unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod dimacs_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let network = Dimacs {
+            problem: "sp".to_string(),
+            graph: Graph::from_edges(true, 4, vec![(0, 1, -2), (1, 2, 7), (3, 3, 0)]),
+            source: None,
+            sink: Some(3),
+        };
+        let parsed = parse_dimacs::<i32>(&write_dimacs(&network)).unwrap();
+        assert_eq!(parsed.problem, "sp");
+        assert_eq!((parsed.source, parsed.sink), (None, Some(3)));
+        assert_eq!(parsed.graph.edges(), network.graph.edges());
+
+        // the weights of an undirected problem are not written
+        let coloring = Dimacs {
+            problem: "edge".to_string(),
+            graph: Graph::from_edges(false, 4, vec![(0, 1, 5), (1, 2, 5), (3, 0, 5)]),
+            source: None,
+            sink: None,
+        };
+        let parsed = parse_dimacs::<i32>(&write_dimacs(&coloring)).unwrap();
+        assert!(!parsed.graph.is_directed());
+        let edges: Vec<_> = parsed
+            .graph
+            .edges()
+            .iter()
+            .map(|edge| (edge.source, edge.target, edge.weight))
+            .collect();
+        assert_eq!(edges, vec![(0, 1, 0), (1, 2, 0), (3, 0, 0)]);
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            ("c nothing\na 1 2 3\n", 2, "expected the problem line first"),
+            (
+                "p sp 2 1\np sp 2 1\n",
+                2,
+                "a second problem line, the first is on line 1",
+            ),
+            (
+                "p sp 2 2\n\na 1 2 1\n",
+                1,
+                "the problem line announces 2 edges, found 1",
+            ),
+            (
+                "p max 2 0\nn 1 x\n",
+                2,
+                "unknown node designation `x`, expected `s` or `t`",
+            ),
+            ("p sp 2 1\nx 1 2\n", 2, "unknown line type `x`"),
+            ("c only comments\n", 1, "missing the problem line"),
+            ("p sp two 0\n", 1, "invalid node count `two`"),
+        ];
+        for (input, line, message) in cases {
+            assert_eq!(
+                parse_dimacs::<i32>(input).unwrap_err(),
+                ParseError::new(line, message)
+            );
+        }
+    }
+}
@@ -0,0 +1,577 @@
+use super::ParseError;
+use crate::graph::Graph;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// The `key=value` attributes of a DOT graph, node or edge.
+pub type Attributes = BTreeMap<String, String>;
+
+/// A node of a DOT graph: its identifier and its attributes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DotNode {
+    pub id: String,
+    pub attributes: Attributes,
+}
+
+/// A graph read from or written to the Graphviz DOT language.
+///
+/// # Attributes
+///
+/// * `name` - The identifier following `graph` or `digraph`, if any
+/// * `attributes` - The attributes of the graph itself
+/// * `graph` - The nodes, in order of first appearance, and the edges with their attributes
+///
+#[derive(Clone, Debug)]
+pub struct DotGraph {
+    pub name: Option<String>,
+    pub attributes: Attributes,
+    pub graph: Graph<DotNode, Attributes>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Id(String),
+    Symbol(&'static str),
+}
+
+/// Split `input` in identifiers and symbols, each with its line, dropping the comments.
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < chars.len() {
+        let next = chars.get(i + 1).copied();
+        match chars[i] {
+            '\n' => {
+                line += 1;
+                i += 1;
+            }
+            c if c.is_whitespace() => i += 1,
+            '/' if next == Some('*') => {
+                let start = line;
+                i += 2;
+                while !(chars.get(i) == Some(&'*') && chars.get(i + 1) == Some(&'/')) {
+                    match chars.get(i) {
+                        None => return Err(ParseError::new(start, "unterminated comment")),
+                        Some('\n') => line += 1,
+                        Some(_) => {}
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            // line comments and preprocessor output
+            '/' | '#' if chars[i] == '#' || next == Some('/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            '-' if next == Some('>') || next == Some('-') => {
+                tokens.push((
+                    Token::Symbol(if next == Some('>') { "->" } else { "--" }),
+                    line,
+                ));
+                i += 2;
+            }
+            '"' => {
+                let start = line;
+                let mut id = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err(ParseError::new(start, "unterminated string")),
+                        Some('"') => break,
+                        Some('\\') if matches!(chars.get(i + 1), Some('"' | '\\')) => {
+                            id.push(chars[i + 1]);
+                            i += 1;
+                        }
+                        Some('\\') if chars.get(i + 1) == Some(&'\n') => {
+                            // a line continuation
+                            line += 1;
+                            i += 1;
+                        }
+                        Some(&c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            id.push(c);
+                        }
+                    }
+                    i += 1;
+                }
+                tokens.push((Token::Id(id), start));
+                i += 1;
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let start = i;
+                i += 1;
+                while chars
+                    .get(i)
+                    .is_some_and(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+                {
+                    i += 1;
+                }
+                tokens.push((Token::Id(chars[start..i].iter().collect()), line));
+            }
+            c => {
+                let symbol = ["{", "}", "[", "]", "=", ";", ","]
+                    .into_iter()
+                    .find(|symbol| symbol.starts_with(c))
+                    .ok_or_else(|| {
+                        ParseError::new(line, format!("unexpected character `{}`", c))
+                    })?;
+                tokens.push((Token::Symbol(symbol), line));
+                i += 1;
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// A cursor over the tokens of a DOT input.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    last_line: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn peek_is(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(found)) if *found == symbol)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn advance(&mut self) {
+        self.position += 1;
+    }
+
+    /// The line of the next token, or the last line at the end of the input.
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.last_line, |&(_, line)| line)
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        let found = match self.peek() {
+            Some(Token::Id(id)) => format!("`{}`", id),
+            Some(Token::Symbol(symbol)) => format!("`{}`", symbol),
+            None => "the end of the input".to_string(),
+        };
+        ParseError::new(
+            self.line(),
+            format!("expected {}, found {}", expected, found),
+        )
+    }
+
+    fn expect(&mut self, symbol: &'static str) -> Result<(), ParseError> {
+        if self.peek_is(symbol) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", symbol)))
+        }
+    }
+
+    fn expect_id(&mut self, what: &str) -> Result<String, ParseError> {
+        match self.peek() {
+            Some(Token::Id(id)) => {
+                let id = id.clone();
+                self.advance();
+                Ok(id)
+            }
+            _ => Err(self.error(what)),
+        }
+    }
+
+    /// Parse any number of `[key=value, ...]` lists into `attributes`.
+    fn attributes(&mut self, attributes: &mut Attributes) -> Result<(), ParseError> {
+        while self.peek_is("[") {
+            self.advance();
+            while !self.peek_is("]") {
+                if self.peek_is(",") || self.peek_is(";") {
+                    self.advance();
+                    continue;
+                }
+                let key = self.expect_id("an attribute or `]`")?;
+                self.expect("=")?;
+                let value = self.expect_id("an attribute value")?;
+                attributes.insert(key, value);
+            }
+            self.advance();
+        }
+        Ok(())
+    }
+}
+
+/// Returns the index of the node `id`, adding it with the `defaults` attributes if it is new.
+fn node_index(
+    graph: &mut Graph<DotNode, Attributes>,
+    indices: &mut HashMap<String, usize>,
+    defaults: &Attributes,
+    id: String,
+) -> usize {
+    *indices.entry(id).or_insert_with_key(|id| {
+        graph.add_node(DotNode {
+            id: id.clone(),
+            attributes: defaults.clone(),
+        })
+    })
+}
+
+/// Parse a graph written in the Graphviz DOT language.
+///
+/// Supported are node and edge statements, including chains `a -> b -> c`, attribute lists,
+/// `node [...]` and `edge [...]` defaults applying to the nodes and edges that follow,
+/// graph attributes as `graph [...]` or `key = value`, quoted identifiers, where `\"` and
+/// `\\` stand for `"` and `\`, and the three kinds of comments. Subgraphs, ports and HTML
+/// labels are not.
+///
+/// # Arguments
+///
+/// * `input` - The text to parse
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::parse_dot;
+/// fn main() {
+///     let input = r#"digraph roads {
+///         rankdir = LR;
+///         node [shape=box];
+///         Paris -> Lyon -> Marseille [km=465];
+///         "Le Havre" -> Paris [km=197, color=red];
+///     }"#;
+///     let dot = parse_dot(input).unwrap();
+///     assert_eq!(dot.name.as_deref(), Some("roads"));
+///     assert_eq!(dot.attributes["rankdir"], "LR");
+///     assert_eq!(dot.graph.node_count(), 4);
+///     assert_eq!(dot.graph.node(3).id, "Le Havre");
+///     assert_eq!(dot.graph.node(3).attributes["shape"], "box");
+///     assert_eq!(dot.graph.edge(1).weight["km"], "465");
+///
+///     let error = parse_dot("graph {\n  a -- b\n  b -> c\n}").unwrap_err();
+///     assert_eq!(error.to_string(), "line 3: `->` in an undirected graph, use `--`");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<DotGraph, ParseError>` - The graph with its attributes, or the first problem found
+///
+pub fn parse_dot(input: &str) -> Result<DotGraph, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        position: 0,
+        last_line: input.lines().count().max(1),
+    };
+    if parser.peek_keyword("strict") {
+        parser.advance();
+    }
+    let directed = if parser.peek_keyword("digraph") {
+        true
+    } else if parser.peek_keyword("graph") {
+        false
+    } else {
+        return Err(parser.error("`graph` or `digraph`"));
+    };
+    parser.advance();
+    let name = match parser.peek() {
+        Some(Token::Id(_)) => Some(parser.expect_id("a graph name")?),
+        _ => None,
+    };
+    parser.expect("{")?;
+
+    let (operator, other) = if directed { ("->", "--") } else { ("--", "->") };
+    let mut dot = DotGraph {
+        name,
+        attributes: Attributes::new(),
+        graph: Graph::new(directed),
+    };
+    let mut indices = HashMap::new();
+    let mut node_defaults = Attributes::new();
+    let mut edge_defaults = Attributes::new();
+    loop {
+        let keyword_line = parser.line();
+        if parser.peek().is_none() {
+            return Err(parser.error("`}`"));
+        } else if parser.peek_is("}") {
+            parser.advance();
+            break;
+        } else if parser.peek_is(";") {
+            parser.advance();
+            continue;
+        } else if parser.peek_is("{") || parser.peek_keyword("subgraph") {
+            return Err(ParseError::new(keyword_line, "subgraphs are not supported"));
+        }
+
+        let id = parser.expect_id("a statement")?;
+        if parser.peek_is("[") {
+            let defaults = match id.to_ascii_lowercase().as_str() {
+                "graph" => Some(&mut dot.attributes),
+                "node" => Some(&mut node_defaults),
+                "edge" => Some(&mut edge_defaults),
+                _ => None,
+            };
+            if let Some(defaults) = defaults {
+                parser.attributes(defaults)?;
+                continue;
+            }
+        }
+        if parser.peek_is("=") {
+            parser.advance();
+            let value = parser.expect_id("an attribute value")?;
+            dot.attributes.insert(id, value);
+        } else if parser.peek_is(operator) || parser.peek_is(other) {
+            let mut chain = vec![id];
+            while parser.peek_is(operator) || parser.peek_is(other) {
+                if parser.peek_is(other) {
+                    return Err(ParseError::new(
+                        parser.line(),
+                        format!(
+                            "`{}` in {} graph, use `{}`",
+                            other,
+                            if directed {
+                                "a directed"
+                            } else {
+                                "an undirected"
+                            },
+                            operator
+                        ),
+                    ));
+                }
+                parser.advance();
+                chain.push(parser.expect_id("a node")?);
+            }
+            let mut attributes = edge_defaults.clone();
+            parser.attributes(&mut attributes)?;
+            let nodes: Vec<usize> = chain
+                .into_iter()
+                .map(|id| node_index(&mut dot.graph, &mut indices, &node_defaults, id))
+                .collect();
+            for pair in nodes.windows(2) {
+                dot.graph.add_edge(pair[0], pair[1], attributes.clone());
+            }
+        } else {
+            let node = node_index(&mut dot.graph, &mut indices, &node_defaults, id);
+            parser.attributes(&mut dot.graph.node_mut(node).attributes)?;
+        }
+    }
+    if parser.peek().is_some() {
+        return Err(parser.error("the end of the input after the closing brace"));
+    }
+    Ok(dot)
+}
+
+/// Returns `id` as it must be written in DOT: unchanged if it is a plain identifier or a
+/// number, quoted with `\` and `"` escaped otherwise.
+fn quote(id: &str) -> String {
+    let mut chars = id.chars();
+    let plain = chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+    let digits = id.strip_prefix('-').unwrap_or(id);
+    let numeral = digits.chars().any(|c| c.is_ascii_digit())
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && digits.matches('.').count() <= 1;
+    let keyword = ["node", "edge", "graph", "digraph", "subgraph", "strict"]
+        .iter()
+        .any(|keyword| id.eq_ignore_ascii_case(keyword));
+    if (plain || numeral) && !keyword {
+        id.to_string()
+    } else {
+        format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Returns ` [key=value, ...]`, or nothing if there are no attributes.
+fn attribute_list(attributes: &Attributes) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = attributes
+        .iter()
+        .map(|(key, value)| format!("{}={}", quote(key), quote(value)))
+        .collect();
+    format!(" [{}]", pairs.join(", "))
+}
+
+/// Write a graph in the Graphviz DOT language: the graph attributes, every node with its
+/// attributes, then every edge with its attributes, one statement per line.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::{write_dot, Attributes, DotGraph, DotNode};
+/// use rust_algo::graph::Graph;
+/// fn main() {
+///     let roads = Graph::from_edges(false, 2, vec![(0, 1, 465)]);
+///     let names = ["Paris", "Saint-Étienne"];
+///     let graph = roads.map(
+///         |node, _| DotNode { id: names[node].to_string(), attributes: Attributes::new() },
+///         |_, edge| Attributes::from([("km".to_string(), edge.weight.to_string())]),
+///     );
+///     let dot = DotGraph { name: None, attributes: Attributes::new(), graph };
+///     assert_eq!(write_dot(&dot), "graph {\n    Paris;\n    \"Saint-Étienne\";\n    Paris -- \"Saint-Étienne\" [km=465];\n}\n");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `String` - The text, which `parse_dot` reads back
+///
+pub fn write_dot(dot: &DotGraph) -> String {
+    let graph = &dot.graph;
+    let mut output = String::new();
+    write!(
+        output,
+        "{}",
+        if graph.is_directed() {
+            "digraph"
+        } else {
+            "graph"
+        }
+    )
+    .unwrap();
+    if let Some(name) = &dot.name {
+        write!(output, " {}", quote(name)).unwrap();
+    }
+    output.push_str(" {\n");
+    for (key, value) in &dot.attributes {
+        writeln!(output, "    {}={};", quote(key), quote(value)).unwrap();
+    }
+    for node in graph.nodes() {
+        writeln!(
+            output,
+            "    {}{};",
+            quote(&node.id),
+            attribute_list(&node.attributes)
+        )
+        .unwrap();
+    }
+    let operator = if graph.is_directed() { "->" } else { "--" };
+    for edge in graph.edges() {
+        writeln!(
+            output,
+            "    {} {} {}{};",
+            quote(&graph.node(edge.source).id),
+            operator,
+            quote(&graph.node(edge.target).id),
+            attribute_list(&edge.weight)
+        )
+        .unwrap();
+    }
+    output.push_str("}\n");
+    output
+}
+
+#[cfg(test)]
+mod dot_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = r#"
+            /* a graph with
+               everything */
+            strict digraph "two words" {
+                label="quote \" inside";   // a graph attribute
+                edge [color=blue]
+                a [shape=circle] b
+                a -> b -> -1.5 [weight=2]
+                # a preprocessor line
+                node [style=filled]
+                "multi
+line" -> a
+                edge [color=red]
+                b -> graph_
+            }
+        "#;
+        let dot = parse_dot(input).unwrap();
+        assert_eq!(dot.name.as_deref(), Some("two words"));
+        assert_eq!(dot.attributes["label"], "quote \" inside");
+        let ids: Vec<&str> = dot
+            .graph
+            .nodes()
+            .iter()
+            .map(|node| node.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "-1.5", "multi\nline", "graph_"]);
+        assert!(dot.graph.node(2).attributes.is_empty());
+        assert_eq!(dot.graph.node(3).attributes["style"], "filled");
+        assert_eq!(dot.graph.edge(0).weight["color"], "blue");
+        assert_eq!(dot.graph.edge(1).weight["weight"], "2");
+        assert_eq!(dot.graph.edge(3).weight["color"], "red");
+
+        let again = parse_dot(&write_dot(&dot)).unwrap();
+        assert_eq!(again.name, dot.name);
+        assert_eq!(again.attributes, dot.attributes);
+        assert_eq!(again.graph.nodes(), dot.graph.nodes());
+        assert_eq!(again.graph.edges(), dot.graph.edges());
+    }
+
+    #[test]
+    fn escapes_round_trip() {
+        let ids = ["a\\", "x\\\ny", "\"", "\\\"", "\\\\n", "two\nlines\n", "\\"];
+        let graph = Graph::from_edges(true, ids.len(), vec![(0, 1, ()), (5, 6, ())]).map(
+            |node, _| DotNode {
+                id: ids[node].to_string(),
+                attributes: Attributes::from([(
+                    "label".to_string(),
+                    ids[ids.len() - 1 - node].to_string(),
+                )]),
+            },
+            |_, _| Attributes::from([("note".to_string(), "ends with \\".to_string())]),
+        );
+        let dot = DotGraph {
+            name: Some("back\\slash".to_string()),
+            attributes: Attributes::from([("label".to_string(), "C:\\".to_string())]),
+            graph,
+        };
+        let again = parse_dot(&write_dot(&dot)).unwrap();
+        assert_eq!(again.name, dot.name);
+        assert_eq!(again.attributes, dot.attributes);
+        assert_eq!(again.graph.nodes(), dot.graph.nodes());
+        assert_eq!(again.graph.edges(), dot.graph.edges());
+    }
+
+    #[test]
+    fn errors() {
+        let cases = [
+            (
+                "graph {\n a -- b\n",
+                2,
+                "expected `}`, found the end of the input",
+            ),
+            ("digraph {\n a -> \"b\n\n}", 2, "unterminated string"),
+            (
+                "digraph {\n  subgraph cluster { a }\n}",
+                2,
+                "subgraphs are not supported",
+            ),
+            ("graph {\n a [color]\n}", 2, "expected `=`, found `]`"),
+            ("graph {\n a % b\n}", 2, "unexpected character `%`"),
+            ("tree { }", 1, "expected `graph` or `digraph`, found `tree`"),
+            (
+                "graph { }\n}",
+                2,
+                "expected the end of the input after the closing brace, found `}`",
+            ),
+        ];
+        for (input, line, message) in cases {
+            assert_eq!(
+                parse_dot(input).unwrap_err(),
+                ParseError::new(line, message)
+            );
+        }
+    }
+}
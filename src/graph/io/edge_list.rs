@@ -0,0 +1,124 @@
+use super::{content_lines, parse_token, ParseError};
+use crate::graph::Graph;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// Parse an edge list: one `source target [weight]` line per edge, nodes numbered from 0.
+///
+/// A line holding a single node declares it without any edge, so isolated nodes are kept.
+/// A missing weight is `E::default()`. Everything after a `#` is a comment.
+///
+/// # Arguments
+///
+/// * `input` - The text to parse
+/// * `directed` - Whether the graph built is directed
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::parse_edge_list;
+/// fn main() {
+///     let graph = parse_edge_list::<f64>("# a triangle\n0 1 2.5\n1 2\n2 0 1\n3\n", false).unwrap();
+///     assert_eq!(graph.node_count(), 4);
+///     assert_eq!(graph.edge(1).weight, 0.0);
+///
+///     let error = parse_edge_list::<f64>("0 1\n1 x\n", true).unwrap_err();
+///     assert_eq!(error.line, 2);
+///     assert_eq!(error.to_string(), "line 2: invalid node `x`");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<Graph<(), E>, ParseError>` - The graph, with as many nodes as the largest index plus one, or the first problem found
+///
+pub fn parse_edge_list<E: FromStr + Default>(
+    input: &str,
+    directed: bool,
+) -> Result<Graph<(), E>, ParseError> {
+    let mut nodes = 0;
+    let mut edges = Vec::new();
+    for (line, content) in content_lines(input, "#") {
+        let fields: Vec<&str> = content.split_whitespace().collect();
+        if fields.len() > 3 {
+            return Err(ParseError::new(
+                line,
+                format!(
+                    "expected `source target [weight]`, found {} fields",
+                    fields.len()
+                ),
+            ));
+        }
+        let mut ends = Vec::with_capacity(2);
+        for field in fields.iter().take(2) {
+            let node: usize = parse_token(field, line, "node")?;
+            nodes = nodes.max(node + 1);
+            ends.push(node);
+        }
+        if let [source, target] = ends[..] {
+            let weight = match fields.get(2) {
+                Some(field) => parse_token(field, line, "weight")?,
+                None => E::default(),
+            };
+            edges.push((source, target, weight));
+        }
+    }
+    Ok(Graph::from_edges(directed, nodes, edges))
+}
+
+/// Write a graph as an edge list, one `source target weight` line per edge in index order,
+/// then one line for each node no edge touches.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::write_edge_list;
+/// use rust_algo::graph::Graph;
+/// fn main() {
+///     let graph = Graph::from_edges(true, 4, vec![(0, 1, 5), (2, 0, 7)]);
+///     assert_eq!(write_edge_list(&graph), "0 1 5\n2 0 7\n3\n");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `String` - The edge list, which `parse_edge_list` reads back into the same graph
+///
+pub fn write_edge_list<N, E: Display>(graph: &Graph<N, E>) -> String {
+    let mut output = String::new();
+    let mut touched = vec![false; graph.node_count()];
+    for edge in graph.edges() {
+        touched[edge.source] = true;
+        touched[edge.target] = true;
+        writeln!(output, "{} {} {}", edge.source, edge.target, edge.weight).unwrap();
+    }
+    for (node, _) in touched.iter().enumerate().filter(|(_, &touched)| !touched) {
+        writeln!(output, "{}", node).unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod edge_list_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let graph = Graph::from_edges(false, 6, vec![(0, 1, -1.5), (4, 2, 3.0), (2, 2, 0.0)]);
+        let parsed: Graph<(), f64> = parse_edge_list(&write_edge_list(&graph), false).unwrap();
+        assert_eq!(parsed.node_count(), 6);
+        assert_eq!(parsed.edges(), graph.edges());
+    }
+
+    #[test]
+    fn errors() {
+        let error = parse_edge_list::<i32>("0 1 1\n\n# nothing\n1 2 3 4\n", true).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(4, "expected `source target [weight]`, found 4 fields")
+        );
+        let error = parse_edge_list::<i32>("0 1 1\n1 2 heavy\n", true).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "invalid weight `heavy`");
+    }
+}
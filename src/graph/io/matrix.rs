@@ -0,0 +1,179 @@
+use super::{content_lines, parse_token, ParseError};
+use crate::graph::Graph;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+/// Parse an adjacency matrix: one line of whitespace separated weights per node.
+///
+/// The entry in row `i` and column `j` is the weight of the edge from `i` to `j`; an entry
+/// equal to `E::default()` means there is no edge. The matrix of an undirected graph must be
+/// symmetric, and its edges are read from the upper triangle. Everything after a `#` is a
+/// comment.
+///
+/// # Arguments
+///
+/// * `input` - The text to parse
+/// * `directed` - Whether the graph built is directed
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::parse_adjacency_matrix;
+/// fn main() {
+///     let graph = parse_adjacency_matrix::<u32>("0 3 0\n3 0 1\n0 1 0\n", false).unwrap();
+///     assert_eq!(graph.edge_count(), 2);
+///     assert!(graph.has_edge(2, 1));
+///
+///     let error = parse_adjacency_matrix::<u32>("0 3 0\n0 0 1\n0 1 0\n", false).unwrap_err();
+///     assert_eq!(error.to_string(), "line 2: entry (1, 0) differs from entry (0, 1) in an undirected graph");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<Graph<(), E>, ParseError>` - The graph, with one node per row, or the first problem found
+///
+pub fn parse_adjacency_matrix<E: FromStr + Default + PartialEq>(
+    input: &str,
+    directed: bool,
+) -> Result<Graph<(), E>, ParseError> {
+    let mut rows: Vec<Vec<E>> = Vec::new();
+    let mut last_line = 1;
+    for (line, content) in content_lines(input, "#") {
+        last_line = line;
+        let row = content
+            .split_whitespace()
+            .map(|field| parse_token(field, line, "weight"))
+            .collect::<Result<Vec<E>, _>>()?;
+        let width = rows.first().map_or(row.len(), Vec::len);
+        if row.len() != width {
+            return Err(ParseError::new(
+                line,
+                format!("expected {} entries, found {}", width, row.len()),
+            ));
+        }
+        if rows.len() == width {
+            return Err(ParseError::new(
+                line,
+                format!("expected {} rows, found more", width),
+            ));
+        }
+        if !directed {
+            let i = rows.len();
+            if let Some(j) = (0..i).find(|&j| row[j] != rows[j][i]) {
+                return Err(ParseError::new(
+                    line,
+                    format!(
+                        "entry ({}, {}) differs from entry ({}, {}) in an undirected graph",
+                        i, j, j, i
+                    ),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    let n = rows.len();
+    if rows.first().is_some_and(|row| row.len() != n) {
+        return Err(ParseError::new(
+            last_line,
+            format!("expected {} rows, found {}", rows[0].len(), n),
+        ));
+    }
+    let mut graph = Graph::new(directed);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for (i, row) in rows.into_iter().enumerate() {
+        for (j, weight) in row.into_iter().enumerate() {
+            if (directed || j >= i) && weight != E::default() {
+                graph.add_edge(i, j, weight);
+            }
+        }
+    }
+    Ok(graph)
+}
+
+/// Write a graph as an adjacency matrix, one line per node, the missing edges written as
+/// `E::default()`.
+///
+/// An edge whose weight is `E::default()` cannot be told from a missing one, and a pair of
+/// nodes can hold a single edge.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::io::write_adjacency_matrix;
+/// use rust_algo::graph::Graph;
+/// fn main() {
+///     let graph = Graph::from_edges(false, 3, vec![(0, 1, 3), (2, 1, 1)]);
+///     assert_eq!(write_adjacency_matrix(&graph), "0 3 0\n3 0 1\n0 1 0\n");
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `String` - The matrix, which `parse_adjacency_matrix` reads back
+///
+pub fn write_adjacency_matrix<N, E: Display + Default>(graph: &Graph<N, E>) -> String {
+    let n = graph.node_count();
+    let mut matrix: Vec<Vec<Option<&E>>> = vec![vec![None; n]; n];
+    for edge in graph.edges() {
+        let mut cells = vec![(edge.source, edge.target)];
+        if !graph.is_directed() && edge.source != edge.target {
+            cells.push((edge.target, edge.source));
+        }
+        for (i, j) in cells {
+            assert!(
+                matrix[i][j].is_none(),
+                "an adjacency matrix cannot hold the parallel edges between {} and {}",
+                edge.source,
+                edge.target
+            );
+            matrix[i][j] = Some(&edge.weight);
+        }
+    }
+    let zero = E::default().to_string();
+    let mut output = String::new();
+    for row in matrix {
+        let cells: Vec<String> = row
+            .into_iter()
+            .map(|cell| cell.map_or_else(|| zero.clone(), E::to_string))
+            .collect();
+        writeln!(output, "{}", cells.join(" ")).unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod matrix_tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for directed in [true, false] {
+            let graph = Graph::from_edges(
+                directed,
+                4,
+                vec![(0, 1, 2), (1, 3, 5), (3, 3, 1), (2, 0, 4)],
+            );
+            let parsed: Graph<(), i64> =
+                parse_adjacency_matrix(&write_adjacency_matrix(&graph), directed).unwrap();
+            assert_eq!(parsed.node_count(), 4);
+            assert_eq!(parsed.edge_count(), 4);
+            for edge in graph.edges() {
+                let found = parsed.find_edge(edge.source, edge.target).unwrap();
+                assert_eq!(parsed.edge(found).weight, edge.weight);
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let error = parse_adjacency_matrix::<i32>("0 1\n# comment\n1 0 1\n", true).unwrap_err();
+        assert_eq!(error, ParseError::new(3, "expected 2 entries, found 3"));
+        let error = parse_adjacency_matrix::<i32>("0 1 0\n1 0 0\n", true).unwrap_err();
+        assert_eq!(error, ParseError::new(2, "expected 3 rows, found 2"));
+        let error = parse_adjacency_matrix::<i32>("0 1\n1 -\n", true).unwrap_err();
+        assert_eq!(error, ParseError::new(2, "invalid weight `-`"));
+    }
+}
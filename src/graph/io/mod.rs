@@ -0,0 +1,78 @@
+//! Reading and writing graphs in text formats.
+//!
+//! * [`parse_edge_list`] / [`write_edge_list`] - one `source target weight` line per edge
+//! * [`parse_adjacency_matrix`] / [`write_adjacency_matrix`] - one row of weights per node
+//! * [`parse_dimacs`] / [`write_dimacs`] - the DIMACS challenge formats, 1-based
+//! * [`parse_dot`] / [`write_dot`] - Graphviz DOT, with node and edge attributes
+//!
+//! Parsers work on the whole input and report the first problem as a [`ParseError`] carrying
+//! its line number. Writers return a `String` the matching parser reads back.
+
+pub mod dimacs;
+pub mod dot;
+pub mod edge_list;
+pub mod matrix;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[doc(inline)]
+pub use self::dimacs::{parse_dimacs, write_dimacs, Dimacs};
+#[doc(inline)]
+pub use self::dot::{parse_dot, write_dot, Attributes, DotGraph, DotNode};
+#[doc(inline)]
+pub use self::edge_list::{parse_edge_list, write_edge_list};
+#[doc(inline)]
+pub use self::matrix::{parse_adjacency_matrix, write_adjacency_matrix};
+
+/// The reason an input could not be parsed, and where.
+///
+/// # Attributes
+///
+/// * `line` - The line of the input the problem was found on, starting at 1
+/// * `message` - What is wrong
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create a ParseError for `line`, starting at 1.
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token`, found on `line`, as a `T` described as `what` in the error.
+fn parse_token<T: FromStr>(token: &str, line: usize, what: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, format!("invalid {} `{}`", what, token)))
+}
+
+/// The numbered lines of `input` with the comment starting at `comment` removed, skipping the
+/// lines left blank.
+fn content_lines<'a>(input: &'a str, comment: &'a str) -> impl Iterator<Item = (usize, &'a str)> {
+    input.lines().enumerate().filter_map(move |(index, line)| {
+        let content = match line.find(comment) {
+            Some(start) => &line[..start],
+            None => line,
+        }
+        .trim();
+        (!content.is_empty()).then_some((index + 1, content))
+    })
+}
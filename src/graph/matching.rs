@@ -0,0 +1,194 @@
+use super::{bipartition, Graph};
+use std::collections::VecDeque;
+
+/// A set of edges sharing no node.
+///
+/// # Attributes
+///
+/// * `mates` - For each node, the node it is matched with, None if it is free
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matching {
+    pub mates: Vec<Option<usize>>,
+}
+
+impl Matching {
+    /// Returns the number of matched pairs.
+    pub fn len(&self) -> usize {
+        self.mates.iter().filter(|mate| mate.is_some()).count() / 2
+    }
+
+    /// Returns true if no node is matched.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the matched pairs `(a, b)` with `a < b`, by increasing `a`.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        self.mates
+            .iter()
+            .enumerate()
+            .filter_map(|(node, &mate)| mate.filter(|&mate| node < mate).map(|mate| (node, mate)))
+            .collect()
+    }
+}
+
+/// Maximum matching of a bipartite graph with the Hopcroft-Karp algorithm.
+///
+/// The sides are found by `bipartition`, ignoring the direction of the edges. Each phase finds
+/// the length of the shortest augmenting paths by a breadth first search from the free nodes
+/// of one side, then augments along as many disjoint paths of that length as a depth first
+/// search finds. There are O(√V) phases, so O(E √V) in total.
+///
+/// # Arguments
+///
+/// * `graph` - A bipartite graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{hopcroft_karp, Graph};
+/// fn main() {
+///     // workers 0, 1, 2 and the jobs 3, 4, 5 they can do
+///     let graph = Graph::from_edges(false, 6, vec![(0, 3, ()), (0, 4, ()), (1, 3, ()), (2, 4, ()), (2, 5, ())]);
+///     let matching = hopcroft_karp(&graph).unwrap();
+///     assert_eq!(matching.len(), 3);
+///     assert_eq!(matching.pairs(), vec![(0, 4), (1, 3), (2, 5)]);
+///
+///     let triangle = Graph::from_edges(false, 3, vec![(0, 1, ()), (1, 2, ()), (2, 0, ())]);
+///     assert_eq!(hopcroft_karp(&triangle), None);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<Matching>` - A matching with as many pairs as possible, or None if the graph is not bipartite
+///
+pub fn hopcroft_karp<N, E>(graph: &Graph<N, E>) -> Option<Matching> {
+    let sides = bipartition(graph)?;
+    let n = graph.node_count();
+    let left: Vec<usize> = (0..n).filter(|&node| !sides[node]).collect();
+    let mut neighbors = vec![Vec::new(); n];
+    for edge in graph.edges() {
+        let (from, to) = if sides[edge.source] {
+            (edge.target, edge.source)
+        } else {
+            (edge.source, edge.target)
+        };
+        neighbors[from].push(to);
+    }
+
+    let mut mates: Vec<Option<usize>> = vec![None; n];
+    // for the left nodes, the number of matched edges on a shortest alternating path from a
+    // free left node, usize::MAX once they cannot lead to an augmenting path of this phase
+    let mut layers = vec![usize::MAX; n];
+    loop {
+        let mut queue = VecDeque::new();
+        for &node in &left {
+            if mates[node].is_none() {
+                layers[node] = 0;
+                queue.push_back(node);
+            } else {
+                layers[node] = usize::MAX;
+            }
+        }
+        let mut shortest = usize::MAX;
+        while let Some(node) = queue.pop_front() {
+            if layers[node] >= shortest {
+                continue;
+            }
+            for &right in &neighbors[node] {
+                match mates[right] {
+                    None => shortest = shortest.min(layers[node] + 1),
+                    Some(mate) if layers[mate] == usize::MAX => {
+                        layers[mate] = layers[node] + 1;
+                        queue.push_back(mate);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if shortest == usize::MAX {
+            return Some(Matching { mates });
+        }
+
+        let mut next = vec![0; n];
+        for &root in &left {
+            if mates[root].is_some() {
+                continue;
+            }
+            // the left nodes of the alternating path and the right nodes joining them
+            let mut path = vec![root];
+            let mut joins: Vec<usize> = Vec::new();
+            while let Some(&node) = path.last() {
+                let Some(&right) = neighbors[node].get(next[node]) else {
+                    layers[node] = usize::MAX;
+                    path.pop();
+                    joins.pop();
+                    continue;
+                };
+                next[node] += 1;
+                match mates[right] {
+                    None if layers[node] + 1 == shortest => {
+                        joins.push(right);
+                        for (&left, &right) in path.iter().zip(&joins) {
+                            mates[left] = Some(right);
+                            mates[right] = Some(left);
+                        }
+                        break;
+                    }
+                    Some(mate) if layers[mate] == layers[node] + 1 => {
+                        path.push(mate);
+                        joins.push(right);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod matching_tests {
+    use super::*;
+    use crate::graph::dinic;
+    use crate::math::Rng;
+
+    #[test]
+    fn matches_max_flow() {
+        let mut rng = Rng::new(239);
+        for _ in 0..100 {
+            let (left, right) = (rng.gen_range(1..15), rng.gen_range(1..15));
+            let edges: Vec<(usize, usize, ())> = (0..rng.gen_range(0..3 * (left + right)))
+                .map(|_| (rng.gen_range(0..left), left + rng.gen_range(0..right), ()))
+                .collect();
+            let graph = Graph::from_edges(false, left + right, edges.clone());
+            let matching = hopcroft_karp(&graph).unwrap();
+            for (a, b) in matching.pairs() {
+                assert!(graph.has_edge(a, b));
+                assert_eq!(matching.mates[b], Some(a));
+            }
+            // a unit capacity network from a source before the left side to a sink after the right
+            let (source, sink) = (left + right, left + right + 1);
+            let network = Graph::from_edges(
+                true,
+                left + right + 2,
+                edges
+                    .iter()
+                    .map(|&(a, b, ())| (a, b, 1))
+                    .chain((0..left).map(|a| (source, a, 1)))
+                    .chain((left..left + right).map(|b| (b, sink, 1))),
+            );
+            assert_eq!(matching.len(), dinic(&network, source, sink).value);
+        }
+    }
+
+    #[test]
+    fn perfect_matching_on_a_long_path() {
+        let n = 50_000;
+        let graph = Graph::from_edges(false, n, (1..n).rev().map(|node| (node - 1, node, ())));
+        let matching = hopcroft_karp(&graph).unwrap();
+        assert_eq!(matching.len(), n / 2);
+        assert!(matching.mates.iter().all(Option::is_some));
+    }
+}
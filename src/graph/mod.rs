@@ -0,0 +1,38 @@
+pub mod adjacency_list;
+pub mod components;
+pub mod flow;
+pub mod io;
+pub mod matching;
+pub mod shortest_path;
+pub mod spanning_tree;
+pub mod topological;
+pub mod traversal;
+
+use std::ops::Add;
+
+#[doc(inline)]
+pub use self::adjacency_list::{Edge, Graph};
+#[doc(inline)]
+pub use self::components::{
+    bipartition, connected_components, is_bipartite, kosaraju_scc, tarjan_scc,
+};
+#[doc(inline)]
+pub use self::flow::{dinic, edmonds_karp, Capacity, MaxFlow};
+#[doc(inline)]
+pub use self::matching::{hopcroft_karp, Matching};
+#[doc(inline)]
+pub use self::shortest_path::{
+    a_star, bellman_ford, dijkstra, floyd_warshall, zero_one_bfs, AllPairsShortestPaths,
+    ShortestPaths,
+};
+#[doc(inline)]
+pub use self::spanning_tree::{kruskal, prim, SpanningTree};
+#[doc(inline)]
+pub use self::topological::topological_sort;
+#[doc(inline)]
+pub use self::traversal::{bfs, dfs, reconstruct_path, Traversal};
+
+/// Edge weights the shortest path algorithms can add and compare, `W::default()` being zero.
+pub trait Weight: Copy + Default + PartialOrd + Add<Output = Self> {}
+
+impl<T: Copy + Default + PartialOrd + Add<Output = T>> Weight for T {}
@@ -0,0 +1,585 @@
+use super::{reconstruct_path, Graph, Weight};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// The shortest paths from a source node to every node.
+///
+/// # Attributes
+///
+/// * `source` - The node the paths start from
+/// * `distances` - For each node, the length of its shortest path, None if it is unreachable
+/// * `predecessors` - For each node, the node before it on its shortest path; None for the
+///   source and the unreachable nodes
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShortestPaths<W> {
+    pub source: usize,
+    pub distances: Vec<Option<W>>,
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl<W: Weight> ShortestPaths<W> {
+    fn new(node_count: usize, source: usize) -> Self {
+        let mut distances = vec![None; node_count];
+        distances[source] = Some(W::default());
+        Self {
+            source,
+            distances,
+            predecessors: vec![None; node_count],
+        }
+    }
+
+    /// Returns the length of the shortest path to `target`, None if it is unreachable.
+    pub fn distance(&self, target: usize) -> Option<W> {
+        self.distances[target]
+    }
+
+    /// Returns the nodes of the shortest path from the source to `target`, None if it is
+    /// unreachable.
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.distances[target]?;
+        reconstruct_path(&self.predecessors, self.source, target)
+    }
+
+    /// Lower the distance of `node` to `distance` through `from` if it is shorter.
+    fn relax(&mut self, from: usize, node: usize, distance: W) -> bool {
+        if self.distances[node].is_none_or(|current| distance < current) {
+            self.distances[node] = Some(distance);
+            self.predecessors[node] = Some(from);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The shortest paths between every pair of nodes.
+///
+/// # Attributes
+///
+/// * `distances` - `distances[source][target]` is the length of the shortest path, None if
+///   there is none
+/// * `predecessors` - `predecessors[source]` is the predecessor map of the paths from `source`
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllPairsShortestPaths<W> {
+    pub distances: Vec<Vec<Option<W>>>,
+    pub predecessors: Vec<Vec<Option<usize>>>,
+}
+
+impl<W: Weight> AllPairsShortestPaths<W> {
+    /// Returns the length of the shortest path from `source` to `target`, None if there is none.
+    pub fn distance(&self, source: usize, target: usize) -> Option<W> {
+        self.distances[source][target]
+    }
+
+    /// Returns the nodes of the shortest path from `source` to `target`, None if there is none.
+    pub fn path(&self, source: usize, target: usize) -> Option<Vec<usize>> {
+        self.distances[source][target]?;
+        reconstruct_path(&self.predecessors[source], source, target)
+    }
+}
+
+/// A node waiting in a priority queue, the smallest priority coming out of a `BinaryHeap` first.
+pub(super) struct Candidate<W> {
+    pub(super) priority: W,
+    pub(super) node: usize,
+}
+
+impl<W: PartialOrd> Ord for Candidate<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .partial_cmp(&self.priority)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<W: PartialOrd> PartialOrd for Candidate<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> PartialEq for Candidate<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd> Eq for Candidate<W> {}
+
+/// Shortest paths from `source` with Dijkstra's algorithm.
+///
+/// Nodes are settled by increasing distance from a binary heap; entries made stale by a
+/// shorter path are skipped when they come out. O((V + E) log V).
+///
+/// # Arguments
+///
+/// * `graph` - A graph whose edge weights are not negative
+/// * `source` - The node the paths start from
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{dijkstra, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(true, 4, vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)]);
+///     let paths = dijkstra(&graph, 0);
+///     assert_eq!(paths.distances, vec![Some(0), Some(3), Some(1), Some(4)]);
+///     assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `ShortestPaths<W>` - The distance and the predecessor of every node
+///
+pub fn dijkstra<N, W: Weight>(graph: &Graph<N, W>, source: usize) -> ShortestPaths<W> {
+    let mut paths = ShortestPaths::new(graph.node_count(), source);
+    let mut heap = BinaryHeap::new();
+    heap.push(Candidate {
+        priority: W::default(),
+        node: source,
+    });
+    while let Some(Candidate { priority, node }) = heap.pop() {
+        if paths.distances[node].is_some_and(|distance| priority > distance) {
+            continue;
+        }
+        for (neighbor, &weight) in graph.edges_from(node) {
+            assert!(
+                weight >= W::default(),
+                "dijkstra needs non negative weights"
+            );
+            if paths.relax(node, neighbor, priority + weight) {
+                heap.push(Candidate {
+                    priority: priority + weight,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// Shortest path from `source` to `target` with the A* algorithm.
+///
+/// Like `dijkstra`, but nodes are taken by their distance plus `heuristic(node)`, an estimate
+/// of the distance left to `target`, and the search stops once `target` comes out of the heap.
+/// The path found is a shortest one if the heuristic never overestimates; a node is searched
+/// again if a shorter path to it shows up later.
+///
+/// # Arguments
+///
+/// * `graph` - A graph whose edge weights are not negative
+/// * `source` - The node the path starts from
+/// * `target` - The node the path leads to
+/// * `heuristic` - Lower bound of the distance from a node to `target`
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{a_star, Graph};
+/// fn main() {
+///     // a 3 x 3 grid, every node carrying its coordinates
+///     let mut grid = Graph::undirected();
+///     for y in 0..3 {
+///         for x in 0..3 {
+///             grid.add_node((x, y));
+///         }
+///     }
+///     for node in 0..9 {
+///         if node % 3 < 2 {
+///             grid.add_edge(node, node + 1, 1);
+///         }
+///         if node < 6 {
+///             grid.add_edge(node, node + 3, 1);
+///         }
+///     }
+///     let (tx, ty) = (2, 2);
+///     let paths = a_star(&grid, 0, 8, |node| {
+///         let (x, y): (i32, i32) = *grid.node(node);
+///         (tx - x).abs() + (ty - y).abs()
+///     });
+///     assert_eq!(paths.distance(8), Some(4));
+///     assert_eq!(paths.path_to(8).unwrap().len(), 5);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `ShortestPaths<W>` - The distance and the predecessor of the nodes reached, which include `target` if it is reachable
+///
+pub fn a_star<N, W, H>(
+    graph: &Graph<N, W>,
+    source: usize,
+    target: usize,
+    heuristic: H,
+) -> ShortestPaths<W>
+where
+    W: Weight,
+    H: Fn(usize) -> W,
+{
+    let mut paths = ShortestPaths::new(graph.node_count(), source);
+    let mut heap = BinaryHeap::new();
+    heap.push(Candidate {
+        priority: heuristic(source),
+        node: source,
+    });
+    while let Some(Candidate { priority, node }) = heap.pop() {
+        let distance = paths.distances[node].unwrap();
+        if priority > distance + heuristic(node) {
+            continue;
+        }
+        if node == target {
+            break;
+        }
+        for (neighbor, &weight) in graph.edges_from(node) {
+            assert!(weight >= W::default(), "a_star needs non negative weights");
+            if paths.relax(node, neighbor, distance + weight) {
+                heap.push(Candidate {
+                    priority: distance + weight + heuristic(neighbor),
+                    node: neighbor,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// Shortest paths from `source` with the Bellman-Ford algorithm, allowing negative weights.
+///
+/// Every edge is relaxed in rounds until nothing changes. A shortest path has at most V - 1
+/// edges, so a change in round V means a cycle of negative weight is reachable from `source`,
+/// and walking the predecessors back V times lands on it. O(V E).
+///
+/// An undirected edge can be followed both ways, so a negative one is a negative cycle.
+///
+/// # Arguments
+///
+/// * `graph` - Any weighted graph
+/// * `source` - The node the paths start from
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{bellman_ford, Graph};
+/// fn main() {
+///     let mut graph = Graph::from_edges(true, 4, vec![(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2)]);
+///     let paths = bellman_ford(&graph, 0).unwrap();
+///     assert_eq!(paths.distances, vec![Some(0), Some(2), Some(5), Some(4)]);
+///
+///     // 2 -> 1 -> 3 -> 2 weighs -6
+///     graph.add_edge(3, 2, -5);
+///     let mut cycle = bellman_ford(&graph, 0).unwrap_err();
+///     cycle.sort();
+///     assert_eq!(cycle, vec![1, 2, 3]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<ShortestPaths<W>, Vec<usize>>` - The distance and the predecessor of every node, or the nodes of a negative cycle in the order of its edges
+///
+pub fn bellman_ford<N, W: Weight>(
+    graph: &Graph<N, W>,
+    source: usize,
+) -> Result<ShortestPaths<W>, Vec<usize>> {
+    let n = graph.node_count();
+    let mut paths = ShortestPaths::new(n, source);
+    for round in 0..n {
+        let mut last_relaxed = None;
+        for node in 0..n {
+            let Some(distance) = paths.distances[node] else {
+                continue;
+            };
+            for (neighbor, &weight) in graph.edges_from(node) {
+                if paths.relax(node, neighbor, distance + weight) {
+                    last_relaxed = Some(neighbor);
+                }
+            }
+        }
+        match last_relaxed {
+            None => break,
+            Some(node) if round == n - 1 => {
+                return Err(predecessor_cycle(&paths.predecessors, node))
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(paths)
+}
+
+/// Returns the cycle of a predecessor map reached by walking back from `node` once per node.
+fn predecessor_cycle(predecessors: &[Option<usize>], mut node: usize) -> Vec<usize> {
+    for _ in 0..predecessors.len() {
+        node = predecessors[node].unwrap();
+    }
+    let mut cycle = vec![node];
+    let mut previous = predecessors[node].unwrap();
+    while previous != node {
+        cycle.push(previous);
+        previous = predecessors[previous].unwrap();
+    }
+    cycle.reverse();
+    cycle
+}
+
+/// Shortest paths between every pair of nodes with the Floyd-Warshall algorithm, allowing
+/// negative weights.
+///
+/// The paths are improved by allowing the nodes `0..k` as intermediate steps, one more `k` at
+/// a time. O(V³) time and O(V²) memory.
+///
+/// # Arguments
+///
+/// * `graph` - Any weighted graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{floyd_warshall, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(true, 4, vec![(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)]);
+///     let paths = floyd_warshall(&graph).unwrap();
+///     assert_eq!(paths.distance(0, 3), Some(4));
+///     assert_eq!(paths.distance(3, 0), None);
+///     assert_eq!(paths.path(2, 3), Some(vec![2, 1, 3]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<AllPairsShortestPaths<W>, Vec<usize>>` - The shortest paths, or the nodes lying on a closed walk of negative weight
+///
+pub fn floyd_warshall<N, W: Weight>(
+    graph: &Graph<N, W>,
+) -> Result<AllPairsShortestPaths<W>, Vec<usize>> {
+    let n = graph.node_count();
+    let mut distances = vec![vec![None; n]; n];
+    let mut predecessors = vec![vec![None; n]; n];
+    for node in 0..n {
+        distances[node][node] = Some(W::default());
+        for (neighbor, &weight) in graph.edges_from(node) {
+            if distances[node][neighbor].is_none_or(|current| weight < current) {
+                distances[node][neighbor] = Some(weight);
+                predecessors[node][neighbor] = Some(node);
+            }
+        }
+    }
+    for k in 0..n {
+        for i in 0..n {
+            let Some(to_k) = distances[i][k] else {
+                continue;
+            };
+            for j in 0..n {
+                let Some(from_k) = distances[k][j] else {
+                    continue;
+                };
+                if distances[i][j].is_none_or(|current| to_k + from_k < current) {
+                    distances[i][j] = Some(to_k + from_k);
+                    predecessors[i][j] = predecessors[k][j];
+                }
+            }
+        }
+    }
+    let negative: Vec<usize> = (0..n)
+        .filter(|&node| distances[node][node].is_some_and(|distance| distance < W::default()))
+        .collect();
+    if negative.is_empty() {
+        Ok(AllPairsShortestPaths {
+            distances,
+            predecessors,
+        })
+    } else {
+        Err(negative)
+    }
+}
+
+/// Shortest paths from `source` in a graph whose edges weigh 0 or 1.
+///
+/// A breadth first search with a double ended queue: the nodes reached by an edge of weight 0
+/// go to the front, the others to the back, so they still come out by increasing distance.
+/// O(V + E).
+///
+/// # Arguments
+///
+/// * `graph` - A graph whose edge weights are 0 or 1, as any unsigned integer type or `bool`
+/// * `source` - The node the paths start from
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{zero_one_bfs, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(true, 4, vec![(0, 1, 1u8), (0, 2, 0), (2, 1, 0), (1, 3, 1)]);
+///     let paths = zero_one_bfs(&graph, 0);
+///     assert_eq!(paths.distances, vec![Some(0), Some(0), Some(0), Some(1)]);
+///     assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `ShortestPaths<u64>` - The distance and the predecessor of every node
+///
+pub fn zero_one_bfs<N, W: Copy + Into<u64>>(
+    graph: &Graph<N, W>,
+    source: usize,
+) -> ShortestPaths<u64> {
+    let mut paths = ShortestPaths::new(graph.node_count(), source);
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[node].unwrap();
+        for (neighbor, &weight) in graph.edges_from(node) {
+            let weight: u64 = weight.into();
+            assert!(
+                weight <= 1,
+                "zero_one_bfs needs weights of 0 or 1, got {}",
+                weight
+            );
+            if paths.relax(node, neighbor, distance + weight) {
+                if weight == 0 {
+                    queue.push_front(neighbor);
+                } else {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod shortest_path_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    fn random_graph(rng: &mut Rng, directed: bool, min_weight: i64) -> Graph<(), i64> {
+        let n = rng.gen_range(1..30);
+        let edges: Vec<(usize, usize, i64)> = (0..rng.gen_range(0..4 * n))
+            .map(|_| {
+                let weight = min_weight + rng.gen_range(0..10) as i64;
+                (rng.gen_range(0..n), rng.gen_range(0..n), weight)
+            })
+            .collect();
+        Graph::from_edges(directed, n, edges)
+    }
+
+    fn path_weight(graph: &Graph<(), i64>, path: &[usize]) -> i64 {
+        path.windows(2)
+            .map(|step| {
+                graph
+                    .edges_from(step[0])
+                    .filter(|&(neighbor, _)| neighbor == step[1])
+                    .map(|(_, &weight)| weight)
+                    .min()
+                    .unwrap()
+            })
+            .sum()
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        let mut rng = Rng::new(38);
+        for round in 0..60 {
+            let graph = random_graph(&mut rng, round % 2 == 0, 0);
+            let all_pairs = floyd_warshall(&graph).unwrap();
+            for source in 0..graph.node_count() {
+                let expected = dijkstra(&graph, source);
+                assert_eq!(
+                    bellman_ford(&graph, source).unwrap().distances,
+                    expected.distances
+                );
+                assert_eq!(all_pairs.distances[source], expected.distances);
+                for target in 0..graph.node_count() {
+                    let path = expected.path_to(target);
+                    assert_eq!(path.is_some(), expected.distance(target).is_some());
+                    if let Some(path) = path {
+                        assert_eq!(Some(path_weight(&graph, &path)), expected.distance(target));
+                        let other = all_pairs.path(source, target).unwrap();
+                        assert_eq!(Some(path_weight(&graph, &other)), expected.distance(target));
+                    }
+                    let searched = a_star(&graph, source, target, |_| 0);
+                    assert_eq!(searched.distance(target), expected.distance(target));
+                }
+                let unit = Graph::from_edges(
+                    graph.is_directed(),
+                    graph.node_count(),
+                    graph
+                        .edges()
+                        .iter()
+                        .map(|edge| (edge.source, edge.target, edge.weight % 2 == 1)),
+                );
+                let expected = dijkstra(
+                    &Graph::from_edges(
+                        unit.is_directed(),
+                        unit.node_count(),
+                        unit.edges()
+                            .iter()
+                            .map(|edge| (edge.source, edge.target, edge.weight as u64)),
+                    ),
+                    source,
+                );
+                assert_eq!(zero_one_bfs(&unit, source).distances, expected.distances);
+            }
+        }
+    }
+
+    #[test]
+    fn negative_cycles() {
+        let mut rng = Rng::new(1038);
+        let mut found = 0;
+        for _ in 0..100 {
+            let graph = random_graph(&mut rng, true, -2);
+            let Err(cycle) = bellman_ford(&graph, 0) else {
+                continue;
+            };
+            found += 1;
+            let mut closed = cycle.clone();
+            closed.push(cycle[0]);
+            assert!(path_weight(&graph, &closed) < 0);
+            assert!(floyd_warshall(&graph).is_err());
+        }
+        assert!(found > 10);
+        // a negative undirected edge can be walked back and forth
+        let graph = Graph::from_edges(false, 3, vec![(0, 1, 2), (1, 2, -1)]);
+        let mut cycle = bellman_ford(&graph, 0).unwrap_err();
+        cycle.sort();
+        assert_eq!(cycle, vec![1, 2]);
+    }
+
+    #[test]
+    fn a_star_with_an_admissible_heuristic() {
+        // a grid with walls, the heuristic being the manhattan distance to the corner
+        let size = 12;
+        let mut rng = Rng::new(2038);
+        let mut grid = Graph::undirected();
+        for y in 0..size {
+            for x in 0..size {
+                grid.add_node((x as i64, y as i64));
+            }
+        }
+        for node in 0..size * size {
+            if node % size + 1 < size && rng.next_bool(0.8) {
+                grid.add_edge(node, node + 1, 1 + rng.gen_range(0..3) as i64);
+            }
+            if node + size < size * size && rng.next_bool(0.8) {
+                grid.add_edge(node, node + size, 1 + rng.gen_range(0..3) as i64);
+            }
+        }
+        let target = size * size - 1;
+        let heuristic = |node: usize| {
+            let (x, y) = *grid.node(node);
+            (size as i64 - 1 - x) + (size as i64 - 1 - y)
+        };
+        let searched = a_star(&grid, 0, target, heuristic);
+        assert_eq!(
+            searched.distance(target),
+            dijkstra(&grid, 0).distance(target)
+        );
+        let path = searched.path_to(target).unwrap();
+        assert_eq!(path.first(), Some(&0));
+        assert_eq!(path.last(), Some(&target));
+    }
+}
@@ -0,0 +1,197 @@
+use super::shortest_path::Candidate;
+use super::{Graph, Weight};
+use crate::collections::DisjointSet;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A minimum spanning forest: one spanning tree for each connected component.
+///
+/// # Attributes
+///
+/// * `edges` - The indices of the edges of the forest, in the order they were chosen
+/// * `weight` - The total weight of the edges
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanningTree<W> {
+    pub edges: Vec<usize>,
+    pub weight: W,
+}
+
+/// Minimum spanning forest with Kruskal's algorithm.
+///
+/// The edges are taken by increasing weight, and an edge is kept if its endpoints are still
+/// in different trees, which a `DisjointSet` tells. O(E log E).
+///
+/// # Arguments
+///
+/// * `graph` - An undirected weighted graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{kruskal, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(false, 4, vec![(0, 1, 1), (1, 2, 4), (0, 2, 3), (2, 3, 2), (1, 3, 5)]);
+///     let tree = kruskal(&graph);
+///     assert_eq!(tree.edges, vec![0, 3, 2]);
+///     assert_eq!(tree.weight, 6);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `SpanningTree<W>` - The edges of the forest, by increasing weight, and their total weight
+///
+pub fn kruskal<N, W: Weight>(graph: &Graph<N, W>) -> SpanningTree<W> {
+    assert!(
+        !graph.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let mut order: Vec<usize> = (0..graph.edge_count()).collect();
+    order.sort_by(|&a, &b| {
+        graph
+            .edge(a)
+            .weight
+            .partial_cmp(&graph.edge(b).weight)
+            .unwrap_or(Ordering::Equal)
+    });
+    let mut sets = DisjointSet::new(graph.node_count());
+    let mut tree = SpanningTree {
+        edges: Vec::new(),
+        weight: W::default(),
+    };
+    for index in order {
+        let edge = graph.edge(index);
+        if sets.union(edge.source, edge.target) {
+            tree.edges.push(index);
+            tree.weight = tree.weight + edge.weight;
+        }
+    }
+    tree
+}
+
+/// Minimum spanning forest with Prim's algorithm.
+///
+/// Each tree grows from its smallest node by the cheapest edge leaving it, taken from a
+/// binary heap. O(E log V).
+///
+/// # Arguments
+///
+/// * `graph` - An undirected weighted graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{prim, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(false, 4, vec![(0, 1, 1), (1, 2, 4), (0, 2, 3), (2, 3, 2), (1, 3, 5)]);
+///     let tree = prim(&graph);
+///     assert_eq!(tree.edges, vec![0, 2, 3]);
+///     assert_eq!(tree.weight, 6);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `SpanningTree<W>` - The edges of the forest, in the order the trees grew, and their total weight
+///
+pub fn prim<N, W: Weight>(graph: &Graph<N, W>) -> SpanningTree<W> {
+    assert!(
+        !graph.is_directed(),
+        "a spanning tree needs an undirected graph"
+    );
+    let n = graph.node_count();
+    let mut in_tree = vec![false; n];
+    // the cheapest edge known from the tree to each node
+    let mut cheapest: Vec<Option<(W, usize)>> = vec![None; n];
+    let mut tree = SpanningTree {
+        edges: Vec::new(),
+        weight: W::default(),
+    };
+    let mut heap = BinaryHeap::new();
+    for root in 0..n {
+        if in_tree[root] {
+            continue;
+        }
+        heap.push(Candidate {
+            priority: W::default(),
+            node: root,
+        });
+        while let Some(Candidate { priority, node }) = heap.pop() {
+            if in_tree[node] {
+                continue;
+            }
+            if let Some((weight, edge)) = cheapest[node] {
+                if priority > weight {
+                    continue;
+                }
+                tree.edges.push(edge);
+                tree.weight = tree.weight + weight;
+            }
+            in_tree[node] = true;
+            for &(neighbor, edge) in graph.adjacent(node) {
+                let weight = graph.edge(edge).weight;
+                if !in_tree[neighbor] && cheapest[neighbor].is_none_or(|(best, _)| weight < best) {
+                    cheapest[neighbor] = Some((weight, edge));
+                    heap.push(Candidate {
+                        priority: weight,
+                        node: neighbor,
+                    });
+                }
+            }
+        }
+    }
+    tree
+}
+
+#[cfg(test)]
+mod spanning_tree_tests {
+    use super::*;
+    use crate::graph::connected_components;
+    use crate::math::Rng;
+
+    #[test]
+    fn kruskal_and_prim_agree() {
+        let mut rng = Rng::new(39);
+        for _ in 0..50 {
+            let n = rng.gen_range(1..40);
+            let edges: Vec<(usize, usize, u32)> = (0..rng.gen_range(0..3 * n))
+                .map(|_| {
+                    (
+                        rng.gen_range(0..n),
+                        rng.gen_range(0..n),
+                        rng.gen_range(0..20) as u32,
+                    )
+                })
+                .collect();
+            let graph = Graph::from_edges(false, n, edges);
+            let forest = n - connected_components(&graph).len();
+            let by_kruskal = kruskal(&graph);
+            let by_prim = prim(&graph);
+            assert_eq!(by_kruskal.edges.len(), forest);
+            assert_eq!(by_prim.edges.len(), forest);
+            assert_eq!(by_kruskal.weight, by_prim.weight);
+            // the chosen edges form a forest spanning the same components
+            let tree = Graph::from_edges(
+                false,
+                n,
+                by_prim
+                    .edges
+                    .iter()
+                    .map(|&edge| (graph.edge(edge).source, graph.edge(edge).target, ())),
+            );
+            assert_eq!(connected_components(&tree), connected_components(&graph));
+        }
+    }
+
+    #[test]
+    fn float_weights() {
+        let graph = Graph::from_edges(
+            false,
+            3,
+            vec![(0, 1, 0.5), (1, 2, 0.25), (0, 2, 1.0), (2, 2, 0.0)],
+        );
+        assert_eq!(kruskal(&graph).weight, 0.75);
+        assert_eq!(prim(&graph).weight, 0.75);
+    }
+}
@@ -0,0 +1,125 @@
+use super::Graph;
+
+/// Order the nodes of a directed graph so every edge goes from a node to a later one.
+///
+/// Depth first search with an explicit stack: a node is finished once all the nodes it leads
+/// to are, and the reverse finishing order is topological. Meeting a node still on the stack
+/// means the graph has a cycle, which is returned instead. O(V + E).
+///
+/// # Arguments
+///
+/// * `graph` - A directed graph
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{topological_sort, Graph};
+/// fn main() {
+///     // socks before shoes, trousers before shoes, underwear before trousers
+///     let graph = Graph::from_edges(true, 4, vec![(0, 3, ()), (1, 3, ()), (2, 1, ())]);
+///     let order = topological_sort(&graph).unwrap();
+///     let position = |node| order.iter().position(|&n| n == node).unwrap();
+///     assert!(position(0) < position(3) && position(2) < position(1));
+///
+///     let cyclic = Graph::from_edges(true, 3, vec![(0, 1, ()), (1, 2, ()), (2, 1, ())]);
+///     assert_eq!(topological_sort(&cyclic), Err(vec![1, 2]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<Vec<usize>, Vec<usize>>` - The nodes in topological order, or the nodes of a cycle in the order of its edges
+///
+pub fn topological_sort<N, E>(graph: &Graph<N, E>) -> Result<Vec<usize>, Vec<usize>> {
+    assert!(
+        graph.is_directed(),
+        "a topological order needs a directed graph"
+    );
+    const NEW: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+    let mut state = vec![NEW; graph.node_count()];
+    let mut finished = Vec::with_capacity(graph.node_count());
+    for root in 0..graph.node_count() {
+        if state[root] != NEW {
+            continue;
+        }
+        state[root] = ON_STACK;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            match graph.adjacent(node).get(*next) {
+                Some(&(neighbor, _)) => {
+                    *next += 1;
+                    match state[neighbor] {
+                        NEW => {
+                            state[neighbor] = ON_STACK;
+                            stack.push((neighbor, 0));
+                        }
+                        ON_STACK => {
+                            // the stack from `neighbor` to `node` plus the edge back is a cycle
+                            let start = stack.iter().position(|&(n, _)| n == neighbor).unwrap();
+                            return Err(stack[start..].iter().map(|&(n, _)| n).collect());
+                        }
+                        _ => {}
+                    }
+                }
+                None => {
+                    state[node] = DONE;
+                    finished.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    finished.reverse();
+    Ok(finished)
+}
+
+#[cfg(test)]
+mod topological_tests {
+    use super::*;
+
+    #[test]
+    fn every_edge_goes_forward() {
+        let edges: Vec<(usize, usize, ())> = (0..50)
+            .flat_map(|i| [(i, (i * 7 + 3) % 50), (i, (i * 11 + 1) % 50)])
+            .filter(|&(a, b)| a < b)
+            .map(|(a, b)| (49 - a, 49 - b, ()))
+            .collect();
+        let graph = Graph::from_edges(true, 50, edges);
+        let order = topological_sort(&graph).unwrap();
+        let mut position = vec![0; 50];
+        for (index, &node) in order.iter().enumerate() {
+            position[node] = index;
+        }
+        assert_eq!(order.len(), 50);
+        assert!(graph
+            .edges()
+            .iter()
+            .all(|edge| position[edge.source] < position[edge.target]));
+    }
+
+    #[test]
+    fn reports_a_cycle() {
+        let graph = Graph::from_edges(
+            true,
+            6,
+            vec![
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 3, ()),
+                (3, 4, ()),
+                (4, 2, ()),
+                (5, 0, ()),
+            ],
+        );
+        let cycle = topological_sort(&graph).unwrap_err();
+        assert_eq!(cycle, vec![2, 3, 4]);
+        for (index, &node) in cycle.iter().enumerate() {
+            assert!(graph.has_edge(node, cycle[(index + 1) % cycle.len()]));
+        }
+        let self_loop = Graph::from_edges(true, 1, vec![(0, 0, ())]);
+        assert_eq!(topological_sort(&self_loop), Err(vec![0]));
+    }
+}
@@ -0,0 +1,213 @@
+use super::Graph;
+use std::collections::VecDeque;
+
+/// The result of a graph search from a source node.
+///
+/// # Attributes
+///
+/// * `source` - The node the search started from
+/// * `order` - The reached nodes, in the order they were discovered
+/// * `predecessors` - For each node, the node it was discovered from; None for the source
+///   and the unreached nodes
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Traversal {
+    pub source: usize,
+    pub order: Vec<usize>,
+    pub predecessors: Vec<Option<usize>>,
+}
+
+impl Traversal {
+    /// Returns true if the search reached `node`.
+    pub fn visited(&self, node: usize) -> bool {
+        node == self.source || self.predecessors[node].is_some()
+    }
+
+    /// Returns the path followed by the search from the source to `target`, or None if
+    /// `target` was not reached.
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        reconstruct_path(&self.predecessors, self.source, target)
+    }
+}
+
+/// Rebuild the path from `source` to `target` by following a predecessor map backwards.
+///
+/// # Arguments
+///
+/// * `predecessors` - For each node, the node before it on its path from `source`
+/// * `source` - The first node of the path
+/// * `target` - The last node of the path
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::reconstruct_path;
+/// fn main() {
+///     let predecessors = vec![None, Some(0), Some(1), None];
+///     assert_eq!(reconstruct_path(&predecessors, 0, 2), Some(vec![0, 1, 2]));
+///     assert_eq!(reconstruct_path(&predecessors, 0, 3), None);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<Vec<usize>>` - The nodes of the path, or None if following the map from `target` does not lead to `source`
+///
+pub fn reconstruct_path(
+    predecessors: &[Option<usize>],
+    source: usize,
+    target: usize,
+) -> Option<Vec<usize>> {
+    let mut path = vec![target];
+    let mut node = target;
+    while node != source {
+        node = predecessors[node]?;
+        path.push(node);
+        // a predecessor map built by a search has no cycle, but a hand made one might
+        if path.len() > predecessors.len() {
+            return None;
+        }
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Breadth first search from `source`.
+///
+/// Nodes are discovered by increasing number of edges from `source`, so the path to every
+/// node is a path with the fewest edges. O(V + E).
+///
+/// # Arguments
+///
+/// * `graph` - The graph to search
+/// * `source` - The node to start from
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{bfs, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(false, 5, vec![(0, 1, ()), (0, 2, ()), (1, 3, ()), (2, 3, ()), (3, 4, ())]);
+///     let search = bfs(&graph, 0);
+///     assert_eq!(search.order, vec![0, 1, 2, 3, 4]);
+///     assert_eq!(search.path_to(4), Some(vec![0, 1, 3, 4]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Traversal` - The discovery order and the predecessor of each node
+///
+pub fn bfs<N, E>(graph: &Graph<N, E>, source: usize) -> Traversal {
+    let mut visited = vec![false; graph.node_count()];
+    let mut predecessors = vec![None; graph.node_count()];
+    let mut order = Vec::new();
+    let mut queue = VecDeque::new();
+    visited[source] = true;
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for neighbor in graph.neighbors(node) {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                predecessors[neighbor] = Some(node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    Traversal {
+        source,
+        order,
+        predecessors,
+    }
+}
+
+/// Depth first search from `source`, with an explicit stack so deep graphs cannot overflow
+/// the call stack.
+///
+/// Nodes are discovered in the same order as the recursive algorithm visiting the
+/// neighbors in insertion order. O(V + E).
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::graph::{dfs, Graph};
+/// fn main() {
+///     let graph = Graph::from_edges(false, 5, vec![(0, 1, ()), (0, 2, ()), (1, 3, ()), (2, 3, ()), (3, 4, ())]);
+///     let search = dfs(&graph, 0);
+///     assert_eq!(search.order, vec![0, 1, 3, 2, 4]);
+///     assert_eq!(search.path_to(2), Some(vec![0, 1, 3, 2]));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Traversal` - The discovery order and the predecessor of each node
+///
+pub fn dfs<N, E>(graph: &Graph<N, E>, source: usize) -> Traversal {
+    let mut visited = vec![false; graph.node_count()];
+    let mut predecessors = vec![None; graph.node_count()];
+    let mut order = vec![source];
+    visited[source] = true;
+    // each frame is a node and the position of the next edge to follow in its adjacency list
+    let mut stack = vec![(source, 0)];
+    while let Some((node, next)) = stack.last_mut() {
+        let node = *node;
+        match graph.adjacent(node).get(*next) {
+            Some(&(neighbor, _)) => {
+                *next += 1;
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    predecessors[neighbor] = Some(node);
+                    order.push(neighbor);
+                    stack.push((neighbor, 0));
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    Traversal {
+        source,
+        order,
+        predecessors,
+    }
+}
+
+#[cfg(test)]
+mod traversal_tests {
+    use super::*;
+
+    #[test]
+    fn unreachable_nodes() {
+        let graph = Graph::from_edges(true, 4, vec![(0, 1, ()), (1, 2, ()), (3, 0, ())]);
+        for search in [bfs(&graph, 0), dfs(&graph, 0)] {
+            assert_eq!(search.order, vec![0, 1, 2]);
+            assert!(!search.visited(3));
+            assert_eq!(search.path_to(3), None);
+            assert_eq!(search.path_to(0), Some(vec![0]));
+        }
+    }
+
+    #[test]
+    fn deep_path() {
+        let n = 200_000;
+        let graph = Graph::from_edges(true, n, (1..n).map(|i| (i - 1, i, ())));
+        let search = dfs(&graph, 0);
+        assert_eq!(search.order.len(), n);
+        assert_eq!(search.path_to(n - 1).unwrap().len(), n);
+    }
+
+    #[test]
+    fn bfs_finds_fewest_edges() {
+        // the long way round 0 -> 1 -> 2 -> 3 is found first by dfs
+        let graph = Graph::from_edges(
+            true,
+            4,
+            vec![(0, 1, ()), (1, 2, ()), (2, 3, ()), (0, 3, ())],
+        );
+        assert_eq!(bfs(&graph, 0).path_to(3), Some(vec![0, 3]));
+        assert_eq!(dfs(&graph, 0).path_to(3), Some(vec![0, 1, 2, 3]));
+    }
+}
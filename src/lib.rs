@@ -1,3 +1,5 @@
 pub mod math;
 pub mod searching;
-pub mod collections;
\ No newline at end of file
+pub mod collections;
+pub mod graph;
+pub mod sorting;
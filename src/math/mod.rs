@@ -3,10 +3,13 @@ pub mod fibonaci;
 // #[doc(inline)]
 pub mod factorial;
 pub mod distance_from_levenshtein;
+pub mod random;
 
 #[doc(inline)]
 pub use self::fibonaci::fibonaci;
 #[doc(inline)]
 pub use self::factorial::factorial;
 #[doc(inline)]
-pub use self::distance_from_levenshtein::distance_from_levenshtein;
\ No newline at end of file
+pub use self::distance_from_levenshtein::distance_from_levenshtein;
+#[doc(inline)]
+pub use self::random::Rng;
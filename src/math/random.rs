@@ -0,0 +1,130 @@
+use std::ops::Range;
+
+/// Seedable pseudo random number generator (SplitMix64).
+///
+/// Not suitable for cryptography, but fast, good enough for randomized algorithms
+/// and fully reproducible: the same seed always gives the same sequence.
+///
+/// # Attributes
+///
+/// * `state` - Internal state, advanced by a constant on every draw
+///
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Make a generator from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Any value, including 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::math::Rng;
+    /// fn main() {
+    ///     let mut a = Rng::new(42);
+    ///     let mut b = Rng::new(42);
+    ///     assert_eq!(a.next_u64(), b.next_u64());
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Rng` - The generator that was created
+    ///
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next 64 random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a float uniformly distributed in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        // the 53 high bits fill the mantissa of a double exactly
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns true with probability `probability`.
+    pub fn next_bool(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// Returns an integer uniformly distributed in `range`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - A non empty range, e.g. `0..10`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::math::Rng;
+    /// fn main() {
+    ///     let mut rng = Rng::new(7);
+    ///     for _ in 0..100 {
+    ///         let x = rng.gen_range(5..10);
+    ///         assert!(5 <= x && x < 10);
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn gen_range(&mut self, range: Range<usize>) -> usize {
+        assert!(range.start < range.end, "cannot sample empty range");
+        let span = (range.end - range.start) as u64;
+        // rejection sampling removes the bias of a plain modulo
+        let zone = u64::MAX - u64::MAX % span;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return range.start + (value % span) as usize;
+            }
+        }
+    }
+
+    /// Shuffle `slice` uniformly in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_range(0..i + 1);
+            slice.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod random_tests {
+    use super::*;
+    #[test]
+    fn good() {
+        let mut rng = Rng::new(0);
+        let mut counts = [0usize; 10];
+        for _ in 0..10_000 {
+            counts[rng.gen_range(0..10)] += 1;
+        }
+        for count in counts {
+            assert!((800..1200).contains(&count));
+        }
+        let x = rng.next_f64();
+        assert!((0.0..1.0).contains(&x));
+
+        let mut values: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut values);
+        assert_ne!(values, (0..50).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..50).collect::<Vec<_>>());
+    }
+    #[test]
+    fn notgood() {
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+}
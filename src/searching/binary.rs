@@ -0,0 +1,448 @@
+use crate::sorting::RadixKey;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Returns the index of the first element of a sorted slice that is not smaller than `value`.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to search, in increasing order
+/// * `value` - The value to look for
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::lower_bound;
+/// fn main() {
+///     let values = [1, 3, 3, 3, 7, 9];
+///     assert_eq!(lower_bound(&values, &3), 1);
+///     assert_eq!(lower_bound(&values, &4), 4);
+///     assert_eq!(lower_bound(&values, &10), 6);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `usize` - The index, the length if every element is smaller
+///
+pub fn lower_bound<T: Ord>(slice: &[T], value: &T) -> usize {
+    lower_bound_by(slice, |element| element.cmp(value))
+}
+
+/// Returns the index of the first element of a sorted slice for which `compare`, which
+/// compares an element with the value looked for, is not `Less`.
+pub fn lower_bound_by<T, F>(slice: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(&slice[mid]) == Ordering::Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Returns the index of the first element of a slice sorted by the key `key` extracts whose
+/// key is not smaller than `value`.
+pub fn lower_bound_by_key<T, K: Ord, F>(slice: &[T], value: &K, mut key: F) -> usize
+where
+    F: FnMut(&T) -> K,
+{
+    lower_bound_by(slice, |element| key(element).cmp(value))
+}
+
+/// Returns the index of the first element of a sorted slice that is larger than `value`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::upper_bound;
+/// fn main() {
+///     let values = [1, 3, 3, 3, 7, 9];
+///     assert_eq!(upper_bound(&values, &3), 4);
+///     assert_eq!(upper_bound(&values, &0), 0);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `usize` - The index, the length if no element is larger
+///
+pub fn upper_bound<T: Ord>(slice: &[T], value: &T) -> usize {
+    upper_bound_by(slice, |element| element.cmp(value))
+}
+
+/// Returns the index of the first element of a sorted slice for which `compare`, which
+/// compares an element with the value looked for, is `Greater`.
+pub fn upper_bound_by<T, F>(slice: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(&slice[mid]) == Ordering::Greater {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+/// Returns the index of the first element of a slice sorted by the key `key` extracts whose
+/// key is larger than `value`.
+pub fn upper_bound_by_key<T, K: Ord, F>(slice: &[T], value: &K, mut key: F) -> usize
+where
+    F: FnMut(&T) -> K,
+{
+    upper_bound_by(slice, |element| key(element).cmp(value))
+}
+
+/// Returns the positions of the elements of a sorted slice equal to `value`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::equal_range;
+/// fn main() {
+///     let values = [1, 3, 3, 3, 7, 9];
+///     assert_eq!(equal_range(&values, &3), 1..4);
+///     assert_eq!(equal_range(&values, &5), 4..4);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Range<usize>` - From `lower_bound` to `upper_bound`, empty where `value` would be inserted if it is missing
+///
+pub fn equal_range<T: Ord>(slice: &[T], value: &T) -> Range<usize> {
+    equal_range_by(slice, |element| element.cmp(value))
+}
+
+/// Returns the positions of the elements of a sorted slice for which `compare`, which
+/// compares an element with the value looked for, is `Equal`.
+pub fn equal_range_by<T, F>(slice: &[T], mut compare: F) -> Range<usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let start = lower_bound_by(slice, &mut compare);
+    start..start + upper_bound_by(&slice[start..], compare)
+}
+
+/// Search a sorted slice for `value` with an exponential (galloping) search.
+///
+/// The bounds 1, 2, 4, 8... are tried from the front until one passes the value, then the
+/// last gap is searched by bisection. O(log i) for a value found at index i, which beats a
+/// binary search when the value is near the front or the length is unknown.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to search, in increasing order
+/// * `value` - The value to look for
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::exponential_search;
+/// fn main() {
+///     let values: Vec<u32> = (0..1_000_000).map(|i| 2 * i).collect();
+///     assert_eq!(exponential_search(&values, &10), Ok(5));
+///     assert_eq!(exponential_search(&values, &11), Err(6));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<usize, usize>` - `Ok` with the index of the first element equal to `value`, or `Err` with the index where it would be inserted
+///
+pub fn exponential_search<T: Ord>(slice: &[T], value: &T) -> Result<usize, usize> {
+    exponential_search_by(slice, |element| element.cmp(value))
+}
+
+/// Search a sorted slice with an exponential search, `compare` comparing an element with the
+/// value looked for.
+pub fn exponential_search_by<T, F>(slice: &[T], mut compare: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut bound = 1;
+    while bound <= slice.len() && compare(&slice[bound - 1]) == Ordering::Less {
+        bound *= 2;
+    }
+    // the first element not smaller is in slice[bound / 2..bound]
+    let start = bound / 2;
+    let end = bound.min(slice.len() + 1) - 1;
+    let index = start + lower_bound_by(&slice[start..end], &mut compare);
+    match slice.get(index).map(compare) {
+        Some(Ordering::Equal) => Ok(index),
+        _ => Err(index),
+    }
+}
+
+/// Search a sorted slice of integer-like keys for `value` with an interpolation search.
+///
+/// Like a person looking up a dictionary, the search probes where the value should be if the
+/// keys were evenly spread between the bounds, rather than in the middle. O(log log n)
+/// probes on uniformly spread keys, O(n) when they are very unevenly spread.
+///
+/// # Arguments
+///
+/// * `slice` - The keys to search, in increasing order
+/// * `value` - The key to look for
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::interpolation_search;
+/// fn main() {
+///     let values: Vec<u64> = (0..1000).map(|i| i * i).collect();
+///     assert_eq!(interpolation_search(&values, 250_000), Ok(500));
+///     assert_eq!(interpolation_search(&values, 250_001), Err(501));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Result<usize, usize>` - `Ok` with the index of the first key equal to `value`, or `Err` with the index where it would be inserted
+///
+pub fn interpolation_search<T: RadixKey>(slice: &[T], value: T) -> Result<usize, usize> {
+    let key = value.radix_key();
+    // the first key not smaller is in slice[low..=high]
+    let (mut low, mut high) = (0, slice.len());
+    while low < high {
+        let first = slice[low].radix_key();
+        let last = slice[high - 1].radix_key();
+        if key <= first {
+            high = low;
+        } else if key > last {
+            low = high;
+        } else {
+            // first < key <= last, so the probe is in low..high
+            let offset = (key - first) as u128 * (high - 1 - low) as u128 / (last - first) as u128;
+            let probe = low + offset as usize;
+            if slice[probe].radix_key() < key {
+                low = probe + 1;
+            } else {
+                high = probe;
+            }
+        }
+    }
+    match slice.get(low) {
+        Some(found) if found.radix_key() == key => Ok(low),
+        _ => Err(low),
+    }
+}
+
+/// A domain `partition_point` and `ternary_search_max` can bisect: integers, or floats down
+/// to adjacent values.
+pub trait Domain: Copy + PartialOrd {
+    /// Returns a value strictly between `low` and `high`, near the middle, or `None` if there
+    /// is none.
+    fn between(low: Self, high: Self) -> Option<Self>;
+}
+
+macro_rules! integer_domain {
+    ($($t:ty),*) => {
+        $(impl Domain for $t {
+            fn between(low: Self, high: Self) -> Option<Self> {
+                let (low, high) = (low as i128, high as i128);
+                (high - low >= 2).then(|| (low + (high - low) / 2) as Self)
+            }
+        })*
+    };
+}
+
+macro_rules! float_domain {
+    ($($t:ty),*) => {
+        $(impl Domain for $t {
+            fn between(low: Self, high: Self) -> Option<Self> {
+                let width = high - low;
+                let mid = if width.is_finite() {
+                    low + width / 2.0
+                } else {
+                    low / 2.0 + high / 2.0
+                };
+                (low < mid && mid < high).then_some(mid)
+            }
+        })*
+    };
+}
+
+integer_domain!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+float_domain!(f32, f64);
+
+/// Returns the first value of `low..high` for which a monotone predicate is false, by
+/// bisection.
+///
+/// The predicate must be true up to some point and false after it. Over floats the
+/// bisection goes on until the bounds are adjacent floats, so the answer is exact, within
+/// about 64 steps for `f64`. `high` itself is never tested.
+///
+/// # Arguments
+///
+/// * `low` - The first value of the domain
+/// * `high` - The end of the domain
+/// * `predicate` - True before the point searched, false from it
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::partition_point;
+/// fn main() {
+///     // the integer square root of 10^18, plus one
+///     assert_eq!(partition_point(0u64, 1 << 32, |x| x * x <= 1_000_000_000_000_000_000), 1_000_000_001);
+///     // the smallest float whose square is at least 2
+///     let root = partition_point(0.0, 2.0, |x: f64| x * x < 2.0);
+///     assert!(root * root >= 2.0);
+///     assert!(root.next_down() * root.next_down() < 2.0);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `T` - The first value for which `predicate` is false, `high` if it is true on the whole domain
+///
+pub fn partition_point<T: Domain, F>(low: T, high: T, mut predicate: F) -> T
+where
+    F: FnMut(T) -> bool,
+{
+    if low >= high || !predicate(low) {
+        return low;
+    }
+    // the predicate is true at low, and false at high unless high is the end
+    let (mut low, mut high) = (low, high);
+    while let Some(mid) = T::between(low, high) {
+        if predicate(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    high
+}
+
+/// Returns where a unimodal function reaches its maximum over `low..=high`, with a ternary
+/// search.
+///
+/// The function must increase strictly up to its maximum and decrease strictly after it.
+/// Two points split the interval, at a quarter and three quarters, and the part beyond the
+/// point with the smaller value cannot hold the maximum. O(log n) evaluations.
+///
+/// # Arguments
+///
+/// * `low` - The first value of the domain
+/// * `high` - The last value of the domain
+/// * `f` - The unimodal function
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::ternary_search_max;
+/// fn main() {
+///     assert_eq!(ternary_search_max(-100, 100, |x: i32| -(x - 17) * (x - 17)), 17);
+///     let peak = ternary_search_max(0.0, 3.0, |x: f64| x.sin());
+///     assert!((peak - std::f64::consts::FRAC_PI_2).abs() < 1e-7);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `T` - The argument of the maximum
+///
+pub fn ternary_search_max<T: Domain, V: PartialOrd, F>(low: T, high: T, mut f: F) -> T
+where
+    F: FnMut(T) -> V,
+{
+    assert!(low <= high, "the domain of a ternary search is empty");
+    let (mut low, mut high) = (low, high);
+    while let Some(mid) = T::between(low, high) {
+        // next to the bounds a quarter may be missing, and mid stands in for it
+        let first = T::between(low, mid).unwrap_or(mid);
+        let third = T::between(mid, high).unwrap_or(mid);
+        if first == third {
+            // only low, mid and high are left
+            return if f(mid) > f(low) && f(mid) >= f(high) {
+                mid
+            } else if f(high) > f(low) {
+                high
+            } else {
+                low
+            };
+        }
+        if f(first) < f(third) {
+            low = first;
+        } else {
+            high = third;
+        }
+    }
+    if f(high) > f(low) {
+        high
+    } else {
+        low
+    }
+}
+
+/// Returns where a unimodal function reaches its minimum over `low..=high`, with a ternary
+/// search.
+pub fn ternary_search_min<T: Domain, V: PartialOrd, F>(low: T, high: T, mut f: F) -> T
+where
+    F: FnMut(T) -> V,
+{
+    ternary_search_max(low, high, |x| std::cmp::Reverse(f(x)))
+}
+
+#[cfg(test)]
+mod binary_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    #[test]
+    fn bounds_against_std() {
+        let mut rng = Rng::new(47);
+        for _ in 0..200 {
+            let mut values: Vec<u64> = (0..rng.gen_range(0..100))
+                .map(|_| rng.next_u64() % 50)
+                .collect();
+            values.sort();
+            for value in 0..52 {
+                let lower = values.partition_point(|&v| v < value);
+                let upper = values.partition_point(|&v| v <= value);
+                assert_eq!(lower_bound(&values, &value), lower);
+                assert_eq!(upper_bound(&values, &value), upper);
+                assert_eq!(equal_range(&values, &value), lower..upper);
+                let expected = if lower < upper { Ok(lower) } else { Err(lower) };
+                assert_eq!(exponential_search(&values, &value), expected);
+                assert_eq!(interpolation_search(&values, value), expected);
+            }
+        }
+        let signed = [i64::MIN, -5, 0, 0, 3, i64::MAX];
+        assert_eq!(interpolation_search(&signed, 0), Ok(2));
+        assert_eq!(interpolation_search(&signed, i64::MAX), Ok(5));
+        assert_eq!(interpolation_search(&signed, 1), Err(4));
+    }
+
+    #[test]
+    fn domains() {
+        assert_eq!(partition_point(i8::MIN, i8::MAX, |x| x < 100), 100);
+        assert_eq!(partition_point(5, 5, |_| true), 5);
+        assert_eq!(partition_point(0usize, 10, |_| true), 10);
+        let cube_root = partition_point(0.0f32, 10.0, |x| x * x * x < 27.0);
+        assert_eq!(cube_root, 3.0);
+        assert_eq!(ternary_search_min(-1000i64, 1000, |x| (x - 3).abs()), 3);
+        assert_eq!(ternary_search_max(0u8, 255, |x| x), 255);
+        assert_eq!(ternary_search_max(4, 4, |x: i32| x), 4);
+        for high in 0..40i32 {
+            for peak in 0..=high {
+                assert_eq!(ternary_search_max(0, high, |x| -(x - peak).abs()), peak);
+            }
+        }
+        let lowest = ternary_search_min(-10.0, 10.0, |x: f64| (x + 2.5) * (x + 2.5));
+        assert!((lowest + 2.5).abs() < 1e-6);
+    }
+}
@@ -1,78 +1,351 @@
-use std::collections::HashMap;
-
-/// Create an array that will contain all the letters of word and her index from 1 to word.len()-1
+/// Create the table of shifts of `needle`: for each byte, the distance from its last
+/// occurrence in `needle`, the last byte excepted, to the end of `needle`.
 /// # Arguments
 ///
-/// * `word` - A slice of string containing the letters to put in the HashMap
+/// * `needle` - The bytes of the word we're looking for, not empty
 ///
 /// # Return
-/// 
-/// * <HashMap<&'static str, usize>
-/// 
-fn bad_char_table(word: &'static str) -> HashMap<char, usize> {
-    let mut table = HashMap::new();
-    let mut index = word.len()-1;
-
-    for lettre in word.chars() {
-        if index>0 {
-            table.insert(lettre, index);
-            index-= 1 ;
-        }
+///
+/// * `[usize; 256]` - The shift for each byte, `needle.len()` for the bytes not in `needle`
+///
+fn bad_char_table(needle: &[u8]) -> [usize; 256] {
+    let mut table = [needle.len(); 256];
+    for (index, &byte) in needle[..needle.len() - 1].iter().enumerate() {
+        table[byte as usize] = needle.len() - 1 - index;
     }
-    return table;
+    table
 }
 
-
 /// Return the index of the first occurrence of `needle` in `haystack` with Boyer Moore Horspool algorithm
 /// # Arguments
 ///
-/// * `needle` - A slice of string, that's the word we're looking for
+/// * `needle` - The bytes of the word we're looking for
 /// * `haystack` - The place where we go to look for `needle`
 ///
 /// # Examples
-/// 
+///
 /// ```
-/// 
-/// use rust_algo::searching::boyer_moore_horspool::boyer_moore_horspool;
-/// 
+/// use rust_algo::searching::boyer_moore_horspool;
+///
+/// fn main() {
+///     let worlds = b"Hello John, how are you?";
+///     assert_eq!(boyer_moore_horspool(b"are", worlds), Some(16));
+///     assert_eq!(boyer_moore_horspool(b"Mary", worlds), None);
+/// }
+/// ```
+///
+///  # Returns
+///
+/// * `Option<usize>` - The index of the first occurence of `needle`, or None if not found
+///
+pub fn boyer_moore_horspool(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    //make the shift table of the bytes of "needle"
+    find_with(needle, &bad_char_table(needle), haystack)
+}
+
+/// The search loop of `boyer_moore_horspool`, with the shift table of `needle`, not empty.
+fn find_with(needle: &[u8], table: &[usize; 256], haystack: &[u8]) -> Option<usize> {
+    let mut skip = 0; // main position
+    while haystack.len() - skip >= needle.len() {
+        let mut i = needle.len() - 1; //beacause we read backwards
+        while haystack[skip + i] == needle[i] {
+            if i == 0 {
+                //if we arrive to head of word, we found first occurence of needle
+                return Some(skip);
+            }
+            i -= 1;
+        }
+        //we go next position, by the shift of the byte under the end of the needle
+        skip += table[haystack[skip + needle.len() - 1] as usize];
+    }
+    None
+}
+
+/// Return the byte offset of the first occurrence of `needle` in `haystack` with Boyer Moore Horspool algorithm.
+///
+/// Both are valid UTF-8, so a match always starts and ends on char boundaries of `haystack`,
+/// as with `str::find`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::boyer_moore_horspool_str;
+///
 /// fn main() {
 ///     let worlds = String::from("Hello John, how are you?");
-///     let word = "are";
-///    println!("{}", boyer_moore_horspool(word, worlds));
+///     let word = String::from("John");
+///     assert_eq!(boyer_moore_horspool_str(&word, &worlds), Some(6));
+/// }
+/// ```
+///
+pub fn boyer_moore_horspool_str(needle: &str, haystack: &str) -> Option<usize> {
+    boyer_moore_horspool(needle.as_bytes(), haystack.as_bytes())
+}
+
+/// A Boyer Moore Horspool searcher: the shift tables of `needle` are built once, then any
+/// number of haystacks can be searched.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::Horspool;
+///
+/// fn main() {
+///     let searcher = Horspool::new(b"aba");
+///     assert_eq!(searcher.find(b"xxabababa"), Some(2));
+///     assert_eq!(searcher.rfind(b"xxabababa"), Some(6));
+///     assert_eq!(searcher.find_iter(b"xxabababa", false).collect::<Vec<_>>(), vec![2, 6]);
+///     assert_eq!(searcher.count(b"xxabababa", true), 3);
+///     assert_eq!(searcher.find(b"abba"), None);
 /// }
 /// ```
-/// 
-///  # Returns 
-/// 
-/// * `i64` - the index of the first occurence of `needle` or -1 if not found
-/// 
-pub fn boyer_moore_horspool(needle : &'static str, haystack: String ) -> i64 {
-    //make the index table of the letters of "needle"
+///
+#[derive(Clone, Debug)]
+pub struct Horspool {
+    needle: Vec<u8>,
+    // shifts looking forward, from the last occurrence of a byte to the end of the needle
+    forward: [usize; 256],
+    // shifts looking backward, from the first occurrence of a byte to the start of the needle
+    backward: [usize; 256],
+}
+
+impl Horspool {
+    /// Build the searcher of `needle`.
+    pub fn new(needle: &[u8]) -> Self {
+        let mut forward = [needle.len(); 256];
+        let mut backward = [needle.len(); 256];
+        if !needle.is_empty() {
+            forward = bad_char_table(needle);
+            for (index, &byte) in needle.iter().enumerate().skip(1).rev() {
+                backward[byte as usize] = index;
+            }
+        }
+        Horspool {
+            needle: needle.to_vec(),
+            forward,
+            backward,
+        }
+    }
+
+    /// Returns the needle searched for.
+    pub fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    /// Return the index of the first occurrence of the needle in `haystack`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The index, `Some(0)` for an empty needle, or None if not found
+    ///
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        if self.needle.is_empty() {
+            return Some(0);
+        }
+        find_with(&self.needle, &self.forward, haystack)
+    }
+
+    /// Return the index of the last occurrence of the needle in `haystack`.
+    ///
+    /// The window moves from the end of `haystack` to its start, compares the needle from
+    /// its first byte, and shifts by the byte under the start of the window.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The index, `Some(haystack.len())` for an empty needle, or None if not found
+    ///
+    pub fn rfind(&self, haystack: &[u8]) -> Option<usize> {
+        let needle = &self.needle[..];
+        let mut skip = haystack.len().checked_sub(needle.len())?;
+        loop {
+            if haystack[skip..skip + needle.len()] == *needle {
+                return Some(skip);
+            }
+            skip = skip.checked_sub(self.backward[haystack[skip] as usize])?;
+        }
+    }
+
+    /// Returns an iterator over the indexes of the occurrences of the needle in `haystack`,
+    /// in increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The place where we go to look for the needle
+    /// * `overlapping` - Whether an occurrence may start inside the previous one
+    ///
+    pub fn find_iter<'a, 'h>(&'a self, haystack: &'h [u8], overlapping: bool) -> Matches<'a, 'h> {
+        Matches {
+            searcher: self,
+            haystack,
+            position: 0,
+            overlapping,
+        }
+    }
+
+    /// Return the byte offset of the first occurrence of the needle in `haystack` that starts
+    /// and ends on char boundaries.
+    ///
+    /// A needle made from a `&str` only matches on char boundaries; other needles, such as
+    /// the tail of a multi-byte char, may match inside a char and these matches are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::searching::Horspool;
+    ///
+    /// fn main() {
+    ///     let text = "crème brûlée";
+    ///     assert_eq!(Horspool::new("û".as_bytes()).find_str(text), text.find('û'));
+    ///     assert_eq!(Horspool::new("lée".as_bytes()).find_str(text), Some(11));
+    ///     // the second byte of 'è' alone
+    ///     assert_eq!(Horspool::new(&[0xA8]).find(text.as_bytes()), Some(3));
+    ///     assert_eq!(Horspool::new(&[0xA8]).find_str(text), None);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The byte offset, `Some(0)` for an empty needle, or None if not found
+    ///
+    pub fn find_str(&self, haystack: &str) -> Option<usize> {
+        self.find_iter_str(haystack, true).next()
+    }
+
+    /// Return the byte offset of the last occurrence of the needle in `haystack` that starts
+    /// and ends on char boundaries, see [`Horspool::find_str`].
+    pub fn rfind_str(&self, haystack: &str) -> Option<usize> {
+        let bytes = haystack.as_bytes();
+        let mut end = bytes.len();
+        loop {
+            let found = self.rfind(&bytes[..end])?;
+            if self.on_boundaries(haystack, found) {
+                return Some(found);
+            }
+            // an empty needle is always on a boundary, so the window shrinks
+            end = found + self.needle.len() - 1;
+        }
+    }
+
+    /// Returns an iterator over the byte offsets of the occurrences of the needle in
+    /// `haystack` that start and end on char boundaries, in increasing order, see
+    /// [`Horspool::find_str`].
+    pub fn find_iter_str<'a>(
+        &'a self,
+        haystack: &'a str,
+        overlapping: bool,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let step = if overlapping { 1 } else { self.needle.len().max(1) };
+        let mut next = 0;
+        self.find_iter(haystack.as_bytes(), true).filter(move |&found| {
+            let keep = found >= next && self.on_boundaries(haystack, found);
+            if keep {
+                next = found + step;
+            }
+            keep
+        })
+    }
+
+    fn on_boundaries(&self, haystack: &str, found: usize) -> bool {
+        haystack.is_char_boundary(found) && haystack.is_char_boundary(found + self.needle.len())
+    }
+
+    /// Returns the number of occurrences of the needle in `haystack`, see [`Horspool::find_iter`].
+    pub fn count(&self, haystack: &[u8], overlapping: bool) -> usize {
+        self.find_iter(haystack, overlapping).count()
+    }
+}
+
+/// Iterator over the occurrences of a needle, see [`Horspool::find_iter`].
+pub struct Matches<'a, 'h> {
+    searcher: &'a Horspool,
+    haystack: &'h [u8],
+    position: usize,
+    overlapping: bool,
+}
+
+impl<'a, 'h> Iterator for Matches<'a, 'h> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let rest = self.haystack.get(self.position..)?;
+        let found = self.position + self.searcher.find(rest)?;
+        // an empty needle is found at every index, and once past the end
+        let length = self.searcher.needle.len();
+        self.position = found + if self.overlapping { 1 } else { length.max(1) };
+        Some(found)
+    }
+}
+
+/// Return the byte offset of the first occurrence of `needle` in the text made of `chunks` put end to end,
+/// with Boyer Moore Horspool algorithm.
+///
+/// The chunks are never copied into one string, so a match may start in a chunk and end in
+/// another one. This is how the chunks of a `Rope` are searched.
+///
+/// # Arguments
+///
+/// * `needle` - A slice of string, that's the word we're looking for
+/// * `chunks` - The pieces of the text, in order
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::boyer_moore_horspool_chunks;
+///
+/// fn main() {
+///     let chunks = ["Hello Jo", "hn, how a", "re you?"];
+///     assert_eq!(boyer_moore_horspool_chunks("John", &chunks), Some(6));
+///     assert_eq!(boyer_moore_horspool_chunks("are", &chunks), Some(16));
+///     assert_eq!(boyer_moore_horspool_chunks("Mary", &chunks), None);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<usize>` - The byte offset of the first occurence of `needle`, or None if not found
+///
+pub fn boyer_moore_horspool_chunks(needle: &str, chunks: &[&str]) -> Option<usize> {
+    let needle = needle.as_bytes();
+    if needle.is_empty() {
+        return Some(0);
+    }
+    // offsets[i] is the position of the first byte of chunks[i] in the whole text
+    let mut offsets = Vec::with_capacity(chunks.len() + 1);
+    offsets.push(0);
+    for chunk in chunks {
+        offsets.push(offsets[offsets.len() - 1] + chunk.len());
+    }
+    let total = offsets[chunks.len()];
+    if needle.len() > total {
+        return None;
+    }
+
     let table = bad_char_table(needle);
-    
-    let mut skip :usize  = 0; // main position
-    let mut i : usize;
-    
-    while haystack.len() - skip >= needle.len()
-    {
-        i = needle.len() -1; //beacause we read backwards
-        while haystack.as_bytes()[skip+i] as char == needle.as_bytes()[i] as char //if it matches
-        {
-            if i == 0 //if we arrive to head of word, we found first occurence of needle
-            {
-                return skip as i64;
+
+    // accesses stay inside a window of needle.len() bytes moving forward, so the chunk
+    // holding the previous byte is almost always the right one
+    let mut current = 0;
+    let mut byte_at = |position: usize| {
+        if position < offsets[current] || position >= offsets[current + 1] {
+            current = offsets.partition_point(|&offset| offset <= position) - 1;
+        }
+        chunks[current].as_bytes()[position - offsets[current]]
+    };
+
+    let mut skip = 0;
+    while skip + needle.len() <= total {
+        let mut i = needle.len() - 1;
+        while byte_at(skip + i) == needle[i] {
+            if i == 0 {
+                return Some(skip);
             }
-            i-=1;
+            i -= 1;
         }
-        //we go next position (skip + length of needle -1) and we look if we the character matches a character from our table
-        skip +=  match table.get(&(haystack.as_bytes()[skip+needle.len() -1] as char)) {
-            Some(x) =>  *x,
-            None => needle.len(), //
-        };
-    }
-    
-    //if not found
-    return -1;
+        skip += table[byte_at(skip + needle.len() - 1) as usize];
+    }
+    None
 }
 
 #[cfg(test)]
@@ -80,14 +353,99 @@ mod boyer_moore_horspool_tests {
     use super::*;
     #[test]
     fn good() {
-        assert_eq!(boyer_moore_horspool("dont", String::from("I dont know")), 2);
-        assert_eq!(boyer_moore_horspool("I", String::from("I dont know")), 0);
-        assert_eq!(boyer_moore_horspool("know", String::from("I dont know")), 7);
-        assert_eq!(boyer_moore_horspool("fish", String::from("I dont know")), -1);
-        assert_eq!(boyer_moore_horspool("i", String::from("I dont know")), -1);
+        assert_eq!(boyer_moore_horspool_str("dont", "I dont know"), Some(2));
+        assert_eq!(boyer_moore_horspool_str("I", "I dont know"), Some(0));
+        assert_eq!(boyer_moore_horspool_str("know", "I dont know"), Some(7));
+        assert_eq!(boyer_moore_horspool_str("fish", "I dont know"), None);
+        assert_eq!(boyer_moore_horspool_str("i", "I dont know"), None);
     }
     #[test]
     fn notgood() {
+        assert_eq!(boyer_moore_horspool(b"", b""), Some(0));
+        assert_eq!(boyer_moore_horspool(b"", b"abc"), Some(0));
+        assert_eq!(boyer_moore_horspool(b"abcd", b"abc"), None);
+        assert_eq!(boyer_moore_horspool(&[0, 255], &[255, 0, 0, 255]), Some(2));
+        // a needle built at runtime, searched in a borrowed haystack
+        let needle = format!("{}{}", "kn", "ow");
+        let haystack = String::from("I dont know");
+        assert_eq!(boyer_moore_horspool_str(&needle, &haystack[2..]), Some(5));
+    }
+    #[test]
+    fn chunks() {
+        let text = "the quick brown fox jumps over the lazy dog";
+        for size in 1..8 {
+            let pieces: Vec<&str> = text
+                .as_bytes()
+                .chunks(size)
+                .map(|piece| std::str::from_utf8(piece).unwrap())
+                .collect();
+            for needle in ["the", "fox", "lazy dog", "over the", "cat", "g", ""] {
+                assert_eq!(boyer_moore_horspool_chunks(needle, &pieces), text.find(needle));
+            }
+        }
+        assert_eq!(boyer_moore_horspool_chunks("a", &[]), None);
+        assert_eq!(boyer_moore_horspool_chunks("ab", &["", "a", "", "b"]), Some(0));
+    }
+    #[test]
+    fn str_against_std() {
+        let alphabet = ['a', 'b', 'é', '€', '𝄞'];
+        let mut rng = crate::math::Rng::new(50);
+        for _ in 0..500 {
+            let mut text = |length| -> String {
+                (0..rng.gen_range(0..length)).map(|_| alphabet[rng.gen_range(0..5)]).collect()
+            };
+            let haystack = text(30);
+            let random = text(4);
+            let chars: Vec<usize> = haystack
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([haystack.len()])
+                .collect();
+            let (start, end) = (rng.gen_range(0..chars.len()), rng.gen_range(0..chars.len()));
+            let inside = &haystack[chars[start.min(end)]..chars[start.max(end)]];
+            let longer = format!("{haystack}a");
+            for needle in [random.as_str(), inside, "", longer.as_str()] {
+                let searcher = Horspool::new(needle.as_bytes());
+                assert_eq!(boyer_moore_horspool_str(needle, &haystack), haystack.find(needle));
+                assert_eq!(searcher.find_str(&haystack), haystack.find(needle));
+                assert_eq!(searcher.rfind_str(&haystack), haystack.rfind(needle));
+                let indices: Vec<usize> = haystack.match_indices(needle).map(|(i, _)| i).collect();
+                assert_eq!(searcher.find_iter_str(&haystack, false).collect::<Vec<_>>(), indices);
+            }
+            // a needle cut inside a char never matches on boundaries
+            for cut in ["é", "€", "𝄞"].map(|c| &c.as_bytes()[1..]) {
+                let searcher = Horspool::new(cut);
+                let naive = haystack.as_bytes().windows(cut.len()).position(|window| window == cut);
+                assert_eq!(searcher.find(haystack.as_bytes()), naive);
+                assert_eq!(searcher.find_str(&haystack), None);
+                assert_eq!(searcher.rfind_str(&haystack), None);
+            }
+        }
+    }
+    #[test]
+    fn searcher_against_naive() {
+        let mut rng = crate::math::Rng::new(49);
+        for _ in 0..500 {
+            let mut text = |length| -> Vec<u8> {
+                (0..rng.gen_range(0..length)).map(|_| b"ab"[rng.gen_range(0..2)]).collect()
+            };
+            let (haystack, needle) = (text(40), text(5));
+            let all: Vec<usize> = (0..=haystack.len())
+                .filter(|&i| haystack[i..].starts_with(&needle))
+                .collect();
+            let mut disjoint: Vec<usize> = Vec::new();
+            for &i in &all {
+                if disjoint.last().is_none_or(|&last| i >= last + needle.len().max(1)) {
+                    disjoint.push(i);
+                }
+            }
+            let searcher = Horspool::new(&needle);
+            assert_eq!(searcher.find(&haystack), all.first().copied());
+            assert_eq!(searcher.rfind(&haystack), all.last().copied());
+            assert_eq!(searcher.find_iter(&haystack, true).collect::<Vec<_>>(), all);
+            assert_eq!(searcher.find_iter(&haystack, false).collect::<Vec<_>>(), disjoint);
+            assert_eq!(searcher.count(&haystack, true), all.len());
+        }
     }
 
 }
\ No newline at end of file
@@ -1,5 +1,22 @@
 // #[doc(inline)]
+pub mod binary;
 pub mod boyer_moore_horspool;
+pub mod select;
 
 #[doc(inline)]
-pub use self::boyer_moore_horspool::boyer_moore_horspool;
\ No newline at end of file
+pub use self::binary::{
+    equal_range, equal_range_by, exponential_search, exponential_search_by, interpolation_search,
+    lower_bound, lower_bound_by, lower_bound_by_key, partition_point, ternary_search_max,
+    ternary_search_min, upper_bound, upper_bound_by, upper_bound_by_key, Domain,
+};
+#[doc(inline)]
+pub use self::boyer_moore_horspool::{
+    boyer_moore_horspool, boyer_moore_horspool_chunks, boyer_moore_horspool_str, Horspool,
+    Matches,
+};
+#[doc(inline)]
+pub use self::select::{
+    median, partition_by_pivot, partition_by_pivot_by, select_nth, select_nth_by,
+    select_nth_by_key, select_nth_median_of_medians, select_nth_median_of_medians_by, top_k,
+    top_k_by, top_k_by_key,
+};
@@ -0,0 +1,333 @@
+use crate::math::Rng;
+use crate::sorting::{insertion_sort_by, introsort_by};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Partition a slice in three around the element at `pivot`: the smaller elements, the
+/// elements equal to the pivot, then the larger ones (Dijkstra's Dutch national flag).
+///
+/// # Arguments
+///
+/// * `slice` - The elements to partition
+/// * `pivot` - The index of the pivot
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::partition_by_pivot;
+/// fn main() {
+///     let mut values = [5, 1, 5, 9, 2, 5, 7];
+///     let equal = partition_by_pivot(&mut values, 0);
+///     assert_eq!(equal, 2..5);
+///     assert!(values[..2].iter().all(|&v| v < 5));
+///     assert_eq!(values[2..5], [5, 5, 5]);
+///     assert!(values[5..].iter().all(|&v| v > 5));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Range<usize>` - The positions of the elements equal to the pivot, the pivot included
+///
+pub fn partition_by_pivot<T: Ord>(slice: &mut [T], pivot: usize) -> Range<usize> {
+    partition_by_pivot_by(slice, pivot, T::cmp)
+}
+
+/// Partition a slice in three around the element at `pivot`, ordering the elements with
+/// `compare`.
+pub fn partition_by_pivot_by<T, F>(slice: &mut [T], pivot: usize, mut compare: F) -> Range<usize>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    slice.swap(0, pivot);
+    let (head, rest) = slice.split_first_mut().unwrap();
+    // rest[..less] is smaller than the pivot, rest[less..i] equal, rest[greater..] larger
+    let (mut less, mut i, mut greater) = (0, 0, rest.len());
+    while i < greater {
+        match compare(&rest[i], head) {
+            Ordering::Less => {
+                rest.swap(less, i);
+                less += 1;
+                i += 1;
+            }
+            Ordering::Equal => i += 1,
+            Ordering::Greater => {
+                greater -= 1;
+                rest.swap(i, greater);
+            }
+        }
+    }
+    // the last smaller element takes the place of the pivot, at the front
+    slice.swap(0, less);
+    less..greater + 1
+}
+
+/// Reorder a slice so that the element at `k` is the one a sort would put there, with no
+/// larger element before it and no smaller one after it, using quickselect.
+///
+/// Like quick sort, but only the side holding position `k` is partitioned further. The
+/// pivots are drawn from a generator seeded with the length, so the choice is deterministic:
+/// O(n) expected on typical inputs, but an input crafted against those pivots can take O(n²).
+/// Use `select_nth_median_of_medians` when linear time must be guaranteed.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to reorder
+/// * `k` - The position wanted, less than the length
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::select_nth;
+/// fn main() {
+///     let mut values = [9, 1, 8, 2, 7, 3, 6];
+///     assert_eq!(*select_nth(&mut values, 0), 1);
+///     assert_eq!(*select_nth(&mut values, 3), 6);
+///     assert!(values[..3].iter().all(|&v| v <= 6));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `&mut T` - The element at `k`, the k-th smallest counting from 0
+///
+pub fn select_nth<T: Ord>(slice: &mut [T], k: usize) -> &mut T {
+    select_nth_by(slice, k, T::cmp)
+}
+
+/// Reorder a slice with quickselect so that the element at `k` is the one a sort would put
+/// there, ordering the elements with `compare`.
+pub fn select_nth_by<T, F>(slice: &mut [T], k: usize, mut compare: F) -> &mut T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(
+        k < slice.len(),
+        "position {} out of a slice of length {}",
+        k,
+        slice.len()
+    );
+    // seeded with the length so the result is reproducible, not to resist adversarial input
+    let mut rng = Rng::new(slice.len() as u64);
+    let (mut low, mut high) = (0, slice.len());
+    while high - low > 1 {
+        let pivot = rng.gen_range(0..high - low);
+        let equal = partition_by_pivot_by(&mut slice[low..high], pivot, &mut compare);
+        let equal = low + equal.start..low + equal.end;
+        if k < equal.start {
+            high = equal.start;
+        } else if k >= equal.end {
+            low = equal.end;
+        } else {
+            break;
+        }
+    }
+    &mut slice[k]
+}
+
+/// Reorder a slice with quickselect so that the element at `k` is the one a sort would put
+/// there, ordering the elements by the key `key` extracts.
+pub fn select_nth_by_key<T, K: Ord, F>(slice: &mut [T], k: usize, mut key: F) -> &mut T
+where
+    F: FnMut(&T) -> K,
+{
+    select_nth_by(slice, k, |a, b| key(a).cmp(&key(b)))
+}
+
+/// Reorder a slice so that the element at `k` is the one a sort would put there, in linear
+/// time at worst, with the median of medians.
+///
+/// The pivot is chosen with care: the median of each group of five elements is found, then
+/// the median of those medians, recursively. At least 3/10 of the elements are on each side
+/// of it, so every partition drops a constant part of the slice. O(n) in the worst case, but
+/// slower than `select_nth` on average.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to reorder
+/// * `k` - The position wanted, less than the length
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::select_nth_median_of_medians;
+/// fn main() {
+///     let mut values: Vec<u32> = (0..1000).rev().collect();
+///     assert_eq!(*select_nth_median_of_medians(&mut values, 250), 250);
+///     assert!(values[..250].iter().all(|&v| v < 250));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `&mut T` - The element at `k`, the k-th smallest counting from 0
+///
+pub fn select_nth_median_of_medians<T: Ord>(slice: &mut [T], k: usize) -> &mut T {
+    select_nth_median_of_medians_by(slice, k, T::cmp)
+}
+
+/// Reorder a slice with the median of medians so that the element at `k` is the one a sort
+/// would put there, ordering the elements with `compare`.
+pub fn select_nth_median_of_medians_by<T, F>(slice: &mut [T], k: usize, mut compare: F) -> &mut T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn select<T, F>(mut slice: &mut [T], mut k: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        loop {
+            if slice.len() <= 5 {
+                insertion_sort_by(slice, &mut *compare);
+                return;
+            }
+            // the median of each group of five moves to the front
+            let groups = slice.len().div_ceil(5);
+            for group in 0..groups {
+                let start = group * 5;
+                let end = (start + 5).min(slice.len());
+                insertion_sort_by(&mut slice[start..end], &mut *compare);
+                slice.swap(group, (start + end) / 2);
+            }
+            select(&mut slice[..groups], groups / 2, compare);
+            let equal = partition_by_pivot_by(slice, groups / 2, &mut *compare);
+            if k < equal.start {
+                slice = &mut std::mem::take(&mut slice)[..equal.start];
+            } else if k >= equal.end {
+                slice = &mut std::mem::take(&mut slice)[equal.end..];
+                k -= equal.end;
+            } else {
+                return;
+            }
+        }
+    }
+    assert!(
+        k < slice.len(),
+        "position {} out of a slice of length {}",
+        k,
+        slice.len()
+    );
+    select(slice, k, &mut compare);
+    &mut slice[k]
+}
+
+/// Returns the median of a slice, reordering it with quickselect; for an even length, the
+/// lower of the two middle elements.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::median;
+/// fn main() {
+///     assert_eq!(median(&mut [3, 1, 4, 1, 5]), Some(&3));
+///     assert_eq!(median(&mut [8, 2, 6, 4]), Some(&4));
+///     assert_eq!(median::<u8>(&mut []), None);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<&T>` - The median, `None` for an empty slice
+///
+pub fn median<T: Ord>(slice: &mut [T]) -> Option<&T> {
+    if slice.is_empty() {
+        return None;
+    }
+    let middle = (slice.len() - 1) / 2;
+    Some(select_nth(slice, middle))
+}
+
+/// Move the `k` largest elements of a slice to its front, in decreasing order, without
+/// sorting the others.
+///
+/// Quickselect puts them in front in O(n) on typical inputs, then only they are sorted, for
+/// O(n + k log k) in total.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to search
+/// * `k` - The number of elements wanted, all of them if larger than the length
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::searching::top_k;
+/// fn main() {
+///     let mut scores = [31, 87, 12, 99, 45, 87, 3];
+///     assert_eq!(top_k(&mut scores, 3), [99, 87, 87]);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `&mut [T]` - The first `k` elements, the largest in decreasing order
+///
+pub fn top_k<T: Ord>(slice: &mut [T], k: usize) -> &mut [T] {
+    top_k_by(slice, k, T::cmp)
+}
+
+/// Move the `k` largest elements of a slice to its front, in decreasing order, ordering the
+/// elements with `compare`.
+pub fn top_k_by<T, F>(slice: &mut [T], k: usize, mut compare: F) -> &mut [T]
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let k = k.min(slice.len());
+    if k == 0 {
+        return &mut slice[..0];
+    }
+    let mut decreasing = |a: &T, b: &T| compare(b, a);
+    select_nth_by(slice, k - 1, &mut decreasing);
+    introsort_by(&mut slice[..k], decreasing);
+    &mut slice[..k]
+}
+
+/// Move the `k` elements of a slice with the largest keys to its front, in decreasing order
+/// of key.
+pub fn top_k_by_key<T, K: Ord, F>(slice: &mut [T], k: usize, mut key: F) -> &mut [T]
+where
+    F: FnMut(&T) -> K,
+{
+    top_k_by(slice, k, |a, b| key(a).cmp(&key(b)))
+}
+
+#[cfg(test)]
+mod select_tests {
+    use super::*;
+
+    #[test]
+    fn select_against_sort() {
+        let mut rng = Rng::new(46);
+        for _ in 0..200 {
+            let len = rng.gen_range(1..200);
+            let values: Vec<u32> = (0..len).map(|_| (rng.next_u64() % 20) as u32).collect();
+            let mut sorted = values.clone();
+            sorted.sort();
+            let k = rng.gen_range(0..len);
+            let mut quick = values.clone();
+            assert_eq!(*select_nth(&mut quick, k), sorted[k]);
+            let mut linear = values.clone();
+            assert_eq!(*select_nth_median_of_medians(&mut linear, k), sorted[k]);
+            for selected in [quick, linear] {
+                assert!(selected[..k].iter().all(|&v| v <= sorted[k]));
+                assert!(selected[k + 1..].iter().all(|&v| v >= sorted[k]));
+            }
+            let mut top = values.clone();
+            let count = rng.gen_range(0..len + 5);
+            let expected: Vec<u32> = sorted.iter().rev().take(count).copied().collect();
+            assert_eq!(top_k(&mut top, count), expected);
+        }
+    }
+
+    #[test]
+    fn median_of_medians_is_linear() {
+        // an organ pipe, and many equal elements
+        let mut comparisons = 0;
+        let mut values: Vec<u32> = (0..100_000).map(|i| i.min(100_000 - i) / 4).collect();
+        select_nth_median_of_medians_by(&mut values, 70_000, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(comparisons < 2_000_000, "{} comparisons", comparisons);
+    }
+}
@@ -0,0 +1,65 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+/// Sort a slice in increasing order with bubble sort.
+///
+/// Adjacent elements out of order are swapped, pass after pass; after each pass the largest
+/// element left is in place, and a pass without swap ends the sort. O(n²), O(n) on a sorted
+/// slice. Stable, in place.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::bubble_sort;
+/// fn main() {
+///     let mut values = [3, 1, 2];
+///     bubble_sort(&mut values);
+///     assert_eq!(values, [1, 2, 3]);
+/// }
+/// ```
+///
+pub fn bubble_sort<T: Ord>(slice: &mut [T]) {
+    bubble_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with bubble sort, ordering the elements with `compare`.
+pub fn bubble_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut end = slice.len();
+    while end > 1 {
+        // everything after the last swap is already in place
+        let mut last_swap = 0;
+        for i in 1..end {
+            if compare(&slice[i - 1], &slice[i]) == Ordering::Greater {
+                swap(slice, i - 1, i);
+                last_swap = i;
+            }
+        }
+        end = last_swap;
+    }
+}
+
+/// Sort a slice with bubble sort, ordering the elements by the key `key` extracts.
+pub fn bubble_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    bubble_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod bubble_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| bubble_sort_by_key(slice, |pair| pair.0));
+    }
+}
@@ -0,0 +1,109 @@
+use super::insertion::insertion_sort_by;
+use super::stats::{counted, unrecorded};
+use super::{apply_permutation, destinations};
+
+/// Sort a slice in increasing order with bucket sort.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort, none of them NaN
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::bucket_sort;
+/// fn main() {
+///     let mut values = [0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+///     bucket_sort(&mut values);
+///     assert_eq!(values, [0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// }
+/// ```
+///
+pub fn bucket_sort<T: Copy + Into<f64>>(slice: &mut [T]) {
+    bucket_sort_by_key(slice, |&value| value.into());
+}
+
+/// Sort a slice with bucket sort, ordering the elements by the float key `key` extracts.
+///
+/// The range of the keys is split into one bucket per element, each element goes to the
+/// bucket of its key, and the buckets are sorted by insertion then concatenated. O(n) expected
+/// when the keys are spread uniformly, O(n²) when they fall in a few buckets. Stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+/// * `key` - The key of an element, never NaN
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::bucket_sort_by_key;
+/// fn main() {
+///     let mut cities = [("Oslo", 59.9), ("Quito", -0.2), ("Cairo", 30.0), ("Lima", -12.0)];
+///     bucket_sort_by_key(&mut cities, |city| city.1);
+///     let names: Vec<&str> = cities.iter().map(|city| city.0).collect();
+///     assert_eq!(names, ["Lima", "Quito", "Cairo", "Oslo"]);
+/// }
+/// ```
+///
+pub fn bucket_sort_by_key<T, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> f64,
+{
+    let keys: Vec<f64> = counted(slice.iter().map(&mut key).collect());
+    assert!(
+        keys.iter().all(|k| !k.is_nan()),
+        "bucket sort cannot order NaN keys"
+    );
+    let min = keys.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = keys.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let n = slice.len();
+    if n < 2 || min == max {
+        return;
+    }
+    let mut buckets: Vec<Vec<usize>> = counted(vec![Vec::new(); n]);
+    for (element, &k) in keys.iter().enumerate() {
+        // the ratio is computed apart so infinite or huge ranges do not overflow to NaN
+        let ratio = (k - min) / (max - min);
+        let bucket = if ratio.is_nan() {
+            0
+        } else {
+            (ratio * n as f64) as usize
+        };
+        buckets[bucket.min(n - 1)].push(element);
+    }
+    let mut order = counted(Vec::with_capacity(n));
+    for bucket in buckets {
+        let mut bucket = counted(bucket);
+        // the swaps of indices are not swaps of elements
+        unrecorded(|| insertion_sort_by(&mut bucket, |&a, &b| keys[a].total_cmp(&keys[b])));
+        order.extend(bucket);
+    }
+    apply_permutation(slice, destinations(&order));
+}
+
+#[cfg(test)]
+mod bucket_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| {
+            bucket_sort_by_key(slice, |pair| pair.0 as f64)
+        });
+    }
+
+    #[test]
+    fn signed_zeros() {
+        let mut values = [1.0f64, 0.0, -0.0];
+        bucket_sort(&mut values);
+        assert!(values[0].is_sign_negative() && values[1].is_sign_positive());
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket sort cannot order NaN keys")]
+    fn nan() {
+        bucket_sort(&mut [1.0, f64::NAN]);
+    }
+}
@@ -0,0 +1,84 @@
+use super::apply_permutation;
+use super::radix::RadixKey;
+use super::stats::counted;
+
+/// Sort a slice in increasing order with counting sort.
+///
+/// The occurrences of each key between the smallest and the largest are counted, which gives
+/// where every element goes. O(n + k) time and memory for keys spanning k values, so it only
+/// suits keys from a small range. Stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::counting_sort;
+/// fn main() {
+///     let mut grades = [3u8, 1, 4, 1, 5, 2, 5, 3];
+///     counting_sort(&mut grades);
+///     assert_eq!(grades, [1, 1, 2, 3, 3, 4, 5, 5]);
+/// }
+/// ```
+///
+pub fn counting_sort<T: RadixKey>(slice: &mut [T]) {
+    counting_sort_by_key(slice, |&value| value);
+}
+
+/// Sort a slice with counting sort, ordering the elements by the key `key` extracts.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::counting_sort_by_key;
+/// fn main() {
+///     let mut people = [("Ada", 36), ("Alan", 41), ("Grace", 36), ("Edsger", 41), ("Barbara", 29)];
+///     counting_sort_by_key(&mut people, |person| person.1);
+///     let names: Vec<&str> = people.iter().map(|person| person.0).collect();
+///     assert_eq!(names, ["Barbara", "Ada", "Grace", "Alan", "Edsger"]);
+/// }
+/// ```
+///
+pub fn counting_sort_by_key<T, K: RadixKey, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<u64> = counted(slice.iter().map(|value| key(value).radix_key()).collect());
+    let (Some(&min), Some(&max)) = (keys.iter().min(), keys.iter().max()) else {
+        return;
+    };
+    let range = usize::try_from(max - min)
+        .ok()
+        .and_then(|range| range.checked_add(1))
+        .expect("the keys span too many values for counting sort");
+    let mut starts = counted(vec![0; range + 1]);
+    for &k in &keys {
+        starts[(k - min) as usize + 1] += 1;
+    }
+    for value in 0..range {
+        starts[value + 1] += starts[value];
+    }
+    let destinations = counted(
+        keys.iter()
+            .map(|&k| {
+                let start = &mut starts[(k - min) as usize];
+                *start += 1;
+                *start - 1
+            })
+            .collect(),
+    );
+    apply_permutation(slice, destinations);
+}
+
+#[cfg(test)]
+mod counting_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| counting_sort_by_key(slice, |pair| pair.0));
+    }
+}
@@ -0,0 +1,538 @@
+//! External merge sort, for inputs larger than memory.
+//!
+//! The input is read in chunks that fit in a memory budget; each chunk is sorted and spilled
+//! to a temporary file as a sorted run, then the runs are merged into the output with a heap
+//! holding the next record of each run, a bounded number of runs at a time. An input that fits in one chunk is sorted in memory
+//! and no file is written.
+//!
+//! The records are read and written by a [`Codec`]: [`Lines`] for text, one record per line,
+//! and [`FixedSize`] for binary records of a fixed number of bytes.
+
+use super::timsort::timsort_by;
+use std::cmp::Ordering;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+/// How records are read from and written to a stream.
+pub trait Codec {
+    /// The type of the records.
+    type Record;
+
+    /// Read the next record.
+    ///
+    /// # Returns
+    ///
+    /// * `io::Result<Option<Self::Record>>` - The record, `None` at the end of the stream, or the error met
+    ///
+    fn read(&self, reader: &mut impl BufRead) -> io::Result<Option<Self::Record>>;
+
+    /// Write a record, so that `read` reads it back.
+    fn write(&self, record: &Self::Record, writer: &mut impl Write) -> io::Result<()>;
+
+    /// Returns the memory a record takes, counted against the memory budget.
+    fn size(&self, record: &Self::Record) -> usize;
+}
+
+/// Text records: one record per line, without its `\n`.
+///
+/// A `\r` before the `\n` is part of the record. Every record is written followed by `\n`,
+/// so a last line without one gets it. The input must be valid UTF-8.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Lines;
+
+impl Codec for Lines {
+    type Record = String;
+
+    fn read(&self, reader: &mut impl BufRead) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        if line.ends_with('\n') {
+            line.pop();
+        }
+        Ok(Some(line))
+    }
+
+    fn write(&self, record: &String, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(record.as_bytes())?;
+        writer.write_all(b"\n")
+    }
+
+    fn size(&self, record: &String) -> usize {
+        mem::size_of::<String>() + record.capacity()
+    }
+}
+
+/// Binary records of `N` bytes, with no separator.
+///
+/// A stream whose length is not a multiple of `N` ends with a truncated record, which is an
+/// `InvalidData` error.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FixedSize<const N: usize>;
+
+impl<const N: usize> Codec for FixedSize<N> {
+    type Record = [u8; N];
+
+    fn read(&self, reader: &mut impl BufRead) -> io::Result<Option<[u8; N]>> {
+        assert!(N > 0, "a fixed size record needs at least one byte");
+        let mut record = [0; N];
+        let mut filled = 0;
+        while filled < N {
+            match reader.read(&mut record[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        match filled {
+            0 => Ok(None),
+            _ if filled == N => Ok(Some(record)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("a record of {} bytes is truncated to {} bytes", N, filled),
+            )),
+        }
+    }
+
+    fn write(&self, record: &[u8; N], writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(record)
+    }
+
+    fn size(&self, _: &[u8; N]) -> usize {
+        N
+    }
+}
+
+/// The resources an external sort may use.
+///
+/// # Attributes
+///
+/// * `memory` - The memory the records of a chunk may take, as the codec measures them
+/// * `directory` - Where the sorted runs are written; their files are removed once merged
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalOptions {
+    pub memory: usize,
+    pub directory: PathBuf,
+}
+
+impl Default for ExternalOptions {
+    /// A budget of 64 MiB and the temporary directory of the system.
+    fn default() -> Self {
+        ExternalOptions {
+            memory: 64 << 20,
+            directory: std::env::temp_dir(),
+        }
+    }
+}
+
+/// What an external sort did.
+///
+/// # Attributes
+///
+/// * `records` - The number of records sorted
+/// * `runs` - The number of sorted runs spilled to files, 0 if the input fit in memory
+/// * `passes` - The number of merge passes over the records, 0 if the input fit in memory
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExternalStats {
+    pub records: usize,
+    pub runs: usize,
+    pub passes: usize,
+}
+
+/// The most runs merged at once, well below the usual limit of 1024 open files.
+const MAX_FAN_IN: usize = 256;
+/// The smallest read buffer of a merged run the budget should allow.
+const MIN_BUFFER: usize = 4096;
+
+/// The files of the sorted runs, removed when dropped, even after an error.
+struct RunFiles {
+    paths: Vec<PathBuf>,
+}
+
+impl RunFiles {
+    /// Returns the path of a new run of `directory`, to be removed with the others.
+    fn next_path(&mut self, options: &ExternalOptions) -> &PathBuf {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        self.paths.push(options.directory.join(format!(
+            "rust_algo-sort-{}-{}.run",
+            process::id(),
+            NEXT.fetch_add(1, AtomicOrdering::Relaxed)
+        )));
+        &self.paths[self.paths.len() - 1]
+    }
+
+    /// Create a new empty run file in `directory`.
+    fn create(&mut self, options: &ExternalOptions) -> io::Result<BufWriter<File>> {
+        Ok(BufWriter::new(File::create_new(self.next_path(options))?))
+    }
+
+    /// Write `records` to a new file of `directory`.
+    fn spill<C: Codec>(
+        &mut self,
+        records: &[C::Record],
+        codec: &C,
+        options: &ExternalOptions,
+    ) -> io::Result<()> {
+        let mut writer = self.create(options)?;
+        for record in records {
+            codec.write(record, &mut writer)?;
+        }
+        writer.flush()
+    }
+}
+
+impl Drop for RunFiles {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            // a file that cannot be removed is left behind rather than hiding the result
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Move the run at `node` down the min heap `heap` of run indices, `before` telling whether
+/// the head of a run comes before the head of another.
+fn sift_down(heap: &mut [usize], mut node: usize, mut before: impl FnMut(usize, usize) -> bool) {
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= heap.len() {
+            return;
+        }
+        if child + 1 < heap.len() && before(heap[child + 1], heap[child]) {
+            child += 1;
+        }
+        if !before(heap[child], heap[node]) {
+            return;
+        }
+        heap.swap(node, child);
+        node = child;
+    }
+}
+
+/// Merge the sorted runs of `paths` into `writer`, with a binary heap of their next records,
+/// each run read through a buffer of `capacity` bytes.
+fn merge<C, W, F>(
+    paths: &[PathBuf],
+    writer: &mut W,
+    codec: &C,
+    capacity: usize,
+    compare: &mut F,
+) -> io::Result<()>
+where
+    C: Codec,
+    W: Write,
+    F: FnMut(&C::Record, &C::Record) -> Ordering,
+{
+    let mut readers = paths
+        .iter()
+        .map(|path| Ok(BufReader::with_capacity(capacity, File::open(path)?)))
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut heads = readers
+        .iter_mut()
+        .map(|reader| codec.read(reader))
+        .collect::<io::Result<Vec<_>>>()?;
+    // the head of a run comes first if it is smaller, or equal and from an earlier run
+    let mut before = |heads: &[Option<C::Record>], a: usize, b: usize| match compare(
+        heads[a].as_ref().unwrap(),
+        heads[b].as_ref().unwrap(),
+    ) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => a < b,
+    };
+    let mut heap: Vec<usize> = (0..heads.len())
+        .filter(|&run| heads[run].is_some())
+        .collect();
+    for node in (0..heap.len() / 2).rev() {
+        sift_down(&mut heap, node, |a, b| before(&heads, a, b));
+    }
+    while let Some(&run) = heap.first() {
+        let record = heads[run].take().unwrap();
+        codec.write(&record, writer)?;
+        heads[run] = codec.read(&mut readers[run])?;
+        if heads[run].is_none() {
+            heap.swap_remove(0);
+        }
+        sift_down(&mut heap, 0, |a, b| before(&heads, a, b));
+    }
+    writer.flush()
+}
+
+/// Sort the records of `input` in increasing order into `output`, using at most the memory
+/// budget of `options` for the records.
+///
+/// # Arguments
+///
+/// * `input` - The records to sort
+/// * `output` - Where the sorted records are written
+/// * `codec` - How the records are read and written
+/// * `options` - The memory budget and the directory of the sorted runs
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::external::{external_sort, ExternalOptions, Lines};
+/// fn main() {
+///     let input = "pear\napple\nfig\nbanana\n";
+///     let mut output = Vec::new();
+///     let stats = external_sort(input.as_bytes(), &mut output, &Lines, &ExternalOptions::default()).unwrap();
+///     assert_eq!(String::from_utf8(output).unwrap(), "apple\nbanana\nfig\npear\n");
+///     assert_eq!((stats.records, stats.runs), (4, 0));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `io::Result<ExternalStats>` - The number of records and of spilled runs, or the first error met
+///
+pub fn external_sort<C, R, W>(
+    input: R,
+    output: W,
+    codec: &C,
+    options: &ExternalOptions,
+) -> io::Result<ExternalStats>
+where
+    C: Codec,
+    C::Record: Ord + Clone,
+    R: Read,
+    W: Write,
+{
+    external_sort_by(input, output, codec, options, C::Record::cmp)
+}
+
+/// Sort the records of `input` into `output`, ordering them with `compare`.
+///
+/// Chunks are read until their records take the memory budget, then sorted with TimSort and
+/// spilled as runs. The runs are merged with a binary heap of their next records, each run
+/// read through its own buffer. At most 256 runs, and one per 4 KiB of the budget, are merged
+/// at once: when there are more, groups of runs are first merged into longer runs, in as
+/// many passes as needed. Equal records keep their input order. O(n log n) comparisons,
+/// every record read and written once more per merge pass when the input does not fit in
+/// memory.
+///
+/// # Arguments
+///
+/// * `input` - The records to sort
+/// * `output` - Where the sorted records are written
+/// * `codec` - How the records are read and written
+/// * `options` - The memory budget and the directory of the sorted runs
+/// * `compare` - The order of the records
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::external::{external_sort_by, ExternalOptions, FixedSize};
+/// fn main() {
+///     // big endian u32 records, sorted in decreasing order with a budget of two records
+///     let input: Vec<u8> = [7u32, 1, 9, 4, 8].iter().flat_map(|v| v.to_be_bytes()).collect();
+///     let options = ExternalOptions { memory: 8, ..ExternalOptions::default() };
+///     let mut output = Vec::new();
+///     let stats = external_sort_by(&input[..], &mut output, &FixedSize::<4>, &options, |a, b| b.cmp(a)).unwrap();
+///     let values: Vec<u32> = output.chunks(4).map(|c| u32::from_be_bytes(c.try_into().unwrap())).collect();
+///     assert_eq!(values, [9, 8, 7, 4, 1]);
+///     assert_eq!(stats.runs, 3);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `io::Result<ExternalStats>` - The number of records and of spilled runs, or the first error met
+///
+pub fn external_sort_by<C, R, W, F>(
+    input: R,
+    output: W,
+    codec: &C,
+    options: &ExternalOptions,
+    mut compare: F,
+) -> io::Result<ExternalStats>
+where
+    C: Codec,
+    C::Record: Clone,
+    R: Read,
+    W: Write,
+    F: FnMut(&C::Record, &C::Record) -> Ordering,
+{
+    let mut reader = BufReader::new(input);
+    let mut writer = BufWriter::new(output);
+    let mut runs = RunFiles { paths: Vec::new() };
+    let mut records = 0;
+    let mut chunk = Vec::new();
+    loop {
+        let mut used = 0;
+        let mut exhausted = true;
+        while let Some(record) = codec.read(&mut reader)? {
+            used += codec.size(&record);
+            chunk.push(record);
+            if used >= options.memory {
+                exhausted = false;
+                break;
+            }
+        }
+        records += chunk.len();
+        timsort_by(&mut chunk, &mut compare);
+        if exhausted && runs.paths.is_empty() {
+            // everything fit in memory
+            for record in &chunk {
+                codec.write(record, &mut writer)?;
+            }
+            writer.flush()?;
+            return Ok(ExternalStats {
+                records,
+                runs: 0,
+                passes: 0,
+            });
+        }
+        if !chunk.is_empty() {
+            runs.spill(&chunk, codec, options)?;
+        }
+        chunk.clear();
+        if exhausted {
+            break;
+        }
+    }
+    drop(chunk);
+
+    let spilled = runs.paths.len();
+
+    // each run merged reads through a buffer of an equal share of the budget, of 4 KiB at
+    // least when the budget allows, and no more runs than MAX_FAN_IN are open at once
+    let fan_in = (options.memory / MIN_BUFFER).clamp(2, MAX_FAN_IN);
+    let capacity = (options.memory / fan_in).clamp(1, 1 << 20);
+    let mut passes = 1;
+    while runs.paths.len() > fan_in {
+        // contiguous groups are merged in order, so equal records keep their input order
+        let merged = RunFiles {
+            paths: mem::take(&mut runs.paths),
+        };
+        for group in merged.paths.chunks(fan_in) {
+            if let [lone] = group {
+                // moved to the next pass as it is
+                fs::rename(lone, runs.next_path(options))?;
+                continue;
+            }
+            let mut run = runs.create(options)?;
+            merge(group, &mut run, codec, capacity, &mut compare)?;
+        }
+        passes += 1;
+    }
+    merge(&runs.paths, &mut writer, codec, capacity, &mut compare)?;
+    Ok(ExternalStats {
+        records,
+        runs: spilled,
+        passes,
+    })
+}
+
+/// Sort the records of `input` into `output`, ordering them by the key `key` extracts.
+pub fn external_sort_by_key<C, R, W, K, F>(
+    input: R,
+    output: W,
+    codec: &C,
+    options: &ExternalOptions,
+    mut key: F,
+) -> io::Result<ExternalStats>
+where
+    C: Codec,
+    C::Record: Clone,
+    R: Read,
+    W: Write,
+    K: Ord,
+    F: FnMut(&C::Record) -> K,
+{
+    external_sort_by(input, output, codec, options, |a, b| key(a).cmp(&key(b)))
+}
+
+#[cfg(test)]
+mod external_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    /// Returns options with a budget of `memory` and a new empty directory.
+    fn options(name: &str, memory: usize) -> ExternalOptions {
+        let directory = std::env::temp_dir().join(format!("rust_algo-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir(&directory).unwrap();
+        ExternalOptions { memory, directory }
+    }
+
+    #[test]
+    fn lines() {
+        let mut rng = Rng::new(44);
+        let lines: Vec<String> = (0..5000)
+            .map(|_| format!("{:x}", rng.next_u64() % 100_000))
+            .collect();
+        let input = lines.join("\n");
+        let options = options("lines", 4096);
+        let mut output = Vec::new();
+        let stats = external_sort(input.as_bytes(), &mut output, &Lines, &options).unwrap();
+        let mut expected = lines;
+        expected.sort();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            expected.join("\n") + "\n"
+        );
+        assert_eq!(stats.records, 5000);
+        assert!(stats.runs > 20);
+        // the runs are removed
+        assert_eq!(fs::read_dir(&options.directory).unwrap().count(), 0);
+        fs::remove_dir(&options.directory).unwrap();
+    }
+
+    #[test]
+    fn fixed_size_records() {
+        // the first byte is the key, the second the input position
+        let input: Vec<u8> = (0..200u8).flat_map(|i| [i % 7, i]).collect();
+        let options = options("fixed", 30);
+        let mut output = Vec::new();
+        let stats =
+            external_sort_by_key(&input[..], &mut output, &FixedSize::<2>, &options, |r| r[0])
+                .unwrap();
+        assert_eq!(stats.runs, 14);
+        let mut expected: Vec<[u8; 2]> = (0..200u8).map(|i| [i % 7, i]).collect();
+        expected.sort_by_key(|r| r[0]);
+        assert_eq!(output, expected.concat());
+
+        assert_eq!(stats.passes, 4);
+        let error = external_sort(&input[..3], io::sink(), &FixedSize::<2>, &options).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(fs::read_dir(&options.directory).unwrap().count(), 0);
+        fs::remove_dir(&options.directory).unwrap();
+    }
+
+    #[test]
+    fn merge_passes() {
+        // 20 runs of 1536 records merged 3 at a time: 20 runs, then 7, then 3, then the output
+        let mut rng = Rng::new(4096);
+        let records: Vec<[u8; 8]> = (0..20 * 1536u64)
+            .map(|i| {
+                let key = rng.next_u64() % 1000;
+                ((key << 32) | i).to_be_bytes()
+            })
+            .collect();
+        let options = options("passes", 3 * 4096);
+        let mut output = Vec::new();
+        let stats = external_sort_by_key(
+            &records.concat()[..],
+            &mut output,
+            &FixedSize::<8>,
+            &options,
+            |record| record[..4].to_vec(),
+        )
+        .unwrap();
+        assert_eq!((stats.runs, stats.passes), (20, 3));
+        // stable, so the input position breaks ties as the whole record does
+        let mut expected = records;
+        expected.sort();
+        assert_eq!(output, expected.concat());
+        assert_eq!(fs::read_dir(&options.directory).unwrap().count(), 0);
+        fs::remove_dir(&options.directory).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+/// Move the element at `node` down the max heap `slice[..end]` until it is not smaller than
+/// its children.
+fn sift_down<T, F>(slice: &mut [T], mut node: usize, end: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && compare(&slice[child], &slice[child + 1]) == Ordering::Less {
+            child += 1;
+        }
+        if compare(&slice[node], &slice[child]) != Ordering::Less {
+            return;
+        }
+        swap(slice, node, child);
+        node = child;
+    }
+}
+
+/// Sort a slice in increasing order with heap sort.
+///
+/// The slice is turned into a max heap in O(n), then the largest element is swapped to the
+/// end of the heap and the heap shrinks, n times. O(n log n) in every case, in place, not
+/// stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::heap_sort;
+/// fn main() {
+///     let mut values = [16, 4, 10, 14, 7, 9, 3, 2, 8, 1];
+///     heap_sort(&mut values);
+///     assert_eq!(values, [1, 2, 3, 4, 7, 8, 9, 10, 14, 16]);
+/// }
+/// ```
+///
+pub fn heap_sort<T: Ord>(slice: &mut [T]) {
+    heap_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with heap sort, ordering the elements with `compare`.
+pub fn heap_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    for node in (0..len / 2).rev() {
+        sift_down(slice, node, len, &mut compare);
+    }
+    for end in (1..len).rev() {
+        swap(slice, 0, end);
+        sift_down(slice, 0, end, &mut compare);
+    }
+}
+
+/// Sort a slice with heap sort, ordering the elements by the key `key` extracts.
+pub fn heap_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    heap_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod heap_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(false, |slice| heap_sort_by_key(slice, |pair| pair.0));
+    }
+}
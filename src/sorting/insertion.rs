@@ -0,0 +1,71 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+/// Sort a slice in increasing order with insertion sort.
+///
+/// Every element is moved left past the larger ones before it. O(n²) in general but O(n)
+/// on an almost sorted slice, and the fastest sort for a few elements. Stable, in place.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::insertion_sort;
+/// fn main() {
+///     let mut values = [5, 2, 4, 6, 1, 3];
+///     insertion_sort(&mut values);
+///     assert_eq!(values, [1, 2, 3, 4, 5, 6]);
+/// }
+/// ```
+///
+pub fn insertion_sort<T: Ord>(slice: &mut [T]) {
+    insertion_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with insertion sort, ordering the elements with `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::insertion_sort_by;
+/// fn main() {
+///     let mut values = [5, 2, 4, 6, 1, 3];
+///     insertion_sort_by(&mut values, |a, b| b.cmp(a));
+///     assert_eq!(values, [6, 5, 4, 3, 2, 1]);
+/// }
+/// ```
+///
+pub fn insertion_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 1..slice.len() {
+        let mut j = i;
+        while j > 0 && compare(&slice[j - 1], &slice[j]) == Ordering::Greater {
+            swap(slice, j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// Sort a slice with insertion sort, ordering the elements by the key `key` extracts.
+pub fn insertion_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod insertion_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| insertion_sort_by_key(slice, |pair| pair.0));
+    }
+}
@@ -0,0 +1,356 @@
+use super::heap::heap_sort_by;
+use super::insertion::insertion_sort_by;
+use super::stats::{reverse, swap};
+use crate::math::Rng;
+use std::cmp::Ordering;
+
+/// Slices at most this long are sorted by insertion sort.
+const INSERTION_THRESHOLD: usize = 20;
+/// From this length, the pivot is the median of three medians of three (Tukey's ninther).
+const NINTHER_THRESHOLD: usize = 50;
+/// The number of out of order pairs an almost sorted slice may have fixed by insertion.
+const MAX_INSERTION_STEPS: usize = 5;
+
+/// Returns true if `a` comes strictly before `b`.
+fn less<T, F>(compare: &mut F, a: &T, b: &T) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    compare(a, b) == Ordering::Less
+}
+
+/// Order the indices `a` and `b` by the elements they point to, counting the swaps.
+fn sort2<T, F>(slice: &[T], a: &mut usize, b: &mut usize, swaps: &mut usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if less(compare, &slice[*b], &slice[*a]) {
+        std::mem::swap(a, b);
+        *swaps += 1;
+    }
+}
+
+/// Order the indices `a`, `b` and `c` by the elements they point to, counting the swaps.
+fn sort3<T, F>(
+    slice: &[T],
+    a: &mut usize,
+    b: &mut usize,
+    c: &mut usize,
+    swaps: &mut usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sort2(slice, a, b, swaps, compare);
+    sort2(slice, b, c, swaps, compare);
+    sort2(slice, a, b, swaps, compare);
+}
+
+/// Returns the index of the median of `index` and its two neighbours.
+fn median_around<T, F>(slice: &[T], index: usize, swaps: &mut usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut before, mut middle, mut after) = (index - 1, index, index + 1);
+    sort3(slice, &mut before, &mut middle, &mut after, swaps, compare);
+    middle
+}
+
+/// Choose a pivot from elements spread over the slice.
+///
+/// The samples also tell the order of the slice: when none of them is out of order the slice
+/// is likely sorted, and when all of them are the slice is likely reversed, so it is reversed
+/// and the pivot index follows.
+///
+/// # Returns
+///
+/// * `(usize, bool)` - The index of the pivot, and whether the slice is likely sorted
+///
+fn choose_pivot<T, F>(slice: &mut [T], compare: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    let (mut a, mut b, mut c) = (len / 4, len / 4 * 2, len / 4 * 3);
+    let mut swaps = 0;
+    let max_swaps = if len >= NINTHER_THRESHOLD { 12 } else { 3 };
+    if len >= NINTHER_THRESHOLD {
+        a = median_around(slice, a, &mut swaps, compare);
+        b = median_around(slice, b, &mut swaps, compare);
+        c = median_around(slice, c, &mut swaps, compare);
+    }
+    sort3(slice, &mut a, &mut b, &mut c, &mut swaps, compare);
+    if swaps < max_swaps {
+        (b, swaps == 0)
+    } else {
+        reverse(slice);
+        (len - 1 - b, true)
+    }
+}
+
+/// Partition the slice around the element at `pivot`: the smaller elements before it, the
+/// others after it.
+///
+/// # Returns
+///
+/// * `(usize, bool)` - The final index of the pivot, and whether the slice was already partitioned
+///
+fn partition<T, F>(slice: &mut [T], pivot: usize, compare: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    swap(slice, 0, pivot);
+    let (head, rest) = slice.split_first_mut().unwrap();
+    let pivot = &*head;
+    let (mut left, mut right) = (0, rest.len());
+    while left < right && less(compare, &rest[left], pivot) {
+        left += 1;
+    }
+    while left < right && !less(compare, &rest[right - 1], pivot) {
+        right -= 1;
+    }
+    let was_partitioned = left >= right;
+    while left < right {
+        right -= 1;
+        swap(rest, left, right);
+        left += 1;
+        while left < right && less(compare, &rest[left], pivot) {
+            left += 1;
+        }
+        while left < right && !less(compare, &rest[right - 1], pivot) {
+            right -= 1;
+        }
+    }
+    swap(slice, 0, left);
+    (left, was_partitioned)
+}
+
+/// Move the elements equal to the element at `pivot` to the front, assuming none is smaller.
+///
+/// # Returns
+///
+/// * `usize` - The number of elements equal to the pivot, the pivot included
+///
+fn partition_equal<T, F>(slice: &mut [T], pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    swap(slice, 0, pivot);
+    let (head, rest) = slice.split_first_mut().unwrap();
+    let pivot = &*head;
+    let (mut left, mut right) = (0, rest.len());
+    loop {
+        while left < right && !less(compare, pivot, &rest[left]) {
+            left += 1;
+        }
+        while left < right && less(compare, pivot, &rest[right - 1]) {
+            right -= 1;
+        }
+        if left >= right {
+            return left + 1;
+        }
+        right -= 1;
+        swap(rest, left, right);
+        left += 1;
+    }
+}
+
+/// Try to sort an almost sorted slice by fixing a few out of order pairs.
+///
+/// # Returns
+///
+/// * `bool` - True if the slice is now sorted
+///
+fn partial_insertion_sort<T, F>(slice: &mut [T], compare: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    let mut i = 1;
+    for _ in 0..MAX_INSERTION_STEPS {
+        while i < len && !less(compare, &slice[i], &slice[i - 1]) {
+            i += 1;
+        }
+        if i == len {
+            return true;
+        }
+        swap(slice, i - 1, i);
+        // move the smaller element left and the larger one right to their places
+        let mut j = i - 1;
+        while j > 0 && less(compare, &slice[j], &slice[j - 1]) {
+            swap(slice, j, j - 1);
+            j -= 1;
+        }
+        let mut j = i;
+        while j + 1 < len && less(compare, &slice[j + 1], &slice[j]) {
+            swap(slice, j, j + 1);
+            j += 1;
+        }
+    }
+    false
+}
+
+/// Swap a few elements around the middle with random ones, to break the pattern that made
+/// the last partition unbalanced.
+fn break_patterns<T>(slice: &mut [T]) {
+    let len = slice.len();
+    let mut rng = Rng::new(len as u64);
+    let middle = len / 4 * 2;
+    for i in middle - 1..=middle + 1 {
+        swap(slice, i, rng.gen_range(0..len));
+    }
+}
+
+/// Sort `slice`, whose elements are all at least `predecessor` if any, allowing `limit`
+/// unbalanced partitions before falling back to heap sort.
+fn sort<'a, T, F>(
+    mut slice: &'a mut [T],
+    mut predecessor: Option<&'a T>,
+    mut limit: u32,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut was_balanced, mut was_partitioned) = (true, true);
+    loop {
+        let len = slice.len();
+        if len <= INSERTION_THRESHOLD {
+            insertion_sort_by(slice, &mut *compare);
+            return;
+        }
+        if limit == 0 {
+            heap_sort_by(slice, &mut *compare);
+            return;
+        }
+        if !was_balanced {
+            break_patterns(slice);
+            limit -= 1;
+        }
+        let (pivot, likely_sorted) = choose_pivot(slice, compare);
+        if was_balanced
+            && was_partitioned
+            && likely_sorted
+            && partial_insertion_sort(slice, compare)
+        {
+            return;
+        }
+        // a pivot equal to the predecessor is the smallest element: take all its copies at once
+        if predecessor.is_some_and(|predecessor| !less(compare, predecessor, &slice[pivot])) {
+            let equal = partition_equal(slice, pivot, compare);
+            slice = std::mem::take(&mut slice).split_at_mut(equal).1;
+            continue;
+        }
+        let (mid, already_partitioned) = partition(slice, pivot, compare);
+        was_balanced = mid.min(len - mid) >= len / 8;
+        was_partitioned = already_partitioned;
+        let (left, right) = std::mem::take(&mut slice).split_at_mut(mid);
+        let (pivot, right) = right.split_first_mut().unwrap();
+        let pivot: &'a T = pivot;
+        // recurse into the smaller side so the stack stays O(log n)
+        if left.len() < right.len() {
+            sort(left, predecessor, limit, compare);
+            slice = right;
+            predecessor = Some(pivot);
+        } else {
+            sort(right, Some(pivot), limit, compare);
+            slice = left;
+        }
+    }
+}
+
+/// Sort a slice in increasing order with introsort.
+///
+/// A quick sort that cannot go quadratic: after about log n unbalanced partitions it
+/// switches to heap sort, and short slices are left to insertion sort. The pivot is the
+/// median of three, or of nine elements on long slices, and the partitions defeat the usual
+/// patterns: sorted and reversed slices are detected and take O(n), runs of equal elements
+/// are taken at once, and unbalanced partitions shuffle a few elements before the next one.
+/// O(n log n), in place, not stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::introsort;
+/// fn main() {
+///     let mut values: Vec<u32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+///     introsort(&mut values);
+///     assert!(values.iter().enumerate().all(|(i, &v)| v == i as u32));
+/// }
+/// ```
+///
+pub fn introsort<T: Ord>(slice: &mut [T]) {
+    introsort_by(slice, T::cmp);
+}
+
+/// Sort a slice with introsort, ordering the elements with `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{count_comparisons, introsort_by};
+/// fn main() {
+///     // a sorted slice is recognised in linear time
+///     let mut values: Vec<u32> = (0..10_000).collect();
+///     let comparisons = count_comparisons(&mut values, |slice, compare| introsort_by(slice, compare));
+///     assert!(comparisons < 20_000);
+/// }
+/// ```
+///
+pub fn introsort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let limit = usize::BITS - slice.len().leading_zeros();
+    sort(slice, None, limit, &mut compare);
+}
+
+/// Sort a slice with introsort, ordering the elements by the key `key` extracts.
+pub fn introsort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    introsort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod introsort_tests {
+    use super::*;
+    use crate::sorting::{check_sort, count_comparisons};
+
+    #[test]
+    fn sorts() {
+        check_sort(false, |slice| introsort_by_key(slice, |pair| pair.0));
+        // straight to the heap sort fallback
+        check_sort(false, |slice| {
+            sort(slice, None, 0, &mut |a, b| a.0.cmp(&b.0))
+        });
+    }
+
+    #[test]
+    fn comparisons_against_std() {
+        let mut rng = Rng::new(42);
+        let n = 100_000;
+        let patterns: Vec<Vec<u64>> = vec![
+            (0..n).map(|_| rng.next_u64()).collect(),
+            (0..n).map(|_| rng.next_u64() % 16).collect(),
+            (0..n).collect(),
+            (0..n).rev().collect(),
+            (0..n).map(|i| i.min(n - i)).collect(),
+        ];
+        for values in patterns {
+            let mut expected = values.clone();
+            let std = count_comparisons(&mut expected, |slice, compare| {
+                slice.sort_unstable_by(compare)
+            });
+            let mut sorted = values;
+            let ours =
+                count_comparisons(&mut sorted, |slice, compare| introsort_by(slice, compare));
+            assert_eq!(sorted, expected);
+            assert!(ours <= std * 3 / 2 + n as usize, "{} against {}", ours, std);
+        }
+    }
+}
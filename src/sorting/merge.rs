@@ -0,0 +1,172 @@
+use super::insertion::insertion_sort_by;
+use super::stats::{counted, exchange, swap};
+use std::cmp::Ordering;
+
+/// Runs at most this long are sorted by insertion sort before merging.
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Merge the sorted runs `slice[..mid]` and `slice[mid..]`, keeping equal elements in order.
+///
+/// The left run is cloned into `buffer`; the elements are then moved back by swaps, so each
+/// element is cloned at most once.
+pub(super) fn merge<T: Clone, F>(slice: &mut [T], mid: usize, buffer: &mut Vec<T>, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if mid == 0 || mid == slice.len() || compare(&slice[mid - 1], &slice[mid]) != Ordering::Greater
+    {
+        return;
+    }
+    buffer.clear();
+    buffer.extend_from_slice(&slice[..mid]);
+    let (mut left, mut right, mut position) = (0, mid, 0);
+    // once the left run is used up, the rest of the right run is already in place
+    while left < buffer.len() {
+        if right < slice.len() && compare(&slice[right], &buffer[left]) == Ordering::Less {
+            swap(slice, position, right);
+            right += 1;
+        } else {
+            exchange(&mut slice[position], &mut buffer[left]);
+            left += 1;
+        }
+        position += 1;
+    }
+}
+
+/// Sort a slice in increasing order with top-down merge sort.
+///
+/// The slice is split in halves, each half is sorted recursively and the halves are merged;
+/// short runs are left to insertion sort. O(n log n), O(n) extra memory, stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::merge_sort;
+/// fn main() {
+///     let mut words = vec!["pear", "apple", "fig", "banana"];
+///     merge_sort(&mut words);
+///     assert_eq!(words, ["apple", "banana", "fig", "pear"]);
+/// }
+/// ```
+///
+pub fn merge_sort<T: Ord + Clone>(slice: &mut [T]) {
+    merge_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with top-down merge sort, ordering the elements with `compare`.
+pub fn merge_sort_by<T: Clone, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn sort<T: Clone, F>(slice: &mut [T], buffer: &mut Vec<T>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if slice.len() <= INSERTION_THRESHOLD {
+            insertion_sort_by(slice, &mut *compare);
+            return;
+        }
+        let mid = slice.len() / 2;
+        sort(&mut slice[..mid], buffer, compare);
+        sort(&mut slice[mid..], buffer, compare);
+        merge(slice, mid, buffer, compare);
+    }
+    let mut buffer = counted(Vec::with_capacity(slice.len() / 2));
+    sort(slice, &mut buffer, &mut compare);
+}
+
+/// Sort a slice with top-down merge sort, ordering the elements by the key `key` extracts.
+pub fn merge_sort_by_key<T: Clone, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sort a slice in increasing order with bottom-up merge sort.
+///
+/// Runs of 16 elements are sorted by insertion sort, then neighbouring runs are merged into
+/// runs twice as long until one is left; there is no recursion. O(n log n), O(n) extra
+/// memory, stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::bottom_up_merge_sort_by_key;
+/// fn main() {
+///     let mut people = vec![("Lea", 31), ("Tom", 25), ("Ana", 31), ("Max", 25)];
+///     bottom_up_merge_sort_by_key(&mut people, |person| person.1);
+///     // stable: equal ages keep their order
+///     assert_eq!(people, [("Tom", 25), ("Max", 25), ("Lea", 31), ("Ana", 31)]);
+/// }
+/// ```
+///
+pub fn bottom_up_merge_sort<T: Ord + Clone>(slice: &mut [T]) {
+    bottom_up_merge_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with bottom-up merge sort, ordering the elements with `compare`.
+pub fn bottom_up_merge_sort_by<T: Clone, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for run in slice.chunks_mut(INSERTION_THRESHOLD) {
+        insertion_sort_by(run, &mut compare);
+    }
+    // the buffer holds the left run of a merge, the widest one being the last
+    let mut widest = 0;
+    let mut width = INSERTION_THRESHOLD;
+    while width < slice.len() {
+        widest = width;
+        width *= 2;
+    }
+    let mut buffer = counted(Vec::with_capacity(widest));
+    let mut width = INSERTION_THRESHOLD;
+    while width < slice.len() {
+        for pair in slice.chunks_mut(2 * width) {
+            if pair.len() > width {
+                merge(pair, width, &mut buffer, &mut compare);
+            }
+        }
+        width *= 2;
+    }
+}
+
+/// Sort a slice with bottom-up merge sort, ordering the elements by the key `key` extracts.
+pub fn bottom_up_merge_sort_by_key<T: Clone, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    bottom_up_merge_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod merge_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| merge_sort_by_key(slice, |pair| pair.0));
+        check_sort(true, |slice| {
+            bottom_up_merge_sort_by_key(slice, |pair| pair.0)
+        });
+    }
+
+    #[test]
+    fn strings() {
+        let mut words: Vec<String> = (0..500).map(|i| format!("{}", (i * 7919) % 1000)).collect();
+        let mut expected = words.clone();
+        expected.sort();
+        merge_sort(&mut words);
+        assert_eq!(words, expected);
+    }
+}
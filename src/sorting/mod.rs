@@ -0,0 +1,195 @@
+//! Sorting algorithms over slices.
+//!
+//! Comparison sorts come in three forms, like `slice::sort`: `x_sort` for `Ord` elements,
+//! `x_sort_by` with a comparator and `x_sort_by_key` with a key extractor. Distribution sorts
+//! (counting, radix, bucket) order the elements by an integer or float key instead, so they
+//! come as `x_sort` and `x_sort_by_key`.
+//!
+//! | Algorithm | Time | Extra memory | Stable |
+//! |-----------|------|--------------|--------|
+//! | [`insertion_sort`] | O(n²) | O(1) | yes |
+//! | [`selection_sort`] | O(n²) | O(1) | no |
+//! | [`bubble_sort`] | O(n²) | O(1) | yes |
+//! | [`shell_sort`] | about O(n^4/3) | O(1) | no |
+//! | [`merge_sort`], [`bottom_up_merge_sort`] | O(n log n) | O(n) | yes |
+//! | [`quick_sort`] | O(n log n) expected, O(n²) worst | O(log n) | no |
+//! | [`heap_sort`] | O(n log n) | O(1) | no |
+//! | [`introsort()`] | O(n log n), O(n) on sorted input | O(log n) | no |
+//! | [`timsort()`] | O(n log n), O(n) on runs | O(n) | yes |
+//! | [`counting_sort`] | O(n + k) for k distinct key values | O(n + k) | yes |
+//! | [`radix_sort_lsd`], [`radix_sort_msd`] | O(n w) for w key bytes | O(n) | yes |
+//! | [`bucket_sort`] | O(n) on uniform keys | O(n) | yes |
+//! | [`network_sort`] | fixed networks for arrays of at most 16 elements | O(1) | no |
+//! | [`bitonic_sort`] | O(n log² n) on a power of two elements | O(1) | no |
+//!
+//! [`par_merge_sort`] and [`par_quick_sort`] spread merge sort and quick sort over several
+//! threads, with the same results, and [`external`] sorts inputs larger than memory.
+//! With the `stats` feature, `SortStats` measures the comparisons, swaps and allocations of
+//! a sort.
+
+pub mod bubble;
+pub mod bucket;
+pub mod counting;
+pub mod external;
+pub mod heap;
+pub mod insertion;
+pub mod introsort;
+pub mod merge;
+pub mod network;
+pub mod parallel;
+pub mod quick;
+pub mod radix;
+pub mod selection;
+pub mod shell;
+pub mod stats;
+pub mod timsort;
+
+#[doc(inline)]
+pub use self::bubble::{bubble_sort, bubble_sort_by, bubble_sort_by_key};
+#[doc(inline)]
+pub use self::bucket::{bucket_sort, bucket_sort_by_key};
+#[doc(inline)]
+pub use self::counting::{counting_sort, counting_sort_by_key};
+#[doc(inline)]
+pub use self::heap::{heap_sort, heap_sort_by, heap_sort_by_key};
+#[doc(inline)]
+pub use self::insertion::{insertion_sort, insertion_sort_by, insertion_sort_by_key};
+#[doc(inline)]
+pub use self::introsort::{introsort, introsort_by, introsort_by_key};
+#[doc(inline)]
+pub use self::merge::{
+    bottom_up_merge_sort, bottom_up_merge_sort_by, bottom_up_merge_sort_by_key, merge_sort,
+    merge_sort_by, merge_sort_by_key,
+};
+#[doc(inline)]
+pub use self::network::{
+    bitonic_sort, bitonic_sort_by, bitonic_sort_by_key, network_sort, network_sort_by,
+    network_sort_by_key, sorting_network,
+};
+#[doc(inline)]
+pub use self::parallel::{
+    par_merge_sort, par_merge_sort_by, par_merge_sort_by_key, par_quick_sort, par_quick_sort_by,
+    par_quick_sort_by_key, Parallelism,
+};
+#[doc(inline)]
+pub use self::quick::{
+    hoare_partition_by, lomuto_partition_by, quick_sort, quick_sort_by, quick_sort_by_key,
+    quick_sort_with, Partition,
+};
+#[doc(inline)]
+pub use self::radix::{
+    radix_sort_lsd, radix_sort_lsd_by_key, radix_sort_msd, radix_sort_msd_by_key, RadixKey,
+};
+#[doc(inline)]
+pub use self::selection::{selection_sort, selection_sort_by, selection_sort_by_key};
+#[doc(inline)]
+pub use self::shell::{shell_sort, shell_sort_by, shell_sort_by_key};
+#[cfg(any(test, feature = "stats"))]
+#[doc(inline)]
+pub use self::stats::SortStats;
+#[doc(inline)]
+pub use self::timsort::{timsort, timsort_by, timsort_by_key};
+
+use self::stats::{counted, swap};
+use std::cmp::Ordering;
+
+/// Count the comparisons a sort makes: `sort` is given the slice and a comparator that
+/// counts its calls, to pass to any `_by` sort.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+/// * `sort` - Sorts the slice with the comparator it is given
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{count_comparisons, insertion_sort_by};
+/// fn main() {
+///     let mut values = [1, 2, 3, 5, 4];
+///     let comparisons = count_comparisons(&mut values, |slice, compare| insertion_sort_by(slice, compare));
+///     assert_eq!(values, [1, 2, 3, 4, 5]);
+///     assert_eq!(comparisons, 5);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `usize` - The number of comparisons made
+///
+pub fn count_comparisons<T: Ord, S>(slice: &mut [T], sort: S) -> usize
+where
+    S: FnOnce(&mut [T], &mut dyn FnMut(&T, &T) -> Ordering),
+{
+    let mut comparisons = 0;
+    sort(slice, &mut |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    comparisons
+}
+
+/// Move every element of `slice` to its position in `destinations`, with swaps.
+///
+/// Distribution sorts work out where each element goes from its key, then use this to move
+/// the elements themselves without cloning them.
+fn apply_permutation<T>(slice: &mut [T], mut destinations: Vec<usize>) {
+    for i in 0..slice.len() {
+        while destinations[i] != i {
+            let destination = destinations[i];
+            swap(slice, i, destination);
+            destinations.swap(i, destination);
+        }
+    }
+}
+
+/// Returns the position of each element once ordered as listed in `order`.
+fn destinations(order: &[usize]) -> Vec<usize> {
+    let mut destinations = counted(vec![0; order.len()]);
+    for (position, &element) in order.iter().enumerate() {
+        destinations[element] = position;
+    }
+    destinations
+}
+
+/// Inputs covering the usual hard cases: empty, tiny, sorted, reversed, few distinct values,
+/// organ pipe and random.
+#[cfg(test)]
+fn test_inputs() -> Vec<Vec<i32>> {
+    let mut rng = crate::math::Rng::new(41);
+    let mut inputs = vec![vec![], vec![1], vec![2, 1], vec![1, 1, 1]];
+    for n in [10, 100, 1000] {
+        inputs.push((0..n).collect());
+        inputs.push((0..n).rev().collect());
+        inputs.push((0..n).map(|_| rng.gen_range(0..4) as i32).collect());
+        inputs.push((0..n).map(|i| i.min(n - i)).collect());
+        inputs.push((0..n).map(|_| rng.next_u64() as i32).collect());
+    }
+    inputs
+}
+
+/// Check that `sort` orders every test input like `slice::sort`, and that it keeps equal
+/// elements in order if `stable`.
+#[cfg(test)]
+fn check_sort(stable: bool, sort: impl Fn(&mut [(i32, usize)])) {
+    for input in test_inputs() {
+        // tag every value with its position, the key is the value only
+        let mut tagged: Vec<(i32, usize)> = input.iter().map(|&v| (v % 50, 0)).collect();
+        for (i, pair) in tagged.iter_mut().enumerate() {
+            pair.1 = i;
+        }
+        let mut expected = tagged.clone();
+        expected.sort_by_key(|pair| pair.0);
+        sort(&mut tagged);
+        if stable {
+            assert_eq!(tagged, expected);
+        } else {
+            let keys: Vec<i32> = tagged.iter().map(|pair| pair.0).collect();
+            let expected_keys: Vec<i32> = expected.iter().map(|pair| pair.0).collect();
+            assert_eq!(keys, expected_keys);
+            tagged.sort();
+            expected.sort();
+            assert_eq!(tagged, expected);
+        }
+    }
+}
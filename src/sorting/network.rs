@@ -0,0 +1,381 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+// Sorting networks of the smallest known size, as comparator pairs `(i, j)` with `i < j`,
+// one line per layer of independent comparators. Up to 8 inputs they are Batcher's and
+// Floyd's networks, from 9 to 14 the best found by search in the literature. The network for
+// 16 inputs is Green's: four layers of a hypercube, completed by 28 comparators found by a
+// beam search over the sets of 0-1 outputs; the network for 15 drops its last input. All of
+// them are checked on every 0-1 input by the tests.
+
+#[rustfmt::skip]
+const NETWORK_2: &[(usize, usize)] = &[
+    (0, 1),
+];
+
+#[rustfmt::skip]
+const NETWORK_3: &[(usize, usize)] = &[
+    (0, 2),
+    (0, 1),
+    (1, 2),
+];
+
+#[rustfmt::skip]
+const NETWORK_4: &[(usize, usize)] = &[
+    (0, 2), (1, 3),
+    (0, 1), (2, 3),
+    (1, 2),
+];
+
+#[rustfmt::skip]
+const NETWORK_5: &[(usize, usize)] = &[
+    (0, 3), (1, 4),
+    (0, 2), (1, 3),
+    (0, 1), (2, 4),
+    (1, 2), (3, 4),
+    (2, 3),
+];
+
+#[rustfmt::skip]
+const NETWORK_6: &[(usize, usize)] = &[
+    (0, 5), (1, 3), (2, 4),
+    (1, 2), (3, 4),
+    (0, 3), (2, 5),
+    (0, 1), (2, 3), (4, 5),
+    (1, 2), (3, 4),
+];
+
+#[rustfmt::skip]
+const NETWORK_7: &[(usize, usize)] = &[
+    (0, 6), (2, 3), (4, 5),
+    (0, 2), (1, 4), (3, 6),
+    (0, 1), (2, 5), (3, 4),
+    (1, 2), (4, 6),
+    (2, 3), (4, 5),
+    (1, 2), (3, 4), (5, 6),
+];
+
+#[rustfmt::skip]
+const NETWORK_8: &[(usize, usize)] = &[
+    (0, 2), (1, 3), (4, 6), (5, 7),
+    (0, 4), (1, 5), (2, 6), (3, 7),
+    (0, 1), (2, 3), (4, 5), (6, 7),
+    (2, 4), (3, 5),
+    (1, 4), (3, 6),
+    (1, 2), (3, 4), (5, 6),
+];
+
+#[rustfmt::skip]
+const NETWORK_9: &[(usize, usize)] = &[
+    (0, 3), (1, 7), (2, 5), (4, 8),
+    (0, 7), (2, 4), (3, 8), (5, 6),
+    (0, 2), (1, 3), (4, 5), (7, 8),
+    (1, 4), (3, 6), (5, 7),
+    (0, 1), (2, 4), (3, 5), (6, 8),
+    (2, 3), (4, 5), (6, 7),
+    (1, 2), (3, 4), (5, 6),
+];
+
+#[rustfmt::skip]
+const NETWORK_10: &[(usize, usize)] = &[
+    (0, 8), (1, 9), (2, 7), (3, 5), (4, 6),
+    (0, 2), (1, 4), (5, 8), (7, 9),
+    (0, 3), (2, 4), (5, 7), (6, 9),
+    (0, 1), (3, 6), (8, 9),
+    (1, 5), (2, 3), (4, 8), (6, 7),
+    (1, 2), (3, 5), (4, 6), (7, 8),
+    (2, 3), (4, 5), (6, 7),
+    (3, 4), (5, 6),
+];
+
+#[rustfmt::skip]
+const NETWORK_11: &[(usize, usize)] = &[
+    (0, 9), (1, 6), (2, 4), (3, 7), (5, 8),
+    (0, 1), (3, 5), (4, 10), (6, 9), (7, 8),
+    (1, 3), (2, 5), (4, 7), (8, 10),
+    (0, 4), (1, 2), (3, 7), (5, 9), (6, 8),
+    (0, 1), (2, 6), (4, 5), (7, 8), (9, 10),
+    (2, 4), (3, 6), (5, 7), (8, 9),
+    (1, 2), (3, 4), (5, 6), (7, 8),
+    (2, 3), (4, 5), (6, 7),
+];
+
+#[rustfmt::skip]
+const NETWORK_12: &[(usize, usize)] = &[
+    (0, 8), (1, 7), (2, 6), (3, 11), (4, 10), (5, 9),
+    (0, 1), (2, 5), (3, 4), (6, 9), (7, 8), (10, 11),
+    (0, 2), (1, 6), (5, 10), (9, 11),
+    (0, 3), (1, 2), (4, 6), (5, 7), (8, 11), (9, 10),
+    (1, 4), (3, 5), (6, 8), (7, 10),
+    (1, 3), (2, 5), (6, 9), (8, 10),
+    (2, 3), (4, 5), (6, 7), (8, 9),
+    (4, 6), (5, 7),
+    (3, 4), (5, 6), (7, 8),
+];
+
+#[rustfmt::skip]
+const NETWORK_13: &[(usize, usize)] = &[
+    (0, 12), (1, 10), (2, 9), (3, 7), (5, 11), (6, 8),
+    (1, 6), (2, 3), (4, 11), (7, 9), (8, 10),
+    (0, 4), (1, 2), (3, 6), (7, 8), (9, 10), (11, 12),
+    (4, 6), (5, 9), (8, 11), (10, 12),
+    (0, 5), (3, 8), (4, 7), (6, 11), (9, 10),
+    (0, 1), (2, 5), (6, 9), (7, 8), (10, 11),
+    (1, 3), (2, 4), (5, 6), (9, 10),
+    (1, 2), (3, 4), (5, 7), (6, 8),
+    (2, 3), (4, 5), (6, 7), (8, 9),
+    (3, 4), (5, 6),
+];
+
+#[rustfmt::skip]
+const NETWORK_14: &[(usize, usize)] = &[
+    (0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13),
+    (0, 2), (1, 3), (4, 8), (5, 9), (10, 12), (11, 13),
+    (0, 4), (1, 2), (3, 7), (5, 8), (6, 10), (9, 13), (11, 12),
+    (0, 6), (1, 5), (3, 9), (4, 10), (7, 13), (8, 12),
+    (2, 10), (3, 11), (4, 6), (7, 9),
+    (1, 3), (2, 8), (5, 11), (6, 7), (10, 12),
+    (1, 4), (2, 6), (3, 5), (7, 11), (8, 10), (9, 12),
+    (2, 4), (3, 6), (5, 8), (7, 10), (9, 11),
+    (3, 4), (5, 6), (7, 8), (9, 10),
+    (6, 7),
+];
+
+#[rustfmt::skip]
+const NETWORK_15: &[(usize, usize)] = &[
+    (0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13),
+    (0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11), (12, 14),
+    (0, 4), (1, 5), (2, 6), (3, 7), (8, 12), (9, 13), (10, 14),
+    (0, 8), (1, 9), (2, 10), (3, 11), (4, 12), (5, 13), (6, 14),
+    (1, 2), (3, 12), (4, 8), (5, 10), (6, 9), (7, 11), (13, 14),
+    (1, 4), (2, 8), (5, 6), (7, 13), (9, 10), (11, 14),
+    (2, 4), (3, 8), (7, 12), (11, 13),
+    (3, 5), (6, 8), (7, 9), (10, 12),
+    (3, 4), (5, 6), (9, 10), (11, 12),
+    (6, 7), (8, 9),
+    (7, 8),
+];
+
+#[rustfmt::skip]
+const NETWORK_16: &[(usize, usize)] = &[
+    (0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13), (14, 15),
+    (0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11), (12, 14), (13, 15),
+    (0, 4), (1, 5), (2, 6), (3, 7), (8, 12), (9, 13), (10, 14), (11, 15),
+    (0, 8), (1, 9), (2, 10), (3, 11), (4, 12), (5, 13), (6, 14), (7, 15),
+    (1, 2), (3, 12), (4, 8), (5, 10), (6, 9), (7, 11), (13, 14),
+    (1, 4), (2, 8), (5, 6), (7, 13), (9, 10), (11, 14),
+    (2, 4), (3, 8), (7, 12), (11, 13),
+    (3, 5), (6, 8), (7, 9), (10, 12),
+    (3, 4), (5, 6), (9, 10), (11, 12),
+    (6, 7), (8, 9),
+    (7, 8),
+];
+
+/// The networks by number of inputs; none is needed for 0 or 1 input.
+#[rustfmt::skip]
+const NETWORKS: [&[(usize, usize)]; 17] = [
+    &[], &[], NETWORK_2, NETWORK_3, NETWORK_4, NETWORK_5, NETWORK_6, NETWORK_7, NETWORK_8,
+    NETWORK_9, NETWORK_10, NETWORK_11, NETWORK_12, NETWORK_13, NETWORK_14, NETWORK_15,
+    NETWORK_16,
+];
+
+/// Returns the comparators of a sorting network for `n` inputs, n being at most 16.
+///
+/// Applying the comparators in order, each one swapping its two elements when the second is
+/// smaller, sorts any `n` elements. The networks have the smallest size known: 0, 0, 1, 3,
+/// 5, 9, 12, 16, 19, 25, 29, 35, 39, 45, 51, 56 and 60 comparators, proven optimal up to 12.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::sorting_network;
+/// fn main() {
+///     assert_eq!(sorting_network(4), [(0, 2), (1, 3), (0, 1), (2, 3), (1, 2)]);
+///     assert_eq!(sorting_network(16).len(), 60);
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `&'static [(usize, usize)]` - The comparators, as pairs of positions `(i, j)` with `i < j`
+///
+pub fn sorting_network(n: usize) -> &'static [(usize, usize)] {
+    assert!(n <= 16, "no sorting network for {} inputs, at most 16", n);
+    NETWORKS[n]
+}
+
+/// Sort an array of at most 16 elements with an optimal sorting network.
+///
+/// The comparisons do not depend on the elements, so the branches are predictable and the
+/// compiler can unroll the whole network for a fixed `N`. Not stable.
+///
+/// # Arguments
+///
+/// * `array` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::network_sort;
+/// fn main() {
+///     let mut values = [9, 3, 7, 1, 8, 2, 6, 4, 5];
+///     network_sort(&mut values);
+///     assert_eq!(values, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// }
+/// ```
+///
+pub fn network_sort<T: Ord, const N: usize>(array: &mut [T; N]) {
+    network_sort_by(array, T::cmp);
+}
+
+/// Sort an array of at most 16 elements with a sorting network, ordering the elements with
+/// `compare`.
+pub fn network_sort_by<T, F, const N: usize>(array: &mut [T; N], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    const { assert!(N <= 16, "sorting networks sort at most 16 elements") };
+    for &(i, j) in NETWORKS[N] {
+        if compare(&array[j], &array[i]) == Ordering::Less {
+            swap(array, i, j);
+        }
+    }
+}
+
+/// Sort an array of at most 16 elements with a sorting network, ordering the elements by the
+/// key `key` extracts.
+pub fn network_sort_by_key<T, K: Ord, F, const N: usize>(array: &mut [T; N], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    network_sort_by(array, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sort a slice whose length is a power of two in increasing order with bitonic sort.
+///
+/// Sorted blocks of doubling lengths are built by merging pairs of blocks sorted in opposite
+/// directions, which form a bitonic sequence. Every comparison is fixed in advance, so the
+/// sort is a sorting network of O(n log² n) comparators in O(log² n) layers, suited to
+/// parallel hardware. In place, not stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort, a power of two of them
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::bitonic_sort;
+/// fn main() {
+///     let mut values = [3, 7, 4, 8, 6, 2, 1, 5];
+///     bitonic_sort(&mut values);
+///     assert_eq!(values, [1, 2, 3, 4, 5, 6, 7, 8]);
+/// }
+/// ```
+///
+pub fn bitonic_sort<T: Ord>(slice: &mut [T]) {
+    bitonic_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice whose length is a power of two with bitonic sort, ordering the elements with
+/// `compare`.
+pub fn bitonic_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    assert!(
+        len == 0 || len.is_power_of_two(),
+        "bitonic sort needs a power of two elements, got {}",
+        len
+    );
+    // blocks of `block` elements are merged, alternately increasing and decreasing,
+    // comparing the elements `distance` apart
+    let mut block = 2;
+    while block <= len {
+        let mut distance = block / 2;
+        while distance > 0 {
+            for i in 0..len {
+                let j = i ^ distance;
+                if j > i {
+                    let increasing = i & block == 0;
+                    let order = compare(&slice[j], &slice[i]);
+                    if (increasing && order == Ordering::Less)
+                        || (!increasing && order == Ordering::Greater)
+                    {
+                        swap(slice, i, j);
+                    }
+                }
+            }
+            distance /= 2;
+        }
+        block *= 2;
+    }
+}
+
+/// Sort a slice whose length is a power of two with bitonic sort, ordering the elements by
+/// the key `key` extracts.
+pub fn bitonic_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    bitonic_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod network_tests {
+    use super::*;
+    use crate::math::Rng;
+
+    #[test]
+    fn networks_sort_every_zero_one_input() {
+        // a comparator network sorts every input if it sorts every input of 0s and 1s
+        for (n, network) in NETWORKS.iter().enumerate() {
+            assert!(network.iter().all(|&(i, j)| i < j && j < n));
+            for input in 0..1u32 << n {
+                let mut bits = input;
+                for &(i, j) in network.iter() {
+                    if bits >> i & 1 == 1 && bits >> j & 1 == 0 {
+                        bits ^= 1 << i | 1 << j;
+                    }
+                }
+                let ones = bits.count_ones();
+                assert_eq!(bits, ((1 << ones) - 1) << (n as u32 - ones), "{} inputs", n);
+            }
+        }
+    }
+
+    #[test]
+    fn sorts_arrays_and_slices() {
+        let mut rng = Rng::new(45);
+        for _ in 0..100 {
+            let mut array: [u64; 13] = std::array::from_fn(|_| rng.next_u64() % 10);
+            let mut expected = array;
+            expected.sort();
+            network_sort(&mut array);
+            assert_eq!(array, expected);
+
+            let mut values: Vec<(u64, usize)> = (0..1 << rng.gen_range(0..10))
+                .map(|i| (rng.next_u64() % 10, i))
+                .collect();
+            let mut expected = values.clone();
+            expected.sort_by_key(|pair| pair.0);
+            bitonic_sort_by_key(&mut values, |pair| pair.0);
+            assert!(values
+                .iter()
+                .map(|pair| pair.0)
+                .eq(expected.iter().map(|pair| pair.0)));
+            values.sort();
+            expected.sort();
+            assert_eq!(values, expected);
+        }
+        network_sort::<u8, 0>(&mut []);
+        network_sort(&mut [1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bitonic sort needs a power of two elements, got 6")]
+    fn bitonic_needs_a_power_of_two() {
+        bitonic_sort(&mut [1, 2, 3, 4, 5, 6]);
+    }
+}
@@ -0,0 +1,216 @@
+use super::merge::{merge, merge_sort_by};
+use super::quick::{hoare_partition_by, median_index, quick_sort_by};
+use super::stats::counted;
+use std::cmp::Ordering;
+use std::thread;
+
+/// How a parallel sort splits its work.
+///
+/// # Attributes
+///
+/// * `threads` - The number of threads sorting at once, the calling one included, at least 1
+/// * `cutoff` - Slices at most this long are sorted sequentially, as they are not worth a thread
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parallelism {
+    pub threads: usize,
+    pub cutoff: usize,
+}
+
+impl Default for Parallelism {
+    /// One thread per available core and a cutoff of 8192 elements.
+    fn default() -> Self {
+        Parallelism {
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+            cutoff: 1 << 13,
+        }
+    }
+}
+
+/// Sort a slice in increasing order with merge sort on several threads.
+///
+/// The halves are sorted on different threads, each half splitting again while threads are
+/// left and it is longer than the cutoff, then merged by the thread that split them. The
+/// result is the one of `merge_sort`, which is stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+/// * `parallelism` - The number of threads and the sequential cutoff
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{par_merge_sort, Parallelism};
+/// fn main() {
+///     let mut values: Vec<u64> = (0..100_000).map(|i| (i * 7919) % 100_000).collect();
+///     par_merge_sort(&mut values, Parallelism { threads: 4, cutoff: 1000 });
+///     assert!(values.iter().enumerate().all(|(i, &v)| v == i as u64));
+/// }
+/// ```
+///
+pub fn par_merge_sort<T: Ord + Clone + Send>(slice: &mut [T], parallelism: Parallelism) {
+    par_merge_sort_by(slice, parallelism, T::cmp);
+}
+
+/// Sort a slice with merge sort on several threads, ordering the elements with `compare`.
+pub fn par_merge_sort_by<T, F>(slice: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Clone + Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    fn sort<T, F>(slice: &mut [T], threads: usize, cutoff: usize, compare: &F)
+    where
+        T: Clone + Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        if threads == 1 || slice.len() <= cutoff {
+            merge_sort_by(slice, compare);
+            return;
+        }
+        let mid = slice.len() / 2;
+        let (left, right) = slice.split_at_mut(mid);
+        thread::scope(|scope| {
+            scope.spawn(|| sort(left, threads / 2, cutoff, compare));
+            sort(right, threads - threads / 2, cutoff, compare);
+        });
+        merge(
+            slice,
+            mid,
+            &mut counted(Vec::with_capacity(mid)),
+            &mut &*compare,
+        );
+    }
+    assert!(
+        parallelism.threads > 0,
+        "a parallel sort needs at least one thread"
+    );
+    sort(slice, parallelism.threads, parallelism.cutoff, &compare);
+}
+
+/// Sort a slice with merge sort on several threads, ordering the elements by the key `key`
+/// extracts.
+pub fn par_merge_sort_by_key<T, K: Ord, F>(slice: &mut [T], parallelism: Parallelism, key: F)
+where
+    T: Clone + Send,
+    F: Fn(&T) -> K + Sync,
+{
+    par_merge_sort_by(slice, parallelism, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sort a slice in increasing order with quick sort on several threads.
+///
+/// The slice is partitioned like `quick_sort` does, then the two sides are sorted on
+/// different threads, each side partitioning again while threads are left and it is longer
+/// than the cutoff. The partitions are the ones of `quick_sort`, so is the result. No merge
+/// and no extra memory, but an unlucky pivot leaves a thread with most of the work.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+/// * `parallelism` - The number of threads and the sequential cutoff
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{par_quick_sort, quick_sort, Parallelism};
+/// fn main() {
+///     let mut values: Vec<i64> = (0..100_000).map(|i| (i * 7919) % 1000 - 500).collect();
+///     let mut expected = values.clone();
+///     quick_sort(&mut expected);
+///     par_quick_sort(&mut values, Parallelism { threads: 4, cutoff: 1000 });
+///     assert_eq!(values, expected);
+/// }
+/// ```
+///
+pub fn par_quick_sort<T: Ord + Send>(slice: &mut [T], parallelism: Parallelism) {
+    par_quick_sort_by(slice, parallelism, T::cmp);
+}
+
+/// Sort a slice with quick sort on several threads, ordering the elements with `compare`.
+pub fn par_quick_sort_by<T, F>(slice: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    fn sort<T, F>(slice: &mut [T], threads: usize, cutoff: usize, compare: &F)
+    where
+        T: Send,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        if threads == 1 || slice.len() <= cutoff.max(1) {
+            quick_sort_by(slice, compare);
+            return;
+        }
+        let pivot = median_index(slice, &mut &*compare);
+        let position = hoare_partition_by(slice, pivot, &mut &*compare);
+        let (left, right) = slice.split_at_mut(position);
+        let right = &mut right[1..];
+        // the threads are shared in proportion to the work
+        let left_threads = (threads * left.len() / (left.len() + right.len()))
+            .max(1)
+            .min(threads - 1);
+        thread::scope(|scope| {
+            scope.spawn(|| sort(left, left_threads, cutoff, compare));
+            sort(right, threads - left_threads, cutoff, compare);
+        });
+    }
+    assert!(
+        parallelism.threads > 0,
+        "a parallel sort needs at least one thread"
+    );
+    sort(slice, parallelism.threads, parallelism.cutoff, &compare);
+}
+
+/// Sort a slice with quick sort on several threads, ordering the elements by the key `key`
+/// extracts.
+pub fn par_quick_sort_by_key<T, K: Ord, F>(slice: &mut [T], parallelism: Parallelism, key: F)
+where
+    T: Send,
+    F: Fn(&T) -> K + Sync,
+{
+    par_quick_sort_by(slice, parallelism, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod parallel_tests {
+    use super::*;
+    use crate::math::Rng;
+    use crate::sorting::{check_sort, merge_sort_by_key, quick_sort_by_key};
+
+    #[test]
+    fn sorts() {
+        for threads in [1, 2, 3, 8] {
+            let parallelism = Parallelism {
+                threads,
+                cutoff: 16,
+            };
+            check_sort(true, |slice| {
+                par_merge_sort_by_key(slice, parallelism, |pair| pair.0)
+            });
+            check_sort(false, |slice| {
+                par_quick_sort_by_key(slice, parallelism, |pair| pair.0)
+            });
+        }
+    }
+
+    #[test]
+    fn same_as_sequential() {
+        let mut rng = Rng::new(43);
+        let values: Vec<(u32, usize)> = (0..200_000)
+            .map(|i| ((rng.next_u64() % 1000) as u32, i))
+            .collect();
+        let parallelism = Parallelism {
+            threads: 6,
+            cutoff: 1000,
+        };
+        let (mut expected, mut sorted) = (values.clone(), values.clone());
+        merge_sort_by_key(&mut expected, |pair| pair.0);
+        par_merge_sort_by_key(&mut sorted, parallelism, |pair| pair.0);
+        assert_eq!(sorted, expected);
+        let (mut expected, mut sorted) = (values.clone(), values);
+        quick_sort_by_key(&mut expected, |pair| pair.0);
+        par_quick_sort_by_key(&mut sorted, parallelism, |pair| pair.0);
+        assert_eq!(sorted, expected);
+    }
+}
@@ -0,0 +1,265 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+/// The partition scheme of `quick_sort_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Partition {
+    /// One scan from the left, see `lomuto_partition_by`.
+    Lomuto,
+    /// Two scans meeting in the middle, see `hoare_partition_by`.
+    Hoare,
+}
+
+/// Partition a slice around the element at `pivot` with Lomuto's scheme.
+///
+/// The pivot is moved to the end, then a single scan moves every element smaller than the
+/// pivot to the front. Simple, but O(n²) for quick sort when many elements are equal.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to partition, not empty
+/// * `pivot` - The index of the pivot
+/// * `compare` - The order of the elements
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::lomuto_partition_by;
+/// fn main() {
+///     let mut values = [7, 2, 9, 4, 5, 1];
+///     let position = lomuto_partition_by(&mut values, 4, &mut |a: &i32, b: &i32| a.cmp(b));
+///     assert_eq!(values[position], 5);
+///     assert!(values[..position].iter().all(|&v| v < 5));
+///     assert!(values[position + 1..].iter().all(|&v| v >= 5));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `usize` - The final index of the pivot: the elements before it are smaller, the elements after it are not
+///
+pub fn lomuto_partition_by<T, F>(slice: &mut [T], pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = slice.len() - 1;
+    swap(slice, pivot, last);
+    let mut store = 0;
+    for i in 0..last {
+        if compare(&slice[i], &slice[last]) == Ordering::Less {
+            swap(slice, i, store);
+            store += 1;
+        }
+    }
+    swap(slice, store, last);
+    store
+}
+
+/// Partition a slice around the element at `pivot` with Hoare's scheme.
+///
+/// The pivot is moved to the front, then two scans move towards each other and swap the
+/// pairs of elements on the wrong side. Both scans stop on elements equal to the pivot, so
+/// equal elements are split evenly and quick sort stays O(n log n) on them.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to partition, not empty
+/// * `pivot` - The index of the pivot
+/// * `compare` - The order of the elements
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::hoare_partition_by;
+/// fn main() {
+///     let mut values = [3, 3, 1, 3, 5, 3];
+///     let position = hoare_partition_by(&mut values, 0, &mut |a: &i32, b: &i32| a.cmp(b));
+///     assert_eq!(values[position], 3);
+///     assert!(values[..position].iter().all(|&v| v <= 3));
+///     assert!(values[position + 1..].iter().all(|&v| v >= 3));
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `usize` - The final index of the pivot: the elements before it are not greater, the elements after it are not smaller
+///
+pub fn hoare_partition_by<T, F>(slice: &mut [T], pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    swap(slice, 0, pivot);
+    let (mut i, mut j) = (1, slice.len() - 1);
+    loop {
+        while i <= j && compare(&slice[i], &slice[0]) == Ordering::Less {
+            i += 1;
+        }
+        while i <= j && compare(&slice[j], &slice[0]) == Ordering::Greater {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        swap(slice, i, j);
+        i += 1;
+        j -= 1;
+    }
+    swap(slice, 0, j);
+    j
+}
+
+/// Returns the index of the median of the first, middle and last elements.
+pub(super) fn median_index<T, F>(slice: &[T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (a, b, c) = (0, slice.len() / 2, slice.len() - 1);
+    let less =
+        |x: usize, y: usize, compare: &mut F| compare(&slice[x], &slice[y]) == Ordering::Less;
+    if less(a, b, compare) {
+        if less(b, c, compare) {
+            b
+        } else if less(a, c, compare) {
+            c
+        } else {
+            a
+        }
+    } else if less(a, c, compare) {
+        a
+    } else if less(b, c, compare) {
+        c
+    } else {
+        b
+    }
+}
+
+/// Sort a slice in increasing order with quick sort, using Hoare's partition and the median
+/// of three elements as pivot.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::quick_sort;
+/// fn main() {
+///     let mut values = [9, -3, 5, 0, 5, 12, -8];
+///     quick_sort(&mut values);
+///     assert_eq!(values, [-8, -3, 0, 5, 5, 9, 12]);
+/// }
+/// ```
+///
+pub fn quick_sort<T: Ord>(slice: &mut [T]) {
+    quick_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with quick sort, ordering the elements with `compare`.
+pub fn quick_sort_by<T, F>(slice: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_with(slice, Partition::Hoare, true, compare);
+}
+
+/// Sort a slice with quick sort, ordering the elements by the key `key` extracts.
+pub fn quick_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    quick_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sort a slice with quick sort, choosing the partition scheme and the pivot.
+///
+/// The slice is partitioned around a pivot and both sides are sorted the same way. The
+/// smaller side is sorted first by recursion and the larger one by a loop, so the call stack
+/// stays O(log n). O(n log n) expected; without the median of three the pivot is the first
+/// element for Hoare and the last for Lomuto, and a sorted slice takes O(n²). In place, not
+/// stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+/// * `partition` - The partition scheme
+/// * `median_of_three` - Whether the pivot is the median of the first, middle and last elements
+/// * `compare` - The order of the elements
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{quick_sort_with, Partition};
+/// fn main() {
+///     let mut values = [4, 1, 3, 1, 2];
+///     quick_sort_with(&mut values, Partition::Lomuto, false, |a, b| a.cmp(b));
+///     assert_eq!(values, [1, 1, 2, 3, 4]);
+/// }
+/// ```
+///
+pub fn quick_sort_with<T, F>(
+    slice: &mut [T],
+    partition: Partition,
+    median_of_three: bool,
+    mut compare: F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn sort<T, F>(mut slice: &mut [T], partition: Partition, median: bool, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        while slice.len() > 1 {
+            let pivot = match (median, partition) {
+                (true, _) => median_index(slice, compare),
+                (false, Partition::Lomuto) => slice.len() - 1,
+                (false, Partition::Hoare) => 0,
+            };
+            let position = match partition {
+                Partition::Lomuto => lomuto_partition_by(slice, pivot, compare),
+                Partition::Hoare => hoare_partition_by(slice, pivot, compare),
+            };
+            let (left, right) = std::mem::take(&mut slice).split_at_mut(position);
+            let right = &mut right[1..];
+            if left.len() < right.len() {
+                sort(left, partition, median, compare);
+                slice = right;
+            } else {
+                sort(right, partition, median, compare);
+                slice = left;
+            }
+        }
+    }
+    sort(slice, partition, median_of_three, &mut compare);
+}
+
+#[cfg(test)]
+mod quick_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(false, |slice| quick_sort_by_key(slice, |pair| pair.0));
+        for partition in [Partition::Lomuto, Partition::Hoare] {
+            for median in [false, true] {
+                check_sort(false, |slice| {
+                    quick_sort_with(slice, partition, median, |a, b| a.0.cmp(&b.0))
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn median_of_three_on_sorted_input() {
+        // without it, a sorted slice takes n²/2 comparisons
+        let mut values: Vec<u32> = (0..2000).collect();
+        let mut comparisons = 0;
+        quick_sort_by(&mut values, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        assert!(comparisons < 50_000);
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -0,0 +1,186 @@
+use super::stats::counted;
+use super::{apply_permutation, destinations};
+
+/// A key radix sorts can order byte by byte.
+///
+/// `radix_key` maps the key to a `u64` whose order is the order of the keys, so the sorts only
+/// deal with unsigned integers: signed integers have their sign bit flipped.
+pub trait RadixKey: Copy {
+    /// Returns the key as an unsigned integer with the same order.
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! unsigned_radix_key {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        })*
+    };
+}
+
+macro_rules! signed_radix_key {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(self) -> u64 {
+                (self as i64 as u64) ^ (1 << 63)
+            }
+        })*
+    };
+}
+
+unsigned_radix_key!(u8, u16, u32, u64, usize, char, bool);
+signed_radix_key!(i8, i16, i32, i64, isize);
+
+/// Returns the byte of `key` at `shift` bits.
+fn digit(key: u64, shift: u32) -> usize {
+    ((key >> shift) & 0xff) as usize
+}
+
+/// Sort a slice in increasing order with a least significant digit radix sort.
+///
+/// The elements are distributed by their lowest key byte, then by the next one and so on,
+/// each pass keeping the order of the previous one. A pass is skipped when all the keys share
+/// the byte, so small keys take few passes. O(n w) for keys of w bytes, stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::radix_sort_lsd;
+/// fn main() {
+///     let mut values = [170, -45, 75, -90, 802, 24, 2, 66];
+///     radix_sort_lsd(&mut values);
+///     assert_eq!(values, [-90, -45, 2, 24, 66, 75, 170, 802]);
+/// }
+/// ```
+///
+pub fn radix_sort_lsd<T: RadixKey>(slice: &mut [T]) {
+    radix_sort_lsd_by_key(slice, |&value| value);
+}
+
+/// Sort a slice with a least significant digit radix sort, ordering the elements by the key
+/// `key` extracts.
+pub fn radix_sort_lsd_by_key<T, K: RadixKey, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<u64> = counted(slice.iter().map(|value| key(value).radix_key()).collect());
+    let mut order: Vec<usize> = counted((0..slice.len()).collect());
+    let mut buffer = counted(vec![0; slice.len()]);
+    for shift in (0..64).step_by(8) {
+        let mut counts = [0; 257];
+        for &k in &keys {
+            counts[digit(k, shift) + 1] += 1;
+        }
+        if counts.contains(&slice.len()) {
+            continue;
+        }
+        for byte in 0..256 {
+            counts[byte + 1] += counts[byte];
+        }
+        for &element in &order {
+            let byte = digit(keys[element], shift);
+            buffer[counts[byte]] = element;
+            counts[byte] += 1;
+        }
+        std::mem::swap(&mut order, &mut buffer);
+    }
+    apply_permutation(slice, destinations(&order));
+}
+
+/// Sort a slice in increasing order with a most significant digit radix sort.
+///
+/// The elements are distributed by their highest key byte, then each group is sorted the same
+/// way by the next byte. Groups of a single element are done, so keys that differ early take
+/// few passes. O(n w) for keys of w bytes, stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::radix_sort_msd;
+/// fn main() {
+///     let mut letters: Vec<char> = "radix".chars().collect();
+///     radix_sort_msd(&mut letters);
+///     assert_eq!(letters, ['a', 'd', 'i', 'r', 'x']);
+/// }
+/// ```
+///
+pub fn radix_sort_msd<T: RadixKey>(slice: &mut [T]) {
+    radix_sort_msd_by_key(slice, |&value| value);
+}
+
+/// Sort a slice with a most significant digit radix sort, ordering the elements by the key
+/// `key` extracts.
+pub fn radix_sort_msd_by_key<T, K: RadixKey, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    fn sort(order: &mut [usize], keys: &[u64], shift: u32, buffer: &mut [usize]) {
+        let mut counts = [0; 257];
+        for &element in order.iter() {
+            counts[digit(keys[element], shift) + 1] += 1;
+        }
+        for byte in 0..256 {
+            counts[byte + 1] += counts[byte];
+        }
+        let starts = counts;
+        for &element in order.iter() {
+            let byte = digit(keys[element], shift);
+            buffer[counts[byte]] = element;
+            counts[byte] += 1;
+        }
+        order.copy_from_slice(&buffer[..order.len()]);
+        if shift == 0 {
+            return;
+        }
+        for byte in 0..256 {
+            let group = starts[byte]..starts[byte + 1];
+            if group.len() > 1 {
+                sort(
+                    &mut order[group.clone()],
+                    keys,
+                    shift - 8,
+                    &mut buffer[group],
+                );
+            }
+        }
+    }
+    let keys: Vec<u64> = counted(slice.iter().map(|value| key(value).radix_key()).collect());
+    let mut order: Vec<usize> = counted((0..slice.len()).collect());
+    let mut buffer = counted(vec![0; slice.len()]);
+    if slice.len() > 1 {
+        sort(&mut order, &keys, 56, &mut buffer);
+    }
+    apply_permutation(slice, destinations(&order));
+}
+
+#[cfg(test)]
+mod radix_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| radix_sort_lsd_by_key(slice, |pair| pair.0));
+        check_sort(true, |slice| radix_sort_msd_by_key(slice, |pair| pair.0));
+    }
+
+    #[test]
+    fn key_order() {
+        let mut values = [i64::MAX, 0, -1, i64::MIN, 1, i64::MIN + 1];
+        radix_sort_msd(&mut values);
+        assert_eq!(values, [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX]);
+        let mut values = [u64::MAX, 0, 1 << 63, 255, 256];
+        radix_sort_lsd(&mut values);
+        assert_eq!(values, [0, 255, 256, 1 << 63, u64::MAX]);
+    }
+}
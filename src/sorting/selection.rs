@@ -0,0 +1,63 @@
+use super::stats::swap;
+use std::cmp::Ordering;
+
+/// Sort a slice in increasing order with selection sort.
+///
+/// The smallest element left is swapped to the front, n times. Always O(n²) comparisons
+/// but only n - 1 swaps, which helps when moving elements is expensive. In place, not stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::selection_sort;
+/// fn main() {
+///     let mut values = ['d', 'a', 'c', 'b'];
+///     selection_sort(&mut values);
+///     assert_eq!(values, ['a', 'b', 'c', 'd']);
+/// }
+/// ```
+///
+pub fn selection_sort<T: Ord>(slice: &mut [T]) {
+    selection_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with selection sort, ordering the elements with `compare`.
+pub fn selection_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 0..slice.len() {
+        let mut smallest = i;
+        for j in i + 1..slice.len() {
+            if compare(&slice[j], &slice[smallest]) == Ordering::Less {
+                smallest = j;
+            }
+        }
+        if smallest != i {
+            swap(slice, i, smallest);
+        }
+    }
+}
+
+/// Sort a slice with selection sort, ordering the elements by the key `key` extracts.
+pub fn selection_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    selection_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod selection_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(false, |slice| selection_sort_by_key(slice, |pair| pair.0));
+    }
+}
@@ -0,0 +1,77 @@
+use super::stats::{counted, swap};
+use std::cmp::Ordering;
+
+/// Gaps of Ciura's sequence, extended by a factor 2.25 beyond 701.
+fn gaps(len: usize) -> Vec<usize> {
+    // the gaps grow geometrically, so there are fewer than 64 of them
+    let mut gaps = counted(Vec::with_capacity(64));
+    gaps.extend_from_slice(&[1, 4, 10, 23, 57, 132, 301, 701]);
+    while *gaps.last().unwrap() < len {
+        let next = *gaps.last().unwrap() * 9 / 4;
+        gaps.push(next);
+    }
+    gaps.retain(|&gap| gap < len.max(2));
+    gaps.reverse();
+    gaps
+}
+
+/// Sort a slice in increasing order with Shell sort.
+///
+/// An insertion sort over the elements `gap` apart, for a decreasing sequence of gaps
+/// ending with 1 (Ciura's), so elements travel far in few moves. About O(n^4/3) with these
+/// gaps. In place, not stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::shell_sort;
+/// fn main() {
+///     let mut values: Vec<u32> = (0..100).rev().collect();
+///     shell_sort(&mut values);
+///     assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+/// }
+/// ```
+///
+pub fn shell_sort<T: Ord>(slice: &mut [T]) {
+    shell_sort_by(slice, T::cmp);
+}
+
+/// Sort a slice with Shell sort, ordering the elements with `compare`.
+pub fn shell_sort_by<T, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for gap in gaps(slice.len()) {
+        for i in gap..slice.len() {
+            let mut j = i;
+            while j >= gap && compare(&slice[j - gap], &slice[j]) == Ordering::Greater {
+                swap(slice, j - gap, j);
+                j -= gap;
+            }
+        }
+    }
+}
+
+/// Sort a slice with Shell sort, ordering the elements by the key `key` extracts.
+pub fn shell_sort_by_key<T, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    shell_sort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod shell_tests {
+    use super::*;
+    use crate::sorting::check_sort;
+
+    #[test]
+    fn sorts() {
+        check_sort(false, |slice| shell_sort_by_key(slice, |pair| pair.0));
+        assert_eq!(gaps(1000), vec![701, 301, 132, 57, 23, 10, 4, 1]);
+    }
+}
@@ -0,0 +1,176 @@
+//! Counters of the swaps and allocations of the sorts, for `SortStats`.
+//!
+//! They are only kept with the `stats` feature (and in the tests of this crate); otherwise
+//! the helpers below do no more than the operation they wrap.
+
+#[cfg(any(test, feature = "stats"))]
+use std::cell::Cell;
+#[cfg(any(test, feature = "stats"))]
+use std::cmp::Ordering;
+
+#[cfg(any(test, feature = "stats"))]
+thread_local! {
+    static SWAPS: Cell<usize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Record `count` exchanges of two elements.
+#[inline]
+pub(super) fn record_swaps(count: usize) {
+    #[cfg(any(test, feature = "stats"))]
+    SWAPS.with(|swaps| swaps.set(swaps.get() + count));
+    #[cfg(not(any(test, feature = "stats")))]
+    let _ = count;
+}
+
+/// Swap the elements at `a` and `b`, recording the swap.
+#[inline]
+pub(super) fn swap<T>(slice: &mut [T], a: usize, b: usize) {
+    record_swaps(1);
+    slice.swap(a, b);
+}
+
+/// Swap two elements held apart, such as one in a slice and one in a buffer, recording the swap.
+#[inline]
+pub(super) fn exchange<T>(a: &mut T, b: &mut T) {
+    record_swaps(1);
+    std::mem::swap(a, b);
+}
+
+/// Reverse a slice, recording a swap per pair of elements exchanged.
+#[inline]
+pub(super) fn reverse<T>(slice: &mut [T]) {
+    record_swaps(slice.len() / 2);
+    slice.reverse();
+}
+
+/// Record the allocation of a buffer, if it holds memory.
+#[inline]
+pub(super) fn counted<T>(buffer: Vec<T>) -> Vec<T> {
+    #[cfg(any(test, feature = "stats"))]
+    if buffer.capacity() > 0 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+    }
+    buffer
+}
+
+/// Run `f` without recording the swaps it makes, such as swaps of indices standing for the
+/// elements.
+#[inline]
+pub(super) fn unrecorded<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(any(test, feature = "stats"))]
+    let swaps = SWAPS.with(Cell::get);
+    let result = f();
+    #[cfg(any(test, feature = "stats"))]
+    SWAPS.with(|counter| counter.set(swaps));
+    result
+}
+
+/// The work a sort does.
+///
+/// With the `stats` feature, the sorts of this module record the elements they swap and the
+/// buffers they allocate on the thread they run on; a shift of k places, as insertion makes,
+/// counts as k swaps. The work of parallel sorts on other threads is not recorded.
+///
+/// # Attributes
+///
+/// * `comparisons` - The number of calls to the comparator
+/// * `swaps` - The number of exchanges of two elements
+/// * `allocations` - The number of buffers allocated, elements, keys or indices
+///
+#[cfg(any(test, feature = "stats"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortStats {
+    pub comparisons: usize,
+    pub swaps: usize,
+    pub allocations: usize,
+}
+
+#[cfg(any(test, feature = "stats"))]
+impl SortStats {
+    /// Measure a sort of this module: `sort` is given the slice and a comparator that counts
+    /// its calls, to pass to any `_by` sort.
+    ///
+    /// # Arguments
+    ///
+    /// * `slice` - The elements to sort
+    /// * `sort` - Sorts the slice with the comparator it is given
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_algo::sorting::{heap_sort_by, merge_sort_by, SortStats};
+    /// fn main() {
+    ///     let mut values: Vec<u32> = (0..64).map(|i| (i * 37) % 64).collect();
+    ///     let heap = SortStats::measure(&mut values.clone(), |slice, compare| heap_sort_by(slice, compare));
+    ///     let merge = SortStats::measure(&mut values, |slice, compare| merge_sort_by(slice, compare));
+    ///     assert_eq!(heap.allocations, 0);
+    ///     assert_eq!(merge.allocations, 1);
+    ///     assert!(merge.comparisons < heap.comparisons);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// * `SortStats` - The comparisons, swaps and allocations the sort made
+    ///
+    pub fn measure<T: Ord, S>(slice: &mut [T], sort: S) -> SortStats
+    where
+        S: FnOnce(&mut [T], &mut dyn FnMut(&T, &T) -> Ordering),
+    {
+        let swaps = SWAPS.with(Cell::get);
+        let allocations = ALLOCATIONS.with(Cell::get);
+        let mut comparisons = 0;
+        sort(slice, &mut |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        SortStats {
+            comparisons,
+            swaps: SWAPS.with(Cell::get) - swaps,
+            allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+    use crate::sorting::*;
+
+    type Sort = fn(&mut [u32], &mut dyn FnMut(&u32, &u32) -> Ordering);
+
+    #[test]
+    fn swaps_and_allocations() {
+        let reversed: Vec<u32> = (0..100).rev().collect();
+        let measure = |sort: Sort| SortStats::measure(&mut reversed.clone(), sort);
+        // insertion and bubble sort swap once per inversion
+        let inversions = 100 * 99 / 2;
+        let insertion = measure(|slice, compare| insertion_sort_by(slice, compare));
+        assert_eq!((insertion.swaps, insertion.allocations), (inversions, 0));
+        assert_eq!(
+            measure(|slice, compare| bubble_sort_by(slice, compare)).swaps,
+            inversions
+        );
+        // selection sort swaps at most once per position, here for half of them
+        assert_eq!(
+            measure(|slice, compare| selection_sort_by(slice, compare)).swaps,
+            50
+        );
+        // TimSort reverses a descending run in place, only the run stack is allocated
+        let timsort = measure(|slice, compare| timsort_by(slice, compare));
+        assert_eq!(
+            (timsort.comparisons, timsort.swaps, timsort.allocations),
+            (99, 50, 1)
+        );
+        // keys, indices, an index buffer and the destinations
+        let mut values = reversed.clone();
+        let radix = SortStats::measure(&mut values, |slice, _| radix_sort_lsd(slice));
+        assert_eq!((radix.comparisons, radix.allocations), (0, 4));
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+        assert_eq!(
+            SortStats::measure(&mut Vec::<u32>::new(), |slice, _| radix_sort_lsd(slice)),
+            SortStats::default()
+        );
+    }
+}
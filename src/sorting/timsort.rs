@@ -0,0 +1,441 @@
+use super::stats::{counted, exchange, record_swaps, reverse, swap};
+use std::cmp::Ordering;
+
+/// Slices shorter than this are sorted by binary insertion sort alone.
+const MIN_MERGE: usize = 32;
+/// The number of wins in a row after which a merge starts galloping.
+const MIN_GALLOP: usize = 7;
+
+/// Returns the minimum run length for a slice of `len` elements: between 16 and 32, so that
+/// `len / min_run` is a power of two or just below one and the merges stay balanced.
+fn min_run_length(mut len: usize) -> usize {
+    let mut odd = 0;
+    while len >= MIN_MERGE {
+        odd |= len & 1;
+        len >>= 1;
+    }
+    len + odd
+}
+
+/// Returns the length of the run at the start of the slice, reversing it if it is strictly
+/// descending. Equal elements never extend a descending run, which keeps the sort stable.
+fn count_run<T, F>(slice: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if slice.len() < 2 {
+        return slice.len();
+    }
+    let mut end = 2;
+    if compare(&slice[1], &slice[0]) == Ordering::Less {
+        while end < slice.len() && compare(&slice[end], &slice[end - 1]) == Ordering::Less {
+            end += 1;
+        }
+        reverse(&mut slice[..end]);
+    } else {
+        while end < slice.len() && compare(&slice[end], &slice[end - 1]) != Ordering::Less {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// Sort a slice whose first `sorted` elements are sorted, inserting the others after the
+/// equal elements found by binary search.
+fn binary_insertion_sort<T, F>(slice: &mut [T], sorted: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in sorted.max(1)..slice.len() {
+        let (before, rest) = slice.split_at(i);
+        let position = before.partition_point(|x| compare(x, &rest[0]) != Ordering::Greater);
+        record_swaps(i - position);
+        slice[position..=i].rotate_right(1);
+    }
+}
+
+/// Find the first element of a sorted run that is not `before`, searching from `hint` with
+/// steps doubling in length, then by binary search.
+///
+/// The search takes O(log d) comparisons for an answer at distance d from the hint, where a
+/// binary search takes O(log n).
+fn gallop<T>(run: &[T], hint: usize, mut before: impl FnMut(&T) -> bool) -> usize {
+    let (low, high);
+    let mut offset = 1;
+    if before(&run[hint]) {
+        let mut last = hint;
+        while hint + offset < run.len() && before(&run[hint + offset]) {
+            last = hint + offset;
+            offset *= 2;
+        }
+        (low, high) = (last + 1, run.len().min(hint + offset));
+    } else {
+        let mut last = hint;
+        while offset <= hint && !before(&run[hint - offset]) {
+            last = hint - offset;
+            offset *= 2;
+        }
+        (low, high) = (if offset <= hint { hint - offset + 1 } else { 0 }, last);
+    }
+    low + run[low..high].partition_point(before)
+}
+
+/// The state of a sort: the runs waiting to be merged and the merge buffer.
+struct TimSort<'a, T, F> {
+    compare: &'a mut F,
+    /// The start and length of each pending run, the last one found at the end.
+    runs: Vec<(usize, usize)>,
+    buffer: Vec<T>,
+    /// Wins in a row before galloping: lowered when galloping pays, raised when it does not.
+    min_gallop: usize,
+}
+
+impl<T: Clone, F> TimSort<'_, T, F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    /// Merge the runs at the top of the stack until their lengths decrease faster than the
+    /// Fibonacci numbers, which bounds the stack to O(log n) runs.
+    fn merge_collapse(&mut self, slice: &mut [T]) {
+        while self.runs.len() > 1 {
+            let n = self.runs.len();
+            let len = |i: usize| self.runs[i].1;
+            let mut at = n - 2;
+            if (n >= 3 && len(n - 3) <= len(n - 2) + len(n - 1))
+                || (n >= 4 && len(n - 4) <= len(n - 3) + len(n - 2))
+            {
+                if len(n - 3) < len(n - 1) {
+                    at = n - 3;
+                }
+            } else if len(n - 2) > len(n - 1) {
+                return;
+            }
+            self.merge_at(slice, at);
+        }
+    }
+
+    /// Merge all the runs left once the whole slice is split in runs.
+    fn merge_force_collapse(&mut self, slice: &mut [T]) {
+        while self.runs.len() > 1 {
+            let n = self.runs.len();
+            let at = if n >= 3 && self.runs[n - 3].1 < self.runs[n - 1].1 {
+                n - 3
+            } else {
+                n - 2
+            };
+            self.merge_at(slice, at);
+        }
+    }
+
+    /// Merge the runs `at` and `at + 1` of the stack.
+    fn merge_at(&mut self, slice: &mut [T], at: usize) {
+        if self.buffer.capacity() == 0 {
+            // sorted or reversed slices never merge, and never allocate the buffer
+            self.buffer = counted(Vec::with_capacity(slice.len() / 2));
+        }
+        let (start, left) = self.runs[at];
+        let right = self.runs.remove(at + 1).1;
+        self.runs[at].1 = left + right;
+        let compare = &mut *self.compare;
+        let run = &mut slice[start..start + left + right];
+        // the left elements not greater than the first right one are in place already
+        let skipped = gallop(&run[..left], 0, |x| {
+            compare(x, &run[left]) != Ordering::Greater
+        });
+        let (run, left) = (&mut run[skipped..], left - skipped);
+        if left == 0 {
+            return;
+        }
+        // as are the right elements not smaller than the last left one
+        let right = gallop(&run[left..], right - 1, |x| {
+            compare(x, &run[left - 1]) == Ordering::Less
+        });
+        let run = &mut run[..left + right];
+        if left <= right {
+            self.merge_low(run, left);
+        } else {
+            self.merge_high(run, left);
+        }
+    }
+
+    /// Merge `run[..mid]` and `run[mid..]` from the front, the left run being the shorter
+    /// one and moved to the buffer.
+    fn merge_low(&mut self, run: &mut [T], mid: usize) {
+        let Self {
+            compare,
+            buffer,
+            min_gallop,
+            ..
+        } = self;
+        buffer.clear();
+        buffer.extend_from_slice(&run[..mid]);
+        let len = run.len();
+        // the next left element is buffer[left], the next right one run[right], and the next
+        // free place run[position]; left elements go first among equal ones
+        let (mut left, mut right, mut position) = (0, mid, 0);
+        'merge: loop {
+            let (mut left_wins, mut right_wins) = (0, 0);
+            while left_wins.max(right_wins) < *min_gallop {
+                if compare(&run[right], &buffer[left]) == Ordering::Less {
+                    swap(run, position, right);
+                    right += 1;
+                    right_wins += 1;
+                    left_wins = 0;
+                    position += 1;
+                    if right == len {
+                        break 'merge;
+                    }
+                } else {
+                    exchange(&mut run[position], &mut buffer[left]);
+                    left += 1;
+                    left_wins += 1;
+                    right_wins = 0;
+                    position += 1;
+                    if left == mid {
+                        break 'merge;
+                    }
+                }
+            }
+            loop {
+                let left_count = gallop(&buffer[left..], 0, |x| {
+                    compare(x, &run[right]) != Ordering::Greater
+                });
+                for _ in 0..left_count {
+                    exchange(&mut run[position], &mut buffer[left]);
+                    left += 1;
+                    position += 1;
+                }
+                if left == mid {
+                    break 'merge;
+                }
+                let right_count = gallop(&run[right..], 0, |x| {
+                    compare(x, &buffer[left]) == Ordering::Less
+                });
+                for _ in 0..right_count {
+                    swap(run, position, right);
+                    right += 1;
+                    position += 1;
+                }
+                if right == len {
+                    break 'merge;
+                }
+                if left_count < MIN_GALLOP && right_count < MIN_GALLOP {
+                    *min_gallop += 1;
+                    break;
+                }
+                *min_gallop = min_gallop.saturating_sub(1).max(1);
+            }
+        }
+        // the right elements left are in place, the left ones are still in the buffer
+        while left < mid {
+            exchange(&mut run[position], &mut buffer[left]);
+            left += 1;
+            position += 1;
+        }
+    }
+
+    /// Merge `run[..mid]` and `run[mid..]` from the back, the right run being the shorter
+    /// one and moved to the buffer.
+    fn merge_high(&mut self, run: &mut [T], mid: usize) {
+        let Self {
+            compare,
+            buffer,
+            min_gallop,
+            ..
+        } = self;
+        buffer.clear();
+        buffer.extend_from_slice(&run[mid..]);
+        // the left elements still to place are run[..left], the right ones buffer[..right],
+        // and the last free place is run[position - 1]; right elements go last among equal ones
+        let (mut left, mut right, mut position) = (mid, buffer.len(), run.len());
+        'merge: loop {
+            let (mut left_wins, mut right_wins) = (0, 0);
+            while left_wins.max(right_wins) < *min_gallop {
+                if compare(&buffer[right - 1], &run[left - 1]) == Ordering::Less {
+                    swap(run, position - 1, left - 1);
+                    left -= 1;
+                    left_wins += 1;
+                    right_wins = 0;
+                    position -= 1;
+                    if left == 0 {
+                        break 'merge;
+                    }
+                } else {
+                    exchange(&mut run[position - 1], &mut buffer[right - 1]);
+                    right -= 1;
+                    right_wins += 1;
+                    left_wins = 0;
+                    position -= 1;
+                    if right == 0 {
+                        break 'merge;
+                    }
+                }
+            }
+            loop {
+                let left_count = left
+                    - gallop(&run[..left], left - 1, |x| {
+                        compare(x, &buffer[right - 1]) != Ordering::Greater
+                    });
+                for _ in 0..left_count {
+                    swap(run, position - 1, left - 1);
+                    left -= 1;
+                    position -= 1;
+                }
+                if left == 0 {
+                    break 'merge;
+                }
+                let right_count = right
+                    - gallop(&buffer[..right], right - 1, |x| {
+                        compare(x, &run[left - 1]) == Ordering::Less
+                    });
+                for _ in 0..right_count {
+                    exchange(&mut run[position - 1], &mut buffer[right - 1]);
+                    right -= 1;
+                    position -= 1;
+                }
+                if right == 0 {
+                    break 'merge;
+                }
+                if left_count < MIN_GALLOP && right_count < MIN_GALLOP {
+                    *min_gallop += 1;
+                    break;
+                }
+                *min_gallop = min_gallop.saturating_sub(1).max(1);
+            }
+        }
+        // the left elements left are in place, the right ones are still in the buffer
+        while right > 0 {
+            exchange(&mut run[position - 1], &mut buffer[right - 1]);
+            right -= 1;
+            position -= 1;
+        }
+    }
+}
+
+/// Sort a slice in increasing order with TimSort.
+///
+/// The slice is split in its natural runs, the descending ones reversed and the short ones
+/// extended to 16 to 32 elements by binary insertion sort. The runs are merged as they are
+/// found, keeping the pending ones at lengths that make the merges balanced. A merge starts
+/// by skipping the elements already in place, and when one run keeps winning it gallops:
+/// whole blocks are found by exponential search and moved at once. O(n) on sorted, reversed
+/// or concatenated data, O(n log n) otherwise, O(n) extra memory, stable.
+///
+/// # Arguments
+///
+/// * `slice` - The elements to sort
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::timsort;
+/// fn main() {
+///     let mut values: Vec<i32> = (0..500).chain((0..500).rev()).collect();
+///     timsort(&mut values);
+///     assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+/// }
+/// ```
+///
+pub fn timsort<T: Ord + Clone>(slice: &mut [T]) {
+    timsort_by(slice, T::cmp);
+}
+
+/// Sort a slice with TimSort, ordering the elements with `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_algo::sorting::{count_comparisons, timsort_by};
+/// fn main() {
+///     // two sorted halves are merged by galloping
+///     let mut values: Vec<u32> = (5000..10_000).chain(0..5000).collect();
+///     let comparisons = count_comparisons(&mut values, |slice, compare| timsort_by(slice, compare));
+///     assert!(values.iter().enumerate().all(|(i, &v)| v == i as u32));
+///     assert!(comparisons < 10_100);
+/// }
+/// ```
+///
+pub fn timsort_by<T: Clone, F>(slice: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    if len < MIN_MERGE {
+        let run = count_run(slice, &mut compare);
+        binary_insertion_sort(slice, run, &mut compare);
+        return;
+    }
+    let min_run = min_run_length(len);
+    let mut sort = TimSort {
+        compare: &mut compare,
+        // the run lengths grow faster than the Fibonacci numbers from the bottom of the stack
+        runs: counted(Vec::with_capacity(
+            2 * (usize::BITS - len.leading_zeros()) as usize,
+        )),
+        buffer: Vec::new(),
+        min_gallop: MIN_GALLOP,
+    };
+    let mut start = 0;
+    while start < len {
+        let mut run = count_run(&mut slice[start..], sort.compare);
+        if run < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort(&mut slice[start..start + forced], run, sort.compare);
+            run = forced;
+        }
+        sort.runs.push((start, run));
+        sort.merge_collapse(slice);
+        start += run;
+    }
+    sort.merge_force_collapse(slice);
+}
+
+/// Sort a slice with TimSort, ordering the elements by the key `key` extracts.
+pub fn timsort_by_key<T: Clone, K: Ord, F>(slice: &mut [T], mut key: F)
+where
+    F: FnMut(&T) -> K,
+{
+    timsort_by(slice, |a, b| key(a).cmp(&key(b)));
+}
+
+#[cfg(test)]
+mod timsort_tests {
+    use super::*;
+    use crate::math::Rng;
+    use crate::sorting::{check_sort, count_comparisons};
+
+    #[test]
+    fn sorts() {
+        check_sort(true, |slice| timsort_by_key(slice, |pair| pair.0));
+        assert_eq!(min_run_length(31), 31);
+        assert_eq!(min_run_length(64), 16);
+        assert_eq!(min_run_length(65), 17);
+    }
+
+    #[test]
+    fn comparisons_against_std() {
+        let mut rng = Rng::new(42);
+        let n = 100_000;
+        let mut runs = Vec::new();
+        for _ in 0..20 {
+            let start = rng.next_u64() % n;
+            runs.extend(start..start + n / 20);
+        }
+        let patterns: Vec<Vec<u64>> = vec![
+            (0..n).map(|_| rng.next_u64()).collect(),
+            (0..n).map(|_| rng.next_u64() % 16).collect(),
+            (0..n).collect(),
+            (0..n).rev().collect(),
+            runs,
+        ];
+        for values in patterns {
+            let mut expected = values.clone();
+            let std = count_comparisons(&mut expected, |slice, compare| slice.sort_by(compare));
+            let mut sorted = values;
+            let ours = count_comparisons(&mut sorted, |slice, compare| timsort_by(slice, compare));
+            assert_eq!(sorted, expected);
+            // slice::sort partitions equal keys apart, which pays on few distinct values
+            assert!(ours <= std * 2, "{} against {}", ours, std);
+        }
+    }
+}